@@ -0,0 +1,128 @@
+use crate::graph::Graph;
+use crate::node::Node;
+use std::collections::HashMap;
+
+/// RDF dataset consisting of a default graph and any number of named graphs.
+///
+/// Named graphs are identified by a URI or blank node.
+#[derive(Debug)]
+pub struct Dataset {
+    default_graph: Graph,
+    named_graphs: HashMap<Node, Graph>,
+}
+
+impl Default for Dataset {
+    fn default() -> Self {
+        Dataset::new()
+    }
+}
+
+impl Dataset {
+    /// Constructor for `Dataset`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::dataset::Dataset;
+    ///
+    /// let dataset = Dataset::new();
+    ///
+    /// assert!(dataset.is_empty());
+    /// ```
+    pub fn new() -> Dataset {
+        Dataset {
+            default_graph: Graph::new(None),
+            named_graphs: HashMap::new(),
+        }
+    }
+
+    /// Returns `true` if neither the default graph nor any named graph contains triples.
+    pub fn is_empty(&self) -> bool {
+        self.count() == 0
+    }
+
+    /// Returns the number of triples stored in the default graph and all named graphs.
+    pub fn count(&self) -> usize {
+        self.default_graph.count() + self.named_graphs.values().map(|g| g.count()).sum::<usize>()
+    }
+
+    /// Returns a reference to the default graph.
+    pub fn default_graph(&self) -> &Graph {
+        &self.default_graph
+    }
+
+    /// Returns a mutable reference to the default graph.
+    pub fn default_graph_mut(&mut self) -> &mut Graph {
+        &mut self.default_graph
+    }
+
+    /// Returns the named graph with the provided name, if it exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::dataset::Dataset;
+    /// use rdf::node::Node;
+    ///
+    /// let mut dataset = Dataset::new();
+    /// let name = Node::BlankNode { id: "g".to_string() };
+    ///
+    /// assert!(dataset.named_graph(&name).is_none());
+    ///
+    /// dataset.named_graph_mut(&name);
+    ///
+    /// assert!(dataset.named_graph(&name).is_some());
+    /// ```
+    pub fn named_graph(&self, name: &Node) -> Option<&Graph> {
+        self.named_graphs.get(name)
+    }
+
+    /// Returns a mutable reference to the named graph, the graph is created if it does not exist.
+    pub fn named_graph_mut(&mut self, name: &Node) -> &mut Graph {
+        self.named_graphs
+            .entry(name.clone())
+            .or_insert_with(|| Graph::new(None))
+    }
+
+    /// Adds the graph as named graph with the provided name.
+    ///
+    /// If a graph with this name already exists, the triples of the graph are added to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::dataset::Dataset;
+    /// use rdf::graph::Graph;
+    /// use rdf::node::Node;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    /// let node = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node_str("http://example.org/p");
+    /// graph.add_triple(&Triple::new(&node, &predicate, &node));
+    ///
+    /// let mut dataset = Dataset::new();
+    /// let name = Node::BlankNode { id: "g".to_string() };
+    /// dataset.add_graph(name.clone(), graph);
+    ///
+    /// assert_eq!(dataset.named_graph(&name).unwrap().count(), 1);
+    /// assert!(dataset.default_graph().is_empty());
+    /// ```
+    pub fn add_graph(&mut self, name: Node, graph: Graph) {
+        match self.named_graphs.get_mut(&name) {
+            Some(existing) => {
+                for triple in graph.triples_iter() {
+                    existing.add_triple(triple);
+                }
+            }
+            None => {
+                self.named_graphs.insert(name, graph);
+            }
+        }
+    }
+
+    /// Returns the names of all named graphs.
+    pub fn graph_names(&self) -> Vec<&Node> {
+        self.named_graphs.keys().collect()
+    }
+}
@@ -0,0 +1,360 @@
+use crate::error::{Error, ErrorType};
+use crate::node::Node;
+use crate::reader::n_triples_parser::NTriplesParser;
+use crate::reader::rdf_parser::RdfParser;
+use crate::triple::Triple;
+use crate::writer::formatter::n_triples_formatter::NTriplesFormatter;
+use crate::writer::formatter::rdf_formatter::RdfFormatter;
+use crate::writer::n_triples_writer::NTriplesWriter;
+use crate::Result;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, BufWriter, Lines, Write};
+use std::path::{Path, PathBuf};
+
+/// Graph that spills its triples to a file on disk to limit memory usage.
+///
+/// Inserted triples are buffered in memory until the configured threshold is
+/// exceeded, then they are sorted and written as N-Triples to a run file next to the file.
+/// Before querying, the runs are merged into the sorted file so that triples sharing a
+/// subject are stored next to each other and `triples_matching` streams over the file
+/// instead of loading it completely. At most `in_memory_threshold` triples are kept in
+/// memory at a time and the merge stores repeated triples once.
+pub struct DiskGraph {
+    path: PathBuf,
+    in_memory_threshold: usize,
+    buffer: Vec<Triple>,
+    count: usize,
+    runs: Vec<PathBuf>,
+    writer: NTriplesWriter,
+    formatter: NTriplesFormatter,
+}
+
+impl DiskGraph {
+    /// Constructor of `DiskGraph` storing triples in the file at the provided path.
+    ///
+    /// An existing file is truncated.
+    ///
+    /// # Failures
+    ///
+    /// - The file cannot be created.
+    ///
+    pub fn new<P: AsRef<Path>>(path: P, in_memory_threshold: usize) -> Result<DiskGraph> {
+        File::create(path.as_ref()).map_err(Self::write_error)?;
+
+        Ok(DiskGraph {
+            path: path.as_ref().to_path_buf(),
+            in_memory_threshold,
+            buffer: Vec::new(),
+            count: 0,
+            runs: Vec::new(),
+            writer: NTriplesWriter::new(),
+            formatter: NTriplesFormatter::new(),
+        })
+    }
+
+    /// Returns the number of inserted triples.
+    ///
+    /// A triple that is inserted repeatedly is counted each time until the next query
+    /// merges the runs, afterwards it is counted once.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if no triples were inserted.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns the number of triples currently kept in memory.
+    pub fn buffered_count(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Inserts a triple, spilling the buffered triples to disk once the threshold is exceeded.
+    ///
+    /// # Failures
+    ///
+    /// - Writing to the file fails.
+    ///
+    pub fn insert(&mut self, triple: &Triple) -> Result<()> {
+        self.buffer.push(triple.clone());
+        self.count += 1;
+
+        if self.buffer.len() > self.in_memory_threshold {
+            self.flush()?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns all triples matching the provided nodes, `None` matches any node.
+    ///
+    /// # Failures
+    ///
+    /// - Reading or sorting the file fails.
+    ///
+    pub fn triples_matching(
+        &mut self,
+        subject: Option<&Node>,
+        predicate: Option<&Node>,
+        object: Option<&Node>,
+    ) -> Result<Vec<Triple>> {
+        self.flush()?;
+        self.sort()?;
+
+        let file = File::open(&self.path).map_err(Self::read_error)?;
+
+        // lines are sorted, hence all triples with the subject are stored consecutively
+        let subject_prefix = subject.map(|s| self.formatter.format_node(s) + " ");
+
+        let mut triples = Vec::new();
+        let mut subject_found = false;
+
+        for line in BufReader::new(file).lines() {
+            let line = line.map_err(Self::read_error)?;
+
+            if let Some(ref prefix) = subject_prefix {
+                if !line.starts_with(prefix.as_str()) {
+                    if subject_found {
+                        break;
+                    }
+
+                    continue;
+                }
+
+                subject_found = true;
+            }
+
+            let triple = Self::parse_line(line)?;
+
+            if predicate.is_none_or(|p| p == triple.predicate())
+                && object.is_none_or(|o| o == triple.object())
+            {
+                triples.push(triple);
+            }
+        }
+
+        Ok(triples)
+    }
+
+    /// Sorts the buffered triples and writes them to a new run file.
+    fn flush(&mut self) -> Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+
+        let mut lines = Vec::with_capacity(self.buffer.len());
+
+        for triple in self.buffer.drain(..) {
+            lines.push(self.writer.triple_to_n_triples(&triple)?);
+        }
+
+        lines.sort_unstable();
+        lines.dedup();
+
+        let path = self.file_path(&format!("run{}", self.runs.len()));
+        let mut output = BufWriter::new(File::create(&path).map_err(Self::write_error)?);
+        self.runs.push(path);
+
+        for line in lines {
+            writeln!(output, "{}", line).map_err(Self::write_error)?;
+        }
+
+        output.flush().map_err(Self::write_error)
+    }
+
+    /// Merges the sorted file and the runs into the sorted file and removes repeated triples.
+    ///
+    /// Only the next line of each run is kept in memory.
+    fn sort(&mut self) -> Result<()> {
+        if self.runs.is_empty() {
+            return Ok(());
+        }
+
+        let mut readers = Vec::with_capacity(self.runs.len() + 1);
+
+        for path in Some(&self.path).into_iter().chain(&self.runs) {
+            let file = File::open(path).map_err(Self::read_error)?;
+            readers.push(BufReader::new(file).lines());
+        }
+
+        // smallest next line of each run
+        let mut heap = BinaryHeap::new();
+
+        for (run, reader) in readers.iter_mut().enumerate() {
+            if let Some(line) = Self::next_line(reader)? {
+                heap.push(Reverse((line, run)));
+            }
+        }
+
+        let sorted_path = self.file_path("sorted");
+        let mut output = BufWriter::new(File::create(&sorted_path).map_err(Self::write_error)?);
+        let mut last_line: Option<String> = None;
+        let mut count = 0;
+
+        while let Some(Reverse((line, run))) = heap.pop() {
+            if let Some(next) = Self::next_line(&mut readers[run])? {
+                heap.push(Reverse((next, run)));
+            }
+
+            if last_line.as_ref() == Some(&line) {
+                continue;
+            }
+
+            writeln!(output, "{}", line).map_err(Self::write_error)?;
+            count += 1;
+            last_line = Some(line);
+        }
+
+        output.flush().map_err(Self::write_error)?;
+        fs::rename(&sorted_path, &self.path).map_err(Self::write_error)?;
+        self.remove_runs();
+        self.count = count;
+
+        Ok(())
+    }
+
+    /// Returns the path of a file next to the sorted file, e.g. `graph.nt.run0`.
+    fn file_path(&self, extension: &str) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(".");
+        path.push(extension);
+        PathBuf::from(path)
+    }
+
+    /// Removes the run files.
+    fn remove_runs(&mut self) {
+        for path in self.runs.drain(..) {
+            let _ = fs::remove_file(path);
+        }
+    }
+
+    /// Reads the next line of a sorted run.
+    fn next_line(reader: &mut Lines<BufReader<File>>) -> Result<Option<String>> {
+        reader.next().transpose().map_err(Self::read_error)
+    }
+
+    /// Creates the triple stored in a single N-Triples line.
+    fn parse_line(line: String) -> Result<Triple> {
+        let graph = NTriplesParser::from_string(line).decode()?;
+
+        match graph.triples_iter().next() {
+            Some(triple) => Ok(triple.clone()),
+            None => Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Invalid triple stored in disk graph.",
+            )),
+        }
+    }
+
+    fn read_error(err: std::io::Error) -> Error {
+        Error::new(ErrorType::InvalidReaderInput, err.to_string())
+    }
+
+    fn write_error(err: std::io::Error) -> Error {
+        Error::new(ErrorType::InvalidWriterOutput, err.to_string())
+    }
+}
+
+impl Drop for DiskGraph {
+    fn drop(&mut self) {
+        self.remove_runs();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::disk_graph::DiskGraph;
+    use crate::node::Node;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+
+    #[test]
+    fn test_disk_graph_spills_triples() {
+        let path = std::env::temp_dir().join(format!("rdf_disk_graph_{}.nt", std::process::id()));
+        let mut graph = DiskGraph::new(&path, 2).unwrap();
+
+        let predicate = Node::UriNode {
+            uri: Uri::new("http://example.org/p".to_string()),
+        };
+
+        for i in (0..5).rev() {
+            let subject = Node::UriNode {
+                uri: Uri::new(format!("http://example.org/s{}", i % 2)),
+            };
+            let object = Node::LiteralNode {
+                literal: i.to_string(),
+                data_type: None,
+                language: None,
+            };
+
+            graph
+                .insert(&Triple::new(&subject, &predicate, &object))
+                .unwrap();
+        }
+
+        assert_eq!(graph.count(), 5);
+        assert!(graph.buffered_count() <= 2);
+
+        let subject = Node::UriNode {
+            uri: Uri::new("http://example.org/s1".to_string()),
+        };
+        let matching = graph.triples_matching(Some(&subject), None, None).unwrap();
+        assert_eq!(matching.len(), 2);
+        assert!(matching.iter().all(|t| t.subject() == &subject));
+
+        assert_eq!(
+            graph
+                .triples_matching(None, Some(&predicate), None)
+                .unwrap()
+                .len(),
+            5
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_disk_graph_stores_repeated_triples_once() {
+        let file_name = format!("rdf_disk_graph_{}_repeated.nt", std::process::id());
+        let path = std::env::temp_dir().join(file_name);
+        let mut graph = DiskGraph::new(&path, 1).unwrap();
+
+        let predicate = Node::UriNode {
+            uri: Uri::new("http://example.org/p".to_string()),
+        };
+
+        for i in 0..6 {
+            let subject = Node::UriNode {
+                uri: Uri::new(format!("http://example.org/s{}", i % 3)),
+            };
+
+            graph
+                .insert(&Triple::new(&subject, &predicate, &subject))
+                .unwrap();
+        }
+
+        assert_eq!(graph.count(), 6);
+        assert_eq!(graph.triples_matching(None, None, None).unwrap().len(), 3);
+        assert_eq!(graph.count(), 3);
+
+        let subject = Node::UriNode {
+            uri: Uri::new("http://example.org/s3".to_string()),
+        };
+
+        graph
+            .insert(&Triple::new(&subject, &predicate, &subject))
+            .unwrap();
+        graph
+            .insert(&Triple::new(&subject, &predicate, &subject))
+            .unwrap();
+
+        assert_eq!(graph.triples_matching(None, None, None).unwrap().len(), 4);
+        assert_eq!(graph.count(), 4);
+
+        drop(graph);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
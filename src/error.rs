@@ -14,6 +14,9 @@ pub enum ErrorType {
     /// RDF reader reads an invalid token (e.g. invalid node type).
     InvalidToken,
 
+    /// RDF reader reads a token where no token of this kind is expected (e.g. an empty statement).
+    UnexpectedToken,
+
     /// RDF reader reaches the end of the input and stores the characters that were read last.
     EndOfInput(InputChars),
 
@@ -25,6 +28,9 @@ pub enum ErrorType {
 
     /// RDF SPARQL reader reads invalid SPARQL input.
     InvalidSparqlInput,
+
+    /// RDF list of a graph is malformed (e.g. missing `rdf:rest` or a cycle).
+    InvalidList,
 }
 
 /// An error related to the rdf-rs module.
@@ -59,7 +65,7 @@ impl fmt::Display for Error {
 }
 
 impl StdError for Error {
-    fn description(&self) -> &str {
-        self.error.description()
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(self.error.as_ref())
     }
 }
@@ -1,11 +1,117 @@
 use crate::namespace::*;
 use crate::node::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::io::Write;
 use std::slice::Iter;
 use crate::triple::*;
 use crate::uri::Uri;
+use crate::error::{Error, ErrorType};
 use crate::Result;
+use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
 use crate::specs::xml_specs::XmlDataTypes;
+use crate::writer::n_triples_writer::NTriplesWriter;
+
+/// Differences between two RDF graphs.
+///
+/// Triples are listed once, in the order of their first occurrence in the respective graph.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GraphDiff {
+    /// Triples of the other graph that are not contained in the graph.
+    pub added: Vec<Triple>,
+
+    /// Triples of the graph that are not contained in the other graph.
+    pub removed: Vec<Triple>,
+}
+
+impl GraphDiff {
+    /// Returns `true` if both graphs contain the same triples.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Predicate of a subject whose number of objects violates a cardinality constraint, see
+/// `Graph::check_cardinality`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    /// Predicate of the violated constraint.
+    pub predicate: Node,
+
+    /// Number of triples of the subject with the predicate.
+    pub count: usize,
+
+    /// Minimum number of triples required by the constraint.
+    pub min: usize,
+
+    /// Maximum number of triples allowed by the constraint, `None` if unbounded.
+    pub max: Option<usize>,
+}
+
+/// Iterator over the items of an RDF list of a graph, see `Graph::list_iter`.
+///
+/// After an error no further items are returned.
+pub struct ListIter<'a> {
+    graph: &'a Graph,
+    next_node: Option<Node>,
+    visited_nodes: HashSet<Node>,
+}
+
+impl<'a> ListIter<'a> {
+    /// Returns the object of the single triple with the provided subject and predicate.
+    fn single_object(&self, subject: &Node, predicate: RdfSyntaxDataTypes) -> Result<Node> {
+        let predicate_node = Node::UriNode {
+            uri: predicate.to_uri(),
+        };
+
+        match self
+            .graph
+            .get_triples_with_subject_and_predicate(subject, &predicate_node)
+            .as_slice()
+        {
+            [triple] => Ok(triple.object().clone()),
+            triples => Err(Error::new(
+                ErrorType::InvalidList,
+                format!(
+                    "List node {:?} has {} instead of one <{}> triple.",
+                    subject,
+                    triples.len(),
+                    predicate
+                ),
+            )),
+        }
+    }
+}
+
+impl<'a> Iterator for ListIter<'a> {
+    type Item = Result<Node>;
+
+    /// Returns the item of the next list node and continues with the rest of the list.
+    fn next(&mut self) -> Option<Result<Node>> {
+        let node = self.next_node.take()?;
+
+        if node == (Node::UriNode { uri: RdfSyntaxDataTypes::ListNil.to_uri() }) {
+            return None;
+        }
+
+        if !self.visited_nodes.insert(node.clone()) {
+            return Some(Err(Error::new(
+                ErrorType::InvalidList,
+                format!("List contains a cycle at node {:?}.", node),
+            )));
+        }
+
+        let item = self.single_object(&node, RdfSyntaxDataTypes::ListFirst);
+        let rest = self.single_object(&node, RdfSyntaxDataTypes::ListRest);
+
+        Some(match (item, rest) {
+            (Ok(item), Ok(rest)) => {
+                self.next_node = Some(rest);
+                Ok(item)
+            }
+            (Err(err), _) | (_, Err(err)) => Err(err),
+        })
+    }
+}
 
 /// Representation of an RDF graph.
 #[derive(Debug)]
@@ -34,10 +140,7 @@ impl Graph {
     /// let graph = Graph::new(None);
     /// ```
     pub fn new(base_uri: Option<&Uri>) -> Graph {
-        let cloned_uri = match base_uri {
-            None => None,
-            Some(u) => Some(u.clone()),
-        };
+        let cloned_uri = base_uri.cloned();
 
         Graph {
             base_uri: cloned_uri,
@@ -77,6 +180,11 @@ impl Graph {
         self.triples.count()
     }
 
+    /// Returns the number of triples that are stored in the graph, same as `count`.
+    pub fn len(&self) -> usize {
+        self.triples.count()
+    }
+
     /// Returns the base URI of the graph.
     ///
     /// # Examples
@@ -193,13 +301,14 @@ impl Graph {
     /// ```
     /// use rdf::graph::Graph;
     /// use rdf::node::Node;
+    /// use rdf::specs::xml_specs::XmlDataTypes;
     ///
     /// let graph = Graph::new(None);
     /// let literal_node = graph.create_integer_node(17);
     ///
     /// assert_eq!(literal_node, Node::LiteralNode {
     ///   literal: 17.to_string(),
-    ///   data_type: None,
+    ///   data_type: Some(XmlDataTypes::Integer.to_uri()),
     ///   language: None
     /// });
     /// ```
@@ -395,11 +504,13 @@ impl Graph {
             (_, _) => Uri::new(uri.to_string())
         };
 
-        Node::UriNode { uri: uri }
+        Node::UriNode { uri }
     }
 
     /// Adds a triple to the graph.
     ///
+    /// Returns `false` if the graph already contained the triple.
+    ///
     /// # Examples
     ///
     /// ```
@@ -414,12 +525,37 @@ impl Graph {
     /// let object = graph.create_blank_node();
     /// let triple = Triple::new(&subject, &predicate, &object);
     ///
-    /// graph.add_triple(&triple);
+    /// assert!(graph.add_triple(&triple));
+    /// assert!(!graph.add_triple(&triple));
     ///
     /// assert_eq!(graph.count(), 1);
     /// ```
-    pub fn add_triple(&mut self, triple: &Triple) {
-        self.triples.add_triple(triple);
+    pub fn add_triple(&mut self, triple: &Triple) -> bool {
+        self.triples.add_triple(triple)
+    }
+
+    /// Adds the triple to the graph, adding a triple that is already contained has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_literal_node("name".to_string());
+    ///
+    /// graph.add(Triple::new(&subject, &predicate, &object));
+    /// graph.add(Triple::new(&subject, &predicate, &object));
+    ///
+    /// assert_eq!(graph.len(), 1);
+    /// ```
+    pub fn add(&mut self, triple: Triple) {
+        self.triples.add_triple(&triple);
     }
 
     /// Adds a vector of triples.
@@ -442,7 +578,8 @@ impl Graph {
     ///
     /// graph.add_triples(&vec![triple1, triple2]);
     ///
-    /// assert_eq!(graph.count(), 2);
+    /// // equal triples are only contained once
+    /// assert_eq!(graph.count(), 1);
     /// ```
     pub fn add_triples(&mut self, triples: &[Triple]) {
         for triple in triples {
@@ -474,6 +611,60 @@ impl Graph {
         self.triples.remove_triple(triple);
     }
 
+    /// Deletes the triple from the graph and returns `false` if it was not contained.
+    ///
+    /// The last triple of the graph takes the place of the removed triple, hence the time
+    /// does not depend on the size of the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_literal_node("name".to_string());
+    /// let triple = Triple::new(&subject, &predicate, &object);
+    ///
+    /// graph.add(triple.clone());
+    ///
+    /// assert!(graph.remove(&triple));
+    /// assert!(!graph.remove(&triple));
+    /// ```
+    pub fn remove(&mut self, triple: &Triple) -> bool {
+        self.triples.remove_triple(triple)
+    }
+
+    /// Checks if the graph contains the triple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_literal_node("name".to_string());
+    /// let triple = Triple::new(&subject, &predicate, &object);
+    ///
+    /// assert!(!graph.contains(&triple));
+    ///
+    /// graph.add(triple.clone());
+    ///
+    /// assert!(graph.contains(&triple));
+    /// ```
+    pub fn contains(&self, triple: &Triple) -> bool {
+        self.triples.contains(triple)
+    }
+
     /// Returns all triples from the store that have the specified subject node.
     ///
     /// # Examples
@@ -651,57 +842,1436 @@ impl Graph {
             .get_triples_with_predicate_and_object(predicate_node, object_node)
     }
 
-    /// Returns an iterator over the triples of the graph.
-    pub fn triples_iter(&self) -> Iter<Triple> {
-        self.triples.iter()
+    /// Returns the objects of all `rdf:type` triples of the provided subject, in the order
+    /// the triples were added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_uri_node_str("http://example.org/alice");
+    /// let rdf_type = graph.create_uri_node(&RdfSyntaxDataTypes::A.to_uri());
+    /// let person = graph.create_uri_node_str("http://xmlns.com/foaf/0.1/Person");
+    ///
+    /// graph.add_triple(&Triple::new(&subject, &rdf_type, &person));
+    ///
+    /// assert_eq!(graph.types_of(&subject), vec![&person]);
+    /// ```
+    pub fn types_of(&self, subject: &Node) -> Vec<&Node> {
+        let rdf_type = Node::UriNode { uri: RdfSyntaxDataTypes::A.to_uri() };
+
+        self.triples
+            .get_triples_with_subject_and_predicate(subject, &rdf_type)
+            .into_iter()
+            .map(|triple| triple.object())
+            .collect()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::graph::Graph;
-    use crate::node::*;
+    /// Returns all triples containing an IRI that is not absolute.
+    ///
+    /// Subject, predicate and object IRIs as well as data types of literals are checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_uri_node(&Uri::new("a".to_string()));
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_blank_node();
+    /// let triple = Triple::new(&subject, &predicate, &object);
+    ///
+    /// graph.add_triple(&triple);
+    ///
+    /// assert_eq!(graph.relative_iri_triples(), vec![&triple]);
+    /// ```
+    pub fn relative_iri_triples(&self) -> Vec<&Triple> {
+        fn is_relative(node: &Node) -> bool {
+            match *node {
+                Node::UriNode { ref uri } => !uri.is_absolute_iri(),
+                Node::LiteralNode {
+                    data_type: Some(ref data_type),
+                    ..
+                } => !data_type.is_absolute_iri(),
+                _ => false,
+            }
+        }
 
-    #[test]
-    fn empty_graph() {
-        let graph = Graph::new(None);
-        assert_eq!(graph.is_empty(), true);
+        self.triples
+            .iter()
+            .filter(|t| {
+                is_relative(t.subject()) || is_relative(t.predicate()) || is_relative(t.object())
+            })
+            .collect()
     }
 
-    #[test]
-    fn create_literal_node() {
-        let graph = Graph::new(None);
-        let literal_node = graph.create_literal_node("literal".to_string());
+    /// Returns all triples whose object is a literal of the provided data type,
+    /// together with the lexical value of the literal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_integer_node(42);
+    /// let triple = Triple::new(&subject, &predicate, &object);
+    ///
+    /// graph.add_triple(&triple);
+    ///
+    /// let integers: Vec<_> = graph.literals_with_datatype(&XmlDataTypes::Integer.to_string()).collect();
+    ///
+    /// assert_eq!(integers, vec![(&triple, "42")]);
+    /// ```
+    pub fn literals_with_datatype<'a>(
+        &'a self,
+        datatype: &str,
+    ) -> impl Iterator<Item = (&'a Triple, &'a str)> + 'a {
+        let datatype = datatype.to_string();
 
-        assert_eq!(
-            literal_node,
+        self.triples.iter().filter_map(move |triple| match *triple.object() {
             Node::LiteralNode {
-                literal: "literal".to_string(),
-                data_type: None,
-                language: None,
+                ref literal,
+                data_type: Some(ref data_type),
+                ..
+            } if *data_type.to_string() == datatype => Some((triple, literal.as_str())),
+            _ => None,
+        })
+    }
+
+    /// Returns all triples whose object is a literal with a language tag matching the provided
+    /// language range, together with the lexical value and language tag of the literal.
+    ///
+    /// Tags are matched by basic filtering as defined in RFC 4647: a range matches a tag that
+    /// is equal to it or starts with it followed by `-`, ignoring case, e.g. `en` matches
+    /// `en-US` but not `eng`. The range `*` matches all language tags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_literal_node_with_language("color".to_string(), "en-US".to_string());
+    /// let triple = Triple::new(&subject, &predicate, &object);
+    ///
+    /// graph.add_triple(&triple);
+    ///
+    /// let english: Vec<_> = graph.literals_matching_lang("en").collect();
+    ///
+    /// assert_eq!(english, vec![(&triple, "color", "en-US")]);
+    /// ```
+    pub fn literals_matching_lang<'a>(
+        &'a self,
+        range: &str,
+    ) -> impl Iterator<Item = (&'a Triple, &'a str, &'a str)> + 'a {
+        let range = range.to_lowercase();
+
+        self.triples.iter().filter_map(move |triple| match *triple.object() {
+            Node::LiteralNode {
+                ref literal,
+                language: Some(ref language),
+                ..
+            } if Graph::language_matches(language, &range) => {
+                Some((triple, literal.as_str(), language.as_str()))
             }
-        );
+            _ => None,
+        })
     }
 
-    #[test]
-    fn create_multiple_blank_nodes() {
-        let mut graph = Graph::new(None);
+    /// Returns `true` if the language tag matches the lowercase language range by basic
+    /// filtering.
+    fn language_matches(language: &str, range: &str) -> bool {
+        if range == "*" {
+            return true;
+        }
 
-        let blank_node_0 = graph.create_blank_node();
-        let blank_node_1 = graph.create_blank_node();
+        let language = language.to_lowercase();
 
-        assert_eq!(
-            blank_node_0,
-            Node::BlankNode {
-                id: "auto0".to_string(),
-            }
-        );
+        match language.strip_prefix(range) {
+            Some(rest) => rest.is_empty() || rest.starts_with('-'),
+            None => false,
+        }
+    }
 
-        assert_eq!(
-            blank_node_1,
-            Node::BlankNode {
-                id: "auto1".to_string(),
-            }
-        );
+    /// Returns the lexical value, data type and language of each literal of the graph.
+    ///
+    /// Literals are returned in the order of their triples, once per triple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_literal_node_with_language("name".to_string(), "en".to_string());
+    ///
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+    ///
+    /// assert_eq!(graph.literals().collect::<Vec<_>>(), vec![("name", None, Some("en"))]);
+    /// ```
+    pub fn literals(&self) -> impl Iterator<Item = (&str, Option<&str>, Option<&str>)> {
+        self.triples.iter().filter_map(|triple| match *triple.object() {
+            Node::LiteralNode {
+                ref literal,
+                ref data_type,
+                ref language,
+            } => Some((
+                literal.as_str(),
+                data_type.as_ref().map(|uri| uri.to_string().as_str()),
+                language.as_deref(),
+            )),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over the items of the RDF list starting at the provided node.
+    ///
+    /// The list is walked along `rdf:first` and `rdf:rest` while iterating and ends at
+    /// `rdf:nil`, hence starting at `rdf:nil` results in an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    ///
+    /// let input = "<http://example.org/s> <http://example.org/p> ( 1 2 3 ) .";
+    /// let graph = TurtleParser::from_string(input.to_string()).decode().unwrap();
+    ///
+    /// let predicate = graph.create_uri_node_str("http://example.org/p");
+    /// let head = graph.get_triples_with_predicate(&predicate)[0].object();
+    /// let items: Vec<_> = graph.list_iter(head).map(|item| item.unwrap()).collect();
+    ///
+    /// assert_eq!(items.len(), 3);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - A node of the list does not have exactly one `rdf:first` and one `rdf:rest` triple.
+    /// - The list contains a cycle.
+    ///
+    pub fn list_iter(&self, head: &Node) -> ListIter<'_> {
+        ListIter {
+            graph: self,
+            next_node: Some(head.clone()),
+            visited_nodes: HashSet::new(),
+        }
+    }
+
+    /// Returns the blank nodes that are used as object but never as subject of a triple.
+    ///
+    /// Such blank nodes cannot be described any further and often indicate modeling errors.
+    /// Each node is returned once, in the order of its first occurrence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_uri_node(&Uri::new("http://example.org/show/s".to_string()));
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_blank_node();
+    ///
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+    ///
+    /// assert_eq!(graph.orphan_blank_nodes(), vec![&object]);
+    /// ```
+    pub fn orphan_blank_nodes(&self) -> Vec<&Node> {
+        let subjects: HashSet<&Node> = self.triples.iter().map(|t| t.subject()).collect();
+        let mut orphans: Vec<&Node> = Vec::new();
+
+        for triple in self.triples.iter() {
+            let object = triple.object();
+
+            if let Node::BlankNode { .. } = *object {
+                if !subjects.contains(object) && !orphans.contains(&object) {
+                    orphans.push(object);
+                }
+            }
+        }
+
+        orphans
+    }
+
+    /// Returns the number of triples that use each blank node label, in any position.
+    ///
+    /// A triple using a blank node in several positions counts once for it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node_with_id("s".to_string());
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_literal_node("name".to_string());
+    ///
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+    ///
+    /// assert_eq!(graph.blank_node_usage().get("s"), Some(&1));
+    /// ```
+    pub fn blank_node_usage(&self) -> HashMap<String, usize> {
+        let mut usage: HashMap<String, usize> = HashMap::new();
+
+        for triple in self.triples.iter() {
+            let mut labels: Vec<&String> = Vec::new();
+
+            for node in [triple.subject(), triple.predicate(), triple.object()] {
+                if let Node::BlankNode { ref id } = *node {
+                    if !labels.contains(&id) {
+                        labels.push(id);
+                    }
+                }
+            }
+
+            for label in labels {
+                *usage.entry(label.to_string()).or_insert(0) += 1;
+            }
+        }
+
+        usage
+    }
+
+    /// Returns the subjects that have more than one distinct object for the provided predicate,
+    /// together with these objects.
+    ///
+    /// Subjects and objects are returned in the order of their first occurrence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let first = graph.create_literal_node("first".to_string());
+    /// let second = graph.create_literal_node("second".to_string());
+    ///
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &first));
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &second));
+    ///
+    /// assert_eq!(graph.functional_violations(&predicate), vec![(subject, vec![first, second])]);
+    /// ```
+    pub fn functional_violations(&self, predicate: &Node) -> Vec<(Node, Vec<Node>)> {
+        let mut objects_by_subject: Vec<(Node, Vec<Node>)> = Vec::new();
+        let mut subject_positions: HashMap<&Node, usize> = HashMap::new();
+
+        for triple in self.get_triples_with_predicate(predicate) {
+            let position = *subject_positions.entry(triple.subject()).or_insert_with(|| {
+                objects_by_subject.push((triple.subject().clone(), Vec::new()));
+                objects_by_subject.len() - 1
+            });
+
+            let objects = &mut objects_by_subject[position].1;
+
+            if !objects.contains(triple.object()) {
+                objects.push(triple.object().clone());
+            }
+        }
+
+        objects_by_subject
+            .into_iter()
+            .filter(|(_, objects)| objects.len() > 1)
+            .collect()
+    }
+
+    /// Checks the number of triples of the subject for each predicate against the provided
+    /// `(predicate, min, max)` constraints, a `max` of `None` is unbounded.
+    ///
+    /// Returns a violation for each constraint whose count is out of bounds, in the order of
+    /// the constraints. This is a minimal cardinality check, not a SHACL validation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_literal_node("name".to_string());
+    ///
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+    ///
+    /// assert!(graph.check_cardinality(&subject, &[(predicate.clone(), 1, Some(1))]).is_empty());
+    /// assert_eq!(graph.check_cardinality(&subject, &[(predicate, 2, None)])[0].count, 1);
+    /// ```
+    pub fn check_cardinality(
+        &self,
+        subject: &Node,
+        constraints: &[(Node, usize, Option<usize>)],
+    ) -> Vec<Violation> {
+        constraints
+            .iter()
+            .filter_map(|(predicate, min, max)| {
+                let count = self.get_triples_with_subject_and_predicate(subject, predicate).len();
+
+                if count < *min || max.is_some_and(|max| count > max) {
+                    Some(Violation {
+                        predicate: predicate.clone(),
+                        count,
+                        min: *min,
+                        max: *max,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Returns each subject of the graph once together with all triples of this subject.
+    ///
+    /// Subjects are yielded in the order of their first occurrence and the triples of a
+    /// subject in insertion order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_literal_node("name".to_string());
+    ///
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+    ///
+    /// let groups: Vec<_> = graph.grouped_by_subject().collect();
+    ///
+    /// assert_eq!(groups.len(), 1);
+    /// assert_eq!(groups[0].0, &subject);
+    /// assert_eq!(groups[0].1.len(), 1);
+    /// ```
+    pub fn grouped_by_subject(&self) -> impl Iterator<Item = (&Node, Vec<&Triple>)> {
+        let mut groups: Vec<(&Node, Vec<&Triple>)> = Vec::new();
+        let mut subject_positions: HashMap<&Node, usize> = HashMap::new();
+
+        for triple in self.triples.iter() {
+            let position = *subject_positions.entry(triple.subject()).or_insert_with(|| {
+                groups.push((triple.subject(), Vec::new()));
+                groups.len() - 1
+            });
+
+            groups[position].1.push(triple);
+        }
+
+        groups.into_iter()
+    }
+
+    /// Returns the number of triples for each predicate of the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_literal_node("name".to_string());
+    ///
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+    ///
+    /// assert_eq!(graph.predicate_histogram().get(&predicate), Some(&1));
+    /// ```
+    pub fn predicate_histogram(&self) -> HashMap<Node, usize> {
+        let mut histogram: HashMap<Node, usize> = HashMap::new();
+
+        for triple in self.triples.iter() {
+            *histogram.entry(triple.predicate().clone()).or_insert(0) += 1;
+        }
+
+        histogram
+    }
+
+    /// Returns a graph with all triples that can be reached from the start node by
+    /// following objects that are URIs or blank nodes.
+    ///
+    /// The triples of the start node have depth 1, the triples of their objects depth 2 and so on.
+    /// Only triples up to `max_depth` are collected, all reachable triples if it is `None`.
+    /// Each node is visited once, hence cycles are followed only once.
+    /// Base URI and namespaces are copied to the returned graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let a = graph.create_uri_node(&Uri::new("http://example.org/a".to_string()));
+    /// let b = graph.create_uri_node(&Uri::new("http://example.org/b".to_string()));
+    /// let c = graph.create_uri_node(&Uri::new("http://example.org/c".to_string()));
+    /// let next = graph.create_uri_node(&Uri::new("http://example.org/next".to_string()));
+    ///
+    /// graph.add_triple(&Triple::new(&a, &next, &b));
+    /// graph.add_triple(&Triple::new(&b, &next, &c));
+    ///
+    /// assert_eq!(graph.reachable_from(&a, None).count(), 2);
+    /// assert_eq!(graph.reachable_from(&a, Some(1)).count(), 1);
+    /// assert_eq!(graph.reachable_from(&b, None).count(), 1);
+    /// ```
+    pub fn reachable_from(&self, start: &Node, max_depth: Option<usize>) -> Graph {
+        let mut reachable = Graph::new(self.base_uri.as_ref());
+
+        for (prefix, uri) in self.namespaces() {
+            reachable.add_namespace(&Namespace::new(prefix.to_string(), uri.clone()));
+        }
+
+        let mut visited: HashSet<&Node> = HashSet::new();
+        let mut queue: VecDeque<(&Node, usize)> = VecDeque::new();
+
+        visited.insert(start);
+        queue.push_back((start, 1));
+
+        while let Some((subject, depth)) = queue.pop_front() {
+            if max_depth.is_some_and(|max_depth| depth > max_depth) {
+                break;
+            }
+
+            for triple in self.get_triples_with_subject(subject) {
+                reachable.add_triple(triple);
+
+                let object = triple.object();
+
+                match *object {
+                    Node::UriNode { .. } | Node::BlankNode { .. } if visited.insert(object) => {
+                        queue.push_back((object, depth + 1))
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        reachable
+    }
+
+    /// Replaces the prefix `old_prefix_iri` of all URIs of subjects, predicates, objects and
+    /// literal data types by `new_prefix_iri` and returns the number of changed triples.
+    ///
+    /// Only URIs starting with `old_prefix_iri` are changed, occurrences at other positions
+    /// are kept. Triples that become equal to another triple by the rewriting are only kept once.
+    /// Namespaces and the base URI of the graph are not changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_uri_node(&Uri::new("http://old.example.org/s".to_string()));
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_literal_node("name".to_string());
+    ///
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+    ///
+    /// assert_eq!(graph.rebase_namespace("http://old.example.org/", "http://new.example.org/"), 1);
+    /// assert_eq!(graph.triples_iter().next().unwrap().subject(),
+    ///            &graph.create_uri_node(&Uri::new("http://new.example.org/s".to_string())));
+    /// ```
+    pub fn rebase_namespace(&mut self, old_prefix_iri: &str, new_prefix_iri: &str) -> usize {
+        let rebase_uri = |uri: &Uri| {
+            uri.to_string()
+                .strip_prefix(old_prefix_iri)
+                .map(|path| Uri::new(new_prefix_iri.to_string() + path))
+        };
+
+        let rebase_node = |node: &Node| match *node {
+            Node::UriNode { ref uri } => rebase_uri(uri).map(|uri| Node::UriNode { uri }),
+            Node::LiteralNode {
+                ref literal,
+                data_type: Some(ref data_type),
+                ref language,
+            } => rebase_uri(data_type).map(|data_type| Node::LiteralNode {
+                literal: literal.to_string(),
+                data_type: Some(data_type),
+                language: language.clone(),
+            }),
+            _ => None,
+        };
+
+        let mut changed = 0;
+
+        for triple in std::mem::take(&mut self.triples).into_vec() {
+            let subject = rebase_node(triple.subject());
+            let predicate = rebase_node(triple.predicate());
+            let object = rebase_node(triple.object());
+
+            if subject.is_none() && predicate.is_none() && object.is_none() {
+                self.triples.add_triple(&triple);
+                continue;
+            }
+
+            changed += 1;
+
+            self.triples.add_triple(&Triple::new(
+                subject.as_ref().unwrap_or_else(|| triple.subject()),
+                predicate.as_ref().unwrap_or_else(|| triple.predicate()),
+                object.as_ref().unwrap_or_else(|| triple.object()),
+            ));
+        }
+
+        changed
+    }
+
+    /// Returns the triples added and removed when changing the graph into the other graph.
+    ///
+    /// Blank nodes are compared by their labels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    /// let mut other = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_literal_node("name".to_string());
+    /// let triple = Triple::new(&subject, &predicate, &object);
+    ///
+    /// other.add_triple(&triple);
+    ///
+    /// let diff = graph.diff(&other);
+    ///
+    /// assert_eq!(diff.added, vec![triple]);
+    /// assert!(diff.removed.is_empty());
+    /// ```
+    pub fn diff(&self, other: &Graph) -> GraphDiff {
+        GraphDiff {
+            added: Self::difference(&other.triples, &self.triples),
+            removed: Self::difference(&self.triples, &other.triples),
+        }
+    }
+
+    /// Returns the distinct triples of `triples` that are not contained in `excluded`.
+    fn difference(triples: &TripleStore, excluded: &TripleStore) -> Vec<Triple> {
+        let mut seen: HashSet<&Triple> = excluded.iter().collect();
+
+        triples
+            .iter()
+            .filter(|triple| seen.insert(triple))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the triples matching the pattern, in the order they were added.
+    ///
+    /// A segment of the pattern that is `None` matches any node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_literal_node("name".to_string());
+    /// let triple = Triple::new(&subject, &predicate, &object);
+    ///
+    /// graph.add_triple(&triple);
+    ///
+    /// assert_eq!(graph.triples_matching(Some(&subject), None, None), vec![&triple]);
+    /// assert!(graph.triples_matching(None, None, Some(&subject)).is_empty());
+    /// ```
+    pub fn triples_matching(
+        &self,
+        subject: Option<&Node>,
+        predicate: Option<&Node>,
+        object: Option<&Node>,
+    ) -> Vec<&Triple> {
+        let matches = |pattern: Option<&Node>, node: &Node| pattern.is_none_or(|p| p == node);
+
+        self.triples
+            .iter()
+            .filter(|triple| {
+                matches(subject, triple.subject())
+                    && matches(predicate, triple.predicate())
+                    && matches(object, triple.object())
+            })
+            .collect()
+    }
+
+    /// Writes the triples matching the pattern as N-Triples to the output and returns their number.
+    ///
+    /// A segment of the pattern that is `None` matches any node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node_with_id("s".to_string());
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_literal_node("name".to_string());
+    ///
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+    ///
+    /// let mut output: Vec<u8> = Vec::new();
+    ///
+    /// assert_eq!(graph.write_matching(&mut output, None, Some(&predicate), None).unwrap(), 1);
+    /// assert_eq!(String::from_utf8(output).unwrap(),
+    ///            "_:s <http://example.org/show/localName> \"name\" .\n".to_string());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - A matching triple cannot be represented as N-Triples.
+    /// - Writing to the output fails.
+    ///
+    pub fn write_matching<W: Write>(
+        &self,
+        mut output: W,
+        subject: Option<&Node>,
+        predicate: Option<&Node>,
+        object: Option<&Node>,
+    ) -> Result<usize> {
+        let writer = NTriplesWriter::new();
+        let mut count = 0;
+
+        for triple in self.triples_matching(subject, predicate, object) {
+            writer.write_triple(triple, &mut output)?;
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
+    /// Returns a hash of the triples of the graph which is independent of the insertion order.
+    ///
+    /// The hash is computed from the sorted N-Triples serialization of the distinct triples
+    /// using FNV-1a, hence it is stable across program runs and platforms. Blank node labels
+    /// are not canonicalized, so isomorphic graphs using different labels hash differently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    /// let empty_hash = graph.content_hash();
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_blank_node();
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+    ///
+    /// assert_ne!(graph.content_hash(), empty_hash);
+    /// ```
+    pub fn content_hash(&self) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+        let writer = NTriplesWriter::new();
+
+        let mut lines: Vec<String> = self
+            .triples
+            .iter()
+            .map(|triple| {
+                // triples that are not valid N-Triples still need to contribute to the hash
+                writer
+                    .triple_to_n_triples(triple)
+                    .unwrap_or_else(|_| format!("{:?}", triple))
+            })
+            .collect();
+        lines.sort();
+        lines.dedup();
+
+        let mut hash = FNV_OFFSET_BASIS;
+
+        for line in &lines {
+            for byte in line.bytes().chain(Some(b'\n')) {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(FNV_PRIME);
+            }
+        }
+
+        hash
+    }
+
+    /// Removes all triples from the graph and returns them, namespaces and base URI are kept.
+    pub(crate) fn take_triples(&mut self) -> Vec<Triple> {
+        std::mem::take(&mut self.triples).into_vec()
+    }
+
+    /// Returns an iterator over the triples of the graph.
+    pub fn triples_iter(&self) -> Iter<'_, Triple> {
+        self.triples.iter()
+    }
+
+    /// Returns an iterator over the triples of the graph, same as `triples_iter`.
+    pub fn iter(&self) -> Iter<'_, Triple> {
+        self.triples.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ErrorType;
+    use crate::graph::{Graph, Violation};
+    use crate::node::*;
+    use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+    use crate::specs::xml_specs::XmlDataTypes;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+
+    #[test]
+    fn empty_graph() {
+        let graph = Graph::new(None);
+        assert!(graph.is_empty());
+    }
+
+    #[test]
+    fn create_literal_node() {
+        let graph = Graph::new(None);
+        let literal_node = graph.create_literal_node("literal".to_string());
+
+        assert_eq!(
+            literal_node,
+            Node::LiteralNode {
+                literal: "literal".to_string(),
+                data_type: None,
+                language: None,
+            }
+        );
+    }
+
+    #[test]
+    fn create_multiple_blank_nodes() {
+        let mut graph = Graph::new(None);
+
+        let blank_node_0 = graph.create_blank_node();
+        let blank_node_1 = graph.create_blank_node();
+
+        assert_eq!(
+            blank_node_0,
+            Node::BlankNode {
+                id: "auto0".to_string(),
+            }
+        );
+
+        assert_eq!(
+            blank_node_1,
+            Node::BlankNode {
+                id: "auto1".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn relative_iri_triples() {
+        let mut graph = Graph::new(None);
+
+        let absolute = graph.create_uri_node(&Uri::new("http://example.org/a".to_string()));
+        let relative = graph.create_uri_node(&Uri::new("b".to_string()));
+        let relative_data_type =
+            graph.create_literal_node_with_data_type("1".to_string(), &Uri::new("int".to_string()));
+        let literal = graph.create_integer_node(1);
+
+        let absolute_triple = Triple::new(&absolute, &absolute, &literal);
+        let relative_subject = Triple::new(&relative, &absolute, &absolute);
+        let relative_object = Triple::new(&absolute, &absolute, &relative);
+        let relative_literal = Triple::new(&absolute, &absolute, &relative_data_type);
+
+        graph.add_triples(&[
+            absolute_triple,
+            relative_subject.clone(),
+            relative_object.clone(),
+            relative_literal.clone(),
+        ]);
+
+        assert_eq!(
+            graph.relative_iri_triples(),
+            vec![&relative_subject, &relative_object, &relative_literal]
+        );
+    }
+
+    #[test]
+    fn literals_with_datatype() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_blank_node();
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let one = graph.create_integer_node(1);
+        let two = graph.create_integer_node(2);
+        let text = graph.create_literal_node("3".to_string());
+        let boolean = graph
+            .create_literal_node_with_data_type("true".to_string(), &XmlDataTypes::Boolean.to_uri());
+
+        graph.add_triple(&Triple::new(&subject, &predicate, &one));
+        graph.add_triple(&Triple::new(&subject, &predicate, &text));
+        graph.add_triple(&Triple::new(&subject, &predicate, &two));
+        graph.add_triple(&Triple::new(&subject, &predicate, &boolean));
+
+        let integers: Vec<&str> = graph
+            .literals_with_datatype(&XmlDataTypes::Integer.to_string())
+            .map(|(_, literal)| literal)
+            .collect();
+
+        assert_eq!(integers, vec!["1", "2"]);
+    }
+
+    #[test]
+    fn content_hash_is_independent_of_order() {
+        let mut graph_1 = Graph::new(None);
+        let mut graph_2 = Graph::new(None);
+
+        let subject = graph_1.create_uri_node(&Uri::new("http://example.org/s".to_string()));
+        let predicate = graph_1.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let object_1 = graph_1.create_literal_node("1".to_string());
+        let object_2 = graph_1.create_literal_node_with_language("2".to_string(), "en".to_string());
+
+        let triple_1 = Triple::new(&subject, &predicate, &object_1);
+        let triple_2 = Triple::new(&subject, &predicate, &object_2);
+
+        graph_1.add_triples(&[triple_1.clone(), triple_2.clone()]);
+        graph_2.add_triples(&[triple_2, triple_1.clone()]);
+
+        assert_eq!(graph_1.content_hash(), graph_2.content_hash());
+
+        graph_2.remove_triple(&triple_1);
+        assert_ne!(graph_1.content_hash(), graph_2.content_hash());
+    }
+
+    #[test]
+    fn set_semantics() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_blank_node();
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let object = graph.create_literal_node("o".to_string());
+        let triple = Triple::new(&subject, &predicate, &object);
+        let absent = Triple::new(&subject, &predicate, &subject);
+
+        graph.add(triple.clone());
+        graph.add(triple.clone());
+        graph.add_triple(&triple);
+
+        assert_eq!(graph.len(), 1);
+        assert_eq!(graph.iter().collect::<Vec<_>>(), vec![&triple]);
+        assert!(graph.contains(&triple));
+        assert!(!graph.contains(&absent));
+
+        assert!(!graph.remove(&absent));
+        assert!(graph.remove(&triple));
+        assert!(!graph.remove(&triple));
+        assert!(graph.is_empty());
+    }
+
+    #[test]
+    fn literals() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_blank_node();
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+
+        for object in &[
+            graph.create_literal_node("plain".to_string()),
+            graph.create_uri_node(&Uri::new("http://example.org/o".to_string())),
+            graph.create_integer_node(42),
+            graph.create_literal_node_with_language("chat".to_string(), "fr".to_string()),
+            subject.clone(),
+        ] {
+            graph.add_triple(&Triple::new(&subject, &predicate, object));
+        }
+
+        let integer = XmlDataTypes::Integer.to_string();
+
+        assert_eq!(
+            graph.literals().collect::<Vec<_>>(),
+            vec![
+                ("plain", None, None),
+                ("42", Some(integer.as_str()), None),
+                ("chat", None, Some("fr")),
+            ]
+        );
+    }
+
+    #[test]
+    fn list_iter() {
+        let mut graph = Graph::new(None);
+
+        let first = Node::UriNode { uri: RdfSyntaxDataTypes::ListFirst.to_uri() };
+        let rest = Node::UriNode { uri: RdfSyntaxDataTypes::ListRest.to_uri() };
+        let nil = Node::UriNode { uri: RdfSyntaxDataTypes::ListNil.to_uri() };
+
+        let head = graph.create_blank_node();
+        let tail = graph.create_blank_node();
+        let a = graph.create_literal_node("a".to_string());
+        let b = graph.create_literal_node("b".to_string());
+
+        graph.add_triple(&Triple::new(&head, &first, &a));
+        graph.add_triple(&Triple::new(&head, &rest, &tail));
+        graph.add_triple(&Triple::new(&tail, &first, &b));
+        graph.add_triple(&Triple::new(&tail, &rest, &nil));
+
+        let items: Vec<Node> = graph.list_iter(&head).map(|item| item.unwrap()).collect();
+
+        assert_eq!(items, vec![a, b.clone()]);
+        assert_eq!(graph.list_iter(&nil).count(), 0);
+
+        // a list node without rdf:rest stops the iteration with an error
+        graph.remove_triple(&Triple::new(&tail, &rest, &nil));
+
+        let results: Vec<_> = graph.list_iter(&tail).collect();
+
+        assert_eq!(results.len(), 1);
+        assert!(matches!(results[0].as_ref().unwrap_err().error_type(), ErrorType::InvalidList));
+    }
+
+    #[test]
+    fn list_iter_with_cycle() {
+        let mut graph = Graph::new(None);
+
+        let first = Node::UriNode { uri: RdfSyntaxDataTypes::ListFirst.to_uri() };
+        let rest = Node::UriNode { uri: RdfSyntaxDataTypes::ListRest.to_uri() };
+
+        let head = graph.create_blank_node();
+        let tail = graph.create_blank_node();
+        let item = graph.create_literal_node("a".to_string());
+
+        graph.add_triple(&Triple::new(&head, &first, &item));
+        graph.add_triple(&Triple::new(&head, &rest, &tail));
+        graph.add_triple(&Triple::new(&tail, &first, &item));
+        graph.add_triple(&Triple::new(&tail, &rest, &head));
+
+        let mut items = graph.list_iter(&head);
+
+        assert_eq!(items.next().unwrap().unwrap(), item);
+        assert_eq!(items.next().unwrap().unwrap(), item);
+        assert!(matches!(
+            items.next().unwrap().unwrap_err().error_type(),
+            ErrorType::InvalidList
+        ));
+        assert!(items.next().is_none());
+    }
+
+    #[test]
+    fn types_of() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_uri_node(&Uri::new("http://example.org/s".to_string()));
+        let other = graph.create_uri_node(&Uri::new("http://example.org/other".to_string()));
+        let rdf_type = graph.create_uri_node(&RdfSyntaxDataTypes::A.to_uri());
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let person = graph.create_uri_node(&Uri::new("http://example.org/Person".to_string()));
+        let agent = graph.create_uri_node(&Uri::new("http://example.org/Agent".to_string()));
+
+        graph.add_triple(&Triple::new(&subject, &rdf_type, &person));
+        graph.add_triple(&Triple::new(&subject, &predicate, &other));
+        graph.add_triple(&Triple::new(&other, &rdf_type, &agent));
+        graph.add_triple(&Triple::new(&subject, &rdf_type, &agent));
+
+        assert_eq!(graph.types_of(&subject), vec![&person, &agent]);
+        assert_eq!(graph.types_of(&other), vec![&agent]);
+        assert!(graph.types_of(&person).is_empty());
+    }
+
+    #[test]
+    fn orphan_blank_nodes() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_uri_node(&Uri::new("http://example.org/s".to_string()));
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let described = graph.create_blank_node();
+        let orphan = graph.create_blank_node();
+        let name = graph.create_literal_node("name".to_string());
+
+        graph.add_triple(&Triple::new(&subject, &predicate, &described));
+        graph.add_triple(&Triple::new(&described, &predicate, &name));
+        graph.add_triple(&Triple::new(&described, &predicate, &orphan));
+        graph.add_triple(&Triple::new(&subject, &predicate, &orphan));
+
+        assert_eq!(graph.orphan_blank_nodes(), vec![&orphan]);
+    }
+
+    #[test]
+    fn blank_node_usage() {
+        let mut graph = Graph::new(None);
+
+        let shared = graph.create_blank_node_with_id("shared".to_string());
+        let other = graph.create_blank_node_with_id("other".to_string());
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let literal = graph.create_literal_node("literal".to_string());
+
+        graph.add_triple(&Triple::new(&shared, &predicate, &literal));
+        graph.add_triple(&Triple::new(&other, &predicate, &shared));
+        graph.add_triple(&Triple::new(&shared, &predicate, &shared));
+
+        let usage = graph.blank_node_usage();
+
+        assert_eq!(usage.len(), 2);
+        assert_eq!(usage.get("shared"), Some(&3));
+        assert_eq!(usage.get("other"), Some(&1));
+        assert!(Graph::new(None).blank_node_usage().is_empty());
+    }
+
+    #[test]
+    fn functional_violations() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_uri_node(&Uri::new("http://example.org/s".to_string()));
+        let other = graph.create_uri_node(&Uri::new("http://example.org/other".to_string()));
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let unrelated = graph.create_uri_node(&Uri::new("http://example.org/q".to_string()));
+        let first = graph.create_literal_node("first".to_string());
+        let second = graph.create_literal_node("second".to_string());
+
+        graph.add_triple(&Triple::new(&subject, &predicate, &first));
+        graph.add_triple(&Triple::new(&other, &predicate, &first));
+        graph.add_triple(&Triple::new(&other, &predicate, &first));
+        graph.add_triple(&Triple::new(&other, &unrelated, &second));
+        graph.add_triple(&Triple::new(&subject, &predicate, &second));
+
+        assert_eq!(
+            graph.functional_violations(&predicate),
+            vec![(subject, vec![first, second])]
+        );
+        assert!(graph.functional_violations(&unrelated).is_empty());
+    }
+
+    #[test]
+    fn literals_matching_lang() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_uri_node(&Uri::new("http://example.org/s".to_string()));
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/label".to_string()));
+
+        for (literal, language) in [("color", "en-US"), ("couleur", "fr"), ("colour", "EN")] {
+            let object =
+                graph.create_literal_node_with_language(literal.to_string(), language.to_string());
+            graph.add_triple(&Triple::new(&subject, &predicate, &object));
+        }
+
+        graph.add_triple(&Triple::new(
+            &subject,
+            &predicate,
+            &graph.create_literal_node("plain".to_string()),
+        ));
+
+        let matches = |range| {
+            graph
+                .literals_matching_lang(range)
+                .map(|(_, literal, _)| literal)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(matches("en"), vec!["color", "colour"]);
+        assert_eq!(matches("en-us"), vec!["color"]);
+        assert_eq!(matches("fr"), vec!["couleur"]);
+        assert_eq!(matches("*"), vec!["color", "couleur", "colour"]);
+        assert!(matches("e").is_empty());
+        assert!(matches("en-GB").is_empty());
+    }
+
+    #[test]
+    fn check_cardinality() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_uri_node(&Uri::new("http://example.org/s".to_string()));
+        let other = graph.create_uri_node(&Uri::new("http://example.org/other".to_string()));
+        let name = graph.create_uri_node(&Uri::new("http://example.org/name".to_string()));
+        let email = graph.create_uri_node(&Uri::new("http://example.org/email".to_string()));
+        let knows = graph.create_uri_node(&Uri::new("http://example.org/knows".to_string()));
+
+        let literal = graph.create_literal_node("literal".to_string());
+
+        graph.add_triple(&Triple::new(&subject, &knows, &other));
+        graph.add_triple(&Triple::new(&subject, &knows, &subject));
+        graph.add_triple(&Triple::new(&other, &name, &literal));
+        graph.add_triple(&Triple::new(&subject, &email, &literal));
+
+        let constraints = vec![
+            (name.clone(), 1, Some(1)),
+            (email.clone(), 0, Some(1)),
+            (knows.clone(), 0, Some(1)),
+        ];
+
+        assert_eq!(
+            graph.check_cardinality(&subject, &constraints),
+            vec![
+                Violation { predicate: name, count: 0, min: 1, max: Some(1) },
+                Violation { predicate: knows, count: 2, min: 0, max: Some(1) },
+            ]
+        );
+        assert!(graph.check_cardinality(&other, &constraints).is_empty());
+    }
+
+    #[test]
+    fn grouped_by_subject() {
+        let mut graph = Graph::new(None);
+
+        let first = graph.create_uri_node(&Uri::new("http://example.org/first".to_string()));
+        let second = graph.create_blank_node();
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let name = graph.create_literal_node("name".to_string());
+
+        let triple_1 = Triple::new(&first, &predicate, &name);
+        let triple_2 = Triple::new(&second, &predicate, &first);
+        let triple_3 = Triple::new(&first, &predicate, &second);
+
+        graph.add_triples(&[triple_1.clone(), triple_2.clone(), triple_3.clone()]);
+
+        let groups: Vec<(&Node, Vec<&Triple>)> = graph.grouped_by_subject().collect();
+
+        assert_eq!(
+            groups,
+            vec![
+                (&first, vec![&triple_1, &triple_3]),
+                (&second, vec![&triple_2])
+            ]
+        );
+        assert_eq!(Graph::new(None).grouped_by_subject().count(), 0);
+    }
+
+    #[test]
+    fn predicate_histogram() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_uri_node(&Uri::new("http://example.org/s".to_string()));
+        let name = graph.create_uri_node(&Uri::new("http://example.org/name".to_string()));
+        let knows = graph.create_uri_node(&Uri::new("http://example.org/knows".to_string()));
+        let friend = graph.create_blank_node();
+        let other = graph.create_blank_node();
+
+        graph.add_triple(&Triple::new(&subject, &knows, &friend));
+        graph.add_triple(&Triple::new(&subject, &name, &graph.create_literal_node("s".to_string())));
+        graph.add_triple(&Triple::new(&friend, &knows, &other));
+        graph.add_triple(&Triple::new(&other, &knows, &subject));
+
+        let histogram = graph.predicate_histogram();
+
+        assert_eq!(histogram.len(), 2);
+        assert_eq!(histogram[&knows], 3);
+        assert_eq!(histogram[&name], 1);
+        assert!(Graph::new(None).predicate_histogram().is_empty());
+    }
+
+    #[test]
+    fn triples_matching() {
+        let mut graph = Graph::new(None);
+
+        let s = graph.create_uri_node(&Uri::new("http://example.org/s".to_string()));
+        let t = graph.create_blank_node();
+        let p = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let q = graph.create_uri_node(&Uri::new("http://example.org/q".to_string()));
+        let o = graph.create_literal_node("o".to_string());
+
+        let spo = Triple::new(&s, &p, &o);
+        let sqt = Triple::new(&s, &q, &t);
+        let tpo = Triple::new(&t, &p, &o);
+        let tqo = Triple::new(&t, &q, &o);
+
+        graph.add_triples(&[spo.clone(), sqt.clone(), tpo.clone(), tqo.clone()]);
+
+        assert_eq!(graph.triples_matching(None, None, None), vec![&spo, &sqt, &tpo, &tqo]);
+        assert_eq!(graph.triples_matching(Some(&s), None, None), vec![&spo, &sqt]);
+        assert_eq!(graph.triples_matching(None, Some(&q), None), vec![&sqt, &tqo]);
+        assert_eq!(graph.triples_matching(None, None, Some(&o)), vec![&spo, &tpo, &tqo]);
+        assert_eq!(graph.triples_matching(Some(&t), Some(&p), None), vec![&tpo]);
+        assert_eq!(graph.triples_matching(Some(&s), None, Some(&t)), vec![&sqt]);
+        assert_eq!(graph.triples_matching(None, Some(&q), Some(&o)), vec![&tqo]);
+        assert_eq!(graph.triples_matching(Some(&t), Some(&q), Some(&o)), vec![&tqo]);
+        assert!(graph.triples_matching(Some(&s), Some(&p), Some(&t)).is_empty());
+        assert!(graph.triples_matching(Some(&o), None, None).is_empty());
+    }
+
+    #[test]
+    fn write_matching() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_uri_node(&Uri::new("http://example.org/s".to_string()));
+        let name = graph.create_uri_node(&Uri::new("http://example.org/name".to_string()));
+        let knows = graph.create_uri_node(&Uri::new("http://example.org/knows".to_string()));
+        let friend = graph.create_blank_node_with_id("friend".to_string());
+
+        graph.add_triple(&Triple::new(&subject, &name, &graph.create_literal_node("s".to_string())));
+        graph.add_triple(&Triple::new(&subject, &knows, &friend));
+        graph.add_triple(&Triple::new(&friend, &name, &graph.create_literal_node("f".to_string())));
+
+        let mut output: Vec<u8> = Vec::new();
+
+        assert_eq!(graph.write_matching(&mut output, None, Some(&name), None).unwrap(), 2);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "<http://example.org/s> <http://example.org/name> \"s\" .\n\
+             _:friend <http://example.org/name> \"f\" .\n"
+        );
+
+        let mut output: Vec<u8> = Vec::new();
+
+        assert_eq!(
+            graph.write_matching(&mut output, Some(&friend), Some(&knows), None).unwrap(),
+            0
+        );
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn diff() {
+        let mut graph = Graph::new(None);
+        let mut other = Graph::new(None);
+
+        let subject = graph.create_uri_node(&Uri::new("http://example.org/s".to_string()));
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+
+        let kept = Triple::new(&subject, &predicate, &graph.create_literal_node("kept".to_string()));
+        let removed = Triple::new(&subject, &predicate, &graph.create_literal_node("old".to_string()));
+        let added = Triple::new(&subject, &predicate, &graph.create_literal_node("new".to_string()));
+
+        graph.add_triples(&[kept.clone(), removed.clone(), removed.clone()]);
+        other.add_triples(&[added.clone(), kept]);
+
+        let diff = graph.diff(&other);
+
+        assert_eq!(diff.added, vec![added.clone()]);
+        assert_eq!(diff.removed, vec![removed.clone()]);
+
+        let reversed = other.diff(&graph);
+
+        assert_eq!(reversed.added, vec![removed]);
+        assert_eq!(reversed.removed, vec![added]);
+        assert!(graph.diff(&graph).is_empty());
+    }
+
+    #[test]
+    fn rebase_namespace() {
+        let mut graph = Graph::new(None);
+
+        let uri = |uri: &str| Node::UriNode {
+            uri: Uri::new(uri.to_string()),
+        };
+
+        let old_subject = uri("http://old.example.org/s");
+        let new_subject = uri("http://new.example.org/s");
+        let old_predicate = uri("http://old.example.org/p");
+        let new_predicate = uri("http://new.example.org/p");
+        let partial = uri("http://example.org/http://old.example.org/o");
+        let old_typed = graph.create_literal_node_with_data_type(
+            "1".to_string(),
+            &Uri::new("http://old.example.org/type".to_string()),
+        );
+        let new_typed = graph.create_literal_node_with_data_type(
+            "1".to_string(),
+            &Uri::new("http://new.example.org/type".to_string()),
+        );
+
+        graph.add_triples(&[
+            Triple::new(&old_subject, &old_predicate, &partial),
+            Triple::new(&old_subject, &old_predicate, &old_typed),
+            Triple::new(&new_subject, &new_predicate, &new_typed),
+            Triple::new(&partial, &uri("http://example.org/p"), &partial),
+        ]);
+
+        assert_eq!(
+            graph.rebase_namespace("http://old.example.org/", "http://new.example.org/"),
+            2
+        );
+
+        let triples: Vec<&Triple> = graph.triples_iter().collect();
+
+        // the rewritten typed literal triple equals an existing one and is only kept once
+        assert_eq!(
+            triples,
+            vec![
+                &Triple::new(&new_subject, &new_predicate, &partial),
+                &Triple::new(&new_subject, &new_predicate, &new_typed),
+                &Triple::new(&partial, &uri("http://example.org/p"), &partial),
+            ]
+        );
+        assert_eq!(graph.rebase_namespace("http://old.example.org/", "http://new.example.org/"), 0);
+    }
+
+    #[test]
+    fn reachable_from_cycle() {
+        let mut graph = Graph::new(None);
+
+        let a = graph.create_uri_node(&Uri::new("http://example.org/a".to_string()));
+        let b = graph.create_blank_node();
+        let c = graph.create_uri_node(&Uri::new("http://example.org/c".to_string()));
+        let unrelated = graph.create_uri_node(&Uri::new("http://example.org/d".to_string()));
+        let next = graph.create_uri_node(&Uri::new("http://example.org/next".to_string()));
+        let name = graph.create_literal_node("a".to_string());
+
+        graph.add_triple(&Triple::new(&a, &next, &b));
+        graph.add_triple(&Triple::new(&a, &next, &name));
+        graph.add_triple(&Triple::new(&b, &next, &c));
+        graph.add_triple(&Triple::new(&c, &next, &a));
+        graph.add_triple(&Triple::new(&unrelated, &next, &a));
+
+        assert_eq!(graph.reachable_from(&a, None).count(), 4);
+        assert_eq!(graph.reachable_from(&b, None).count(), 4);
+        assert_eq!(graph.reachable_from(&a, Some(2)).count(), 3);
+        assert!(graph.reachable_from(&a, Some(0)).is_empty());
+        assert!(graph
+            .reachable_from(&a, None)
+            .get_triples_with_subject(&unrelated)
+            .is_empty());
     }
 }
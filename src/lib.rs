@@ -87,10 +87,14 @@
 
 use std::result;
 
+pub mod dataset;
+#[cfg(feature = "disk_graph")]
+pub mod disk_graph;
 pub mod error;
 pub mod graph;
 pub mod namespace;
 pub mod node;
+pub mod quad;
 pub mod triple;
 pub mod uri;
 
@@ -98,18 +102,23 @@ pub type Result<T> = result::Result<T, error::Error>;
 
 pub mod writer {
     pub mod formatter {
+        pub mod json_formatter;
         pub mod n_triples_formatter;
         pub mod rdf_formatter;
         pub mod turtle_formatter;
     }
 
+    pub mod json_triples_writer;
     pub mod n_triples_writer;
+    pub mod rdf_json_writer;
     pub mod rdf_writer;
     pub mod turtle_writer;
+    pub mod writer_options;
 }
 
 pub mod reader {
     pub mod lexer {
+        pub mod n_quads_lexer;
         pub mod n_triples_lexer;
         pub mod rdf_lexer;
         pub mod sparql_lexer;
@@ -117,10 +126,22 @@ pub mod reader {
         pub mod turtle_lexer;
     }
 
+    pub mod dataset_loader;
     pub mod input_reader;
+    pub mod literal_pool;
+    pub mod n_quads_parser;
+    pub mod n_triples_line_parser;
     pub mod n_triples_parser;
+    pub mod n_triples_sorter;
+    pub mod parse_stats;
+    pub mod parser_builder;
+    pub mod parser_context;
+    pub mod parser_options;
+    pub mod rdf_json_parser;
     pub mod rdf_parser;
+    pub mod sorted_diff;
     pub mod sparql_parser;
+    pub mod tee_parser;
     pub mod turtle_parser;
 }
 
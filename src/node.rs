@@ -1,7 +1,13 @@
+use crate::error::{Error, ErrorType};
+use crate::reader::lexer::token::Token;
+use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+use crate::specs::xml_specs::{XmlDataTypes, XmlDate, XmlTime};
 use crate::uri::Uri;
+use crate::Result;
+use std::collections::HashMap;
 
 /// Node representation.
-#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug)]
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug)]
 pub enum Node {
     /// Node for representing a URI.
     UriNode { uri: Uri },
@@ -17,9 +23,262 @@ pub enum Node {
     BlankNode { id: String },
 }
 
+impl Node {
+    /// Returns the value of an `xsd:date` literal.
+    ///
+    /// Returns `None` if the node is not a literal of this data type or the literal is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::node::Node;
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    ///
+    /// let node = Node::LiteralNode {
+    ///   literal: "2023-06-15".to_string(),
+    ///   data_type: Some(XmlDataTypes::Date.to_uri()),
+    ///   language: None
+    /// };
+    ///
+    /// assert_eq!(node.as_date().unwrap().month, 6);
+    /// ```
+    pub fn as_date(&self) -> Option<XmlDate> {
+        self.typed_literal(XmlDataTypes::Date).and_then(XmlDate::parse)
+    }
+
+    /// Returns the value of an `xsd:time` literal.
+    ///
+    /// Returns `None` if the node is not a literal of this data type or the literal is invalid.
+    pub fn as_time(&self) -> Option<XmlTime> {
+        self.typed_literal(XmlDataTypes::Time).and_then(XmlTime::parse)
+    }
+
+    /// Returns the lexical value of an `xsd:anyURI` literal.
+    ///
+    /// Returns `None` if the node is not a literal of this data type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::node::Node;
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    ///
+    /// let node = Node::LiteralNode {
+    ///   literal: "http://example.org/".to_string(),
+    ///   data_type: Some(XmlDataTypes::AnyUri.to_uri()),
+    ///   language: None
+    /// };
+    ///
+    /// assert_eq!(node.as_uri(), Some("http://example.org/"));
+    /// ```
+    pub fn as_uri(&self) -> Option<&str> {
+        self.typed_literal(XmlDataTypes::AnyUri)
+    }
+
+    /// Returns `true` if both nodes denote the same value.
+    ///
+    /// This is value equality, not term equality as implemented by `==`: numeric literals
+    /// are compared by their parsed values, hence `"1"^^xsd:integer` equals
+    /// `"1.0"^^xsd:decimal` and `"1E0"^^xsd:double`. As in XML Schema, `NaN` is not equal to
+    /// any value, including itself. All other nodes, including literals with invalid numeric
+    /// values, are compared by term equality.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::node::Node;
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    ///
+    /// let integer = Node::LiteralNode {
+    ///   literal: "1".to_string(),
+    ///   data_type: Some(XmlDataTypes::Integer.to_uri()),
+    ///   language: None
+    /// };
+    /// let decimal = Node::LiteralNode {
+    ///   literal: "1.0".to_string(),
+    ///   data_type: Some(XmlDataTypes::Decimal.to_uri()),
+    ///   language: None
+    /// };
+    ///
+    /// assert!(integer != decimal);
+    /// assert!(integer.value_equals(&decimal));
+    /// ```
+    pub fn value_equals(&self, other: &Node) -> bool {
+        match (self.numeric_value(), other.numeric_value()) {
+            (Some(NumericValue::Decimal(a)), Some(NumericValue::Decimal(b))) => a == b,
+            (Some(a), Some(b)) => a.to_f64() == b.to_f64(),
+            _ => self == other,
+        }
+    }
+
+    /// Creates the node denoted by a token of the lexer.
+    ///
+    /// QNames are resolved using the provided namespaces and relative URIs, e.g. `#a` or
+    /// `../a`, are resolved against the base URI as defined by RFC 3986, if a base URI is
+    /// provided. The keyword `a` denotes `rdf:type`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::node::Node;
+    /// use rdf::reader::lexer::token::Token;
+    /// use rdf::uri::Uri;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut namespaces = HashMap::new();
+    /// namespaces.insert("ex".to_string(), Uri::new("http://example.org/".to_string()));
+    ///
+    /// let token = Token::QName("ex".to_string(), "a".to_string());
+    ///
+    /// assert_eq!(Node::try_from_token(token, &namespaces, None).unwrap(),
+    ///            Node::UriNode { uri: Uri::new("http://example.org/a".to_string()) });
+    /// assert!(Node::try_from_token(Token::TripleDelimiter, &namespaces, None).is_err());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The token does not denote an RDF term.
+    /// - No namespace exists for the prefix of a QName.
+    ///
+    pub fn try_from_token(
+        token: Token,
+        namespaces: &HashMap<String, Uri>,
+        base: Option<&str>,
+    ) -> Result<Node> {
+        let resolve_qname = |prefix: &str, path: &str| match namespaces.get(prefix) {
+            Some(namespace) => {
+                let mut uri = namespace.to_owned();
+                uri.append_resource_path(&path.replace(":", "/")); // adjust the QName path to URI path
+                Ok(uri)
+            }
+            None => Err(Error::new(
+                ErrorType::InvalidNamespace,
+                "Namespace does not exists for prefix: ".to_string() + prefix,
+            )),
+        };
+
+        match token {
+            Token::Uri(uri) => {
+                let uri = Uri::new(uri);
+
+                let uri = match base {
+                    Some(base) if !uri.is_absolute_iri() => {
+                        Uri::new(base.to_string()).resolve(uri.to_string())
+                    }
+                    _ => uri,
+                };
+
+                Ok(Node::UriNode { uri })
+            }
+            Token::QName(prefix, path) => Ok(Node::UriNode {
+                uri: resolve_qname(&prefix, &path)?,
+            }),
+            Token::KeywordA => Ok(Node::UriNode {
+                uri: RdfSyntaxDataTypes::A.to_uri(),
+            }),
+            Token::BlankNode(id) => Ok(Node::BlankNode { id }),
+            Token::Literal(literal) => Ok(Node::LiteralNode {
+                literal,
+                data_type: None,
+                language: None,
+            }),
+            Token::LiteralWithLanguageSpecification(literal, language) => Ok(Node::LiteralNode {
+                literal,
+                data_type: None,
+                language: Some(language),
+            }),
+            Token::LiteralWithUrlDatatype(literal, data_type) => Ok(Node::LiteralNode {
+                literal,
+                data_type: Some(Uri::new(data_type)),
+                language: None,
+            }),
+            Token::LiteralWithQNameDatatype(literal, prefix, path) => Ok(Node::LiteralNode {
+                literal,
+                data_type: Some(resolve_qname(&prefix, &path)?),
+                language: None,
+            }),
+            Token::IntegerLiteral(literal) => Ok(Node::LiteralNode {
+                literal,
+                data_type: Some(XmlDataTypes::Integer.to_uri()),
+                language: None,
+            }),
+            Token::DecimalLiteral(literal) => Ok(Node::LiteralNode {
+                literal,
+                data_type: Some(XmlDataTypes::Decimal.to_uri()),
+                language: None,
+            }),
+            Token::DoubleLiteral(literal) => Ok(Node::LiteralNode {
+                literal,
+                data_type: Some(XmlDataTypes::Double.to_uri()),
+                language: None,
+            }),
+            token => Err(Error::new(
+                ErrorType::InvalidToken,
+                format!("Token does not denote an RDF term: {:?}", token),
+            )),
+        }
+    }
+
+    /// Returns the value of a numeric literal, `None` for all other nodes and invalid literals.
+    fn numeric_value(&self) -> Option<NumericValue> {
+        let (literal, data_type) = match *self {
+            Node::LiteralNode {
+                ref literal,
+                data_type: Some(ref data_type),
+                ..
+            } => (literal, data_type.to_string()),
+            _ => return None,
+        };
+
+        let canonical = XmlDataTypes::canonical_numeric_form(data_type, literal)?;
+
+        if data_type.ends_with("#double") || data_type.ends_with("#float") {
+            canonical.parse().ok().map(NumericValue::Double)
+        } else {
+            // integers are a subset of decimals, hence both are compared in decimal form
+            XmlDataTypes::canonical_numeric_form(&XmlDataTypes::Decimal.to_string(), &canonical)
+                .map(NumericValue::Decimal)
+        }
+    }
+
+    /// Returns the literal if the node is a literal of the provided data type.
+    fn typed_literal(&self, data_type: XmlDataTypes) -> Option<&str> {
+        match *self {
+            Node::LiteralNode {
+                ref literal,
+                data_type: Some(ref literal_type),
+                ..
+            } if *literal_type == data_type.to_uri() => Some(literal),
+            _ => None,
+        }
+    }
+}
+
+/// Value of a numeric literal.
+enum NumericValue {
+    /// Canonical form of an `xsd:decimal` or one of the integer data types.
+    Decimal(String),
+
+    /// Value of an `xsd:double` or `xsd:float`.
+    Double(f64),
+}
+
+impl NumericValue {
+    /// Returns the value as floating point number.
+    fn to_f64(&self) -> f64 {
+        match *self {
+            NumericValue::Decimal(ref decimal) => decimal.parse().unwrap_or(f64::NAN),
+            NumericValue::Double(double) => double,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::error::ErrorType;
     use crate::node::*;
+    use crate::reader::lexer::token::Token;
+    use std::collections::HashMap;
 
     #[test]
     fn access_literal_node() {
@@ -35,7 +294,239 @@ mod tests {
                 data_type: _,
                 language: _,
             } => assert_eq!(lit, "abcd".to_string()),
-            _ => assert!(false),
+            _ => panic!("Expected literal node"),
         }
     }
+
+    #[test]
+    fn literal_node_as_date() {
+        let node = Node::LiteralNode {
+            literal: "2023-06-15".to_string(),
+            data_type: Some(XmlDataTypes::Date.to_uri()),
+            language: None,
+        };
+
+        assert_eq!(
+            node.as_date(),
+            Some(XmlDate {
+                year: 2023,
+                month: 6,
+                day: 15,
+                timezone: None,
+            })
+        );
+        assert_eq!(node.as_time(), None);
+
+        let node = Node::LiteralNode {
+            literal: "2023-06-15-05:30".to_string(),
+            data_type: Some(XmlDataTypes::Date.to_uri()),
+            language: None,
+        };
+
+        assert_eq!(node.as_date().unwrap().timezone, Some(-330));
+    }
+
+    #[test]
+    fn literal_node_as_time() {
+        let node = Node::LiteralNode {
+            literal: "13:45:00Z".to_string(),
+            data_type: Some(XmlDataTypes::Time.to_uri()),
+            language: None,
+        };
+
+        assert_eq!(
+            node.as_time(),
+            Some(XmlTime {
+                hour: 13,
+                minute: 45,
+                second: 0,
+                nanosecond: 0,
+                timezone: Some(0),
+            })
+        );
+        assert_eq!(node.as_date(), None);
+
+        let node = Node::LiteralNode {
+            literal: "13:45:00Z".to_string(),
+            data_type: None,
+            language: None,
+        };
+
+        assert_eq!(node.as_time(), None);
+    }
+
+    #[test]
+    fn node_from_term_tokens() {
+        let mut namespaces = HashMap::new();
+        namespaces.insert(
+            "xsd".to_string(),
+            Uri::new("http://www.w3.org/2001/XMLSchema#".to_string()),
+        );
+        let base = Some("http://example.org/doc");
+
+        let uri = |uri: &str| Node::UriNode {
+            uri: Uri::new(uri.to_string()),
+        };
+        let from_token = |token| Node::try_from_token(token, &namespaces, base).unwrap();
+
+        assert_eq!(
+            from_token(Token::Uri("http://example.org/a".to_string())),
+            uri("http://example.org/a")
+        );
+        assert_eq!(
+            from_token(Token::Uri("#a".to_string())),
+            uri("http://example.org/doc#a")
+        );
+        assert_eq!(
+            from_token(Token::Uri("foo".to_string())),
+            uri("http://example.org/foo")
+        );
+        assert_eq!(
+            Node::try_from_token(
+                Token::Uri("../x".to_string()),
+                &namespaces,
+                Some("http://example.org/a/b/doc")
+            )
+            .unwrap(),
+            uri("http://example.org/a/x")
+        );
+        assert_eq!(
+            from_token(Token::QName("xsd".to_string(), "string".to_string())),
+            uri("http://www.w3.org/2001/XMLSchema#string")
+        );
+        assert_eq!(
+            from_token(Token::KeywordA),
+            uri("http://www.w3.org/1999/02/22-rdf-syntax-ns#type")
+        );
+        assert_eq!(
+            from_token(Token::BlankNode("b".to_string())),
+            Node::BlankNode {
+                id: "b".to_string()
+            }
+        );
+        assert_eq!(
+            from_token(Token::Literal("l".to_string())),
+            Node::LiteralNode {
+                literal: "l".to_string(),
+                data_type: None,
+                language: None,
+            }
+        );
+        assert_eq!(
+            from_token(Token::LiteralWithLanguageSpecification(
+                "l".to_string(),
+                "en".to_string()
+            )),
+            Node::LiteralNode {
+                literal: "l".to_string(),
+                data_type: None,
+                language: Some("en".to_string()),
+            }
+        );
+        assert_eq!(
+            from_token(Token::LiteralWithUrlDatatype(
+                "1".to_string(),
+                XmlDataTypes::Integer.to_string()
+            )),
+            Node::LiteralNode {
+                literal: "1".to_string(),
+                data_type: Some(XmlDataTypes::Integer.to_uri()),
+                language: None,
+            }
+        );
+        assert_eq!(
+            from_token(Token::LiteralWithQNameDatatype(
+                "1".to_string(),
+                "xsd".to_string(),
+                "integer".to_string()
+            )),
+            Node::LiteralNode {
+                literal: "1".to_string(),
+                data_type: Some(XmlDataTypes::Integer.to_uri()),
+                language: None,
+            }
+        );
+    }
+
+    #[test]
+    fn node_from_non_term_tokens() {
+        let namespaces = HashMap::new();
+
+        match Node::try_from_token(Token::TripleDelimiter, &namespaces, None) {
+            Ok(node) => panic!("Delimiter must not be converted: {:?}", node),
+            Err(e) => assert!(matches!(e.error_type(), ErrorType::InvalidToken)),
+        }
+
+        match Node::try_from_token(Token::QName("ex".to_string(), "a".to_string()), &namespaces, None) {
+            Ok(node) => panic!("Unknown prefix must be rejected: {:?}", node),
+            Err(e) => assert!(matches!(e.error_type(), ErrorType::InvalidNamespace)),
+        }
+    }
+
+    #[test]
+    fn any_uri_literal_as_uri() {
+        let literal = |data_type: Option<XmlDataTypes>| Node::LiteralNode {
+            literal: "http://example.org/".to_string(),
+            data_type: data_type.map(|data_type| data_type.to_uri()),
+            language: None,
+        };
+
+        assert_eq!(literal(Some(XmlDataTypes::AnyUri)).as_uri(), Some("http://example.org/"));
+        assert_eq!(literal(Some(XmlDataTypes::String)).as_uri(), None);
+        assert_eq!(literal(None).as_uri(), None);
+        assert_eq!(
+            Node::UriNode {
+                uri: Uri::new("http://example.org/".to_string())
+            }
+            .as_uri(),
+            None
+        );
+    }
+
+    #[test]
+    fn literal_nodes_equal_by_value() {
+        let literal = |literal: &str, data_type: XmlDataTypes| Node::LiteralNode {
+            literal: literal.to_string(),
+            data_type: Some(data_type.to_uri()),
+            language: None,
+        };
+
+        let integer = literal("1", XmlDataTypes::Integer);
+
+        assert!(integer.value_equals(&literal("1.0", XmlDataTypes::Decimal)));
+        assert!(integer.value_equals(&literal("+001", XmlDataTypes::Int)));
+        assert!(integer.value_equals(&literal("1E0", XmlDataTypes::Double)));
+
+        let half = literal("0.5", XmlDataTypes::Decimal);
+        let infinity = literal("INF", XmlDataTypes::Double);
+
+        assert!(half.value_equals(&literal("5e-1", XmlDataTypes::Double)));
+        assert!(infinity.value_equals(&literal("+INF", XmlDataTypes::Double)));
+    }
+
+    #[test]
+    fn literal_nodes_differ_by_value() {
+        let literal = |literal: &str, data_type: XmlDataTypes| Node::LiteralNode {
+            literal: literal.to_string(),
+            data_type: Some(data_type.to_uri()),
+            language: None,
+        };
+
+        let integer = literal("1", XmlDataTypes::Integer);
+        let nan = literal("NaN", XmlDataTypes::Double);
+
+        assert!(!integer.value_equals(&literal("1.01", XmlDataTypes::Decimal)));
+        assert!(!integer.value_equals(&literal("-1", XmlDataTypes::Integer)));
+        assert!(!integer.value_equals(&literal("1", XmlDataTypes::String)));
+        assert!(!integer.value_equals(&literal("1.0", XmlDataTypes::Integer)));
+        assert!(!nan.value_equals(&nan));
+
+        // non-numeric nodes and invalid numeric literals fall back to term equality
+        let invalid = literal("one", XmlDataTypes::Integer);
+        let blank = Node::BlankNode { id: "b".to_string() };
+
+        assert!(invalid.value_equals(&invalid.clone()));
+        assert!(blank.value_equals(&blank.clone()));
+        assert!(!literal("a", XmlDataTypes::String).value_equals(&invalid));
+    }
 }
@@ -0,0 +1,105 @@
+use crate::node::Node;
+use crate::triple::Triple;
+
+/// Quad representation, a triple together with the graph it belongs to.
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Debug)]
+pub struct Quad {
+    subject: Node,
+    predicate: Node,
+    object: Node,
+    graph: Option<Node>,
+}
+
+impl Quad {
+    /// Constructor for Quad struct.
+    ///
+    /// Requires subject, predicate and object nodes and the name of the graph, which is
+    /// `None` for the default graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::node::Node;
+    /// use rdf::quad::Quad;
+    /// use rdf::uri::Uri;
+    ///
+    /// let subject = Node::BlankNode { id: "a".to_string() };
+    /// let predicate = Node::UriNode { uri: Uri::new("http://example.org/show/localName".to_string()) } ;
+    /// let object = Node::BlankNode { id: "b".to_string() };
+    /// let graph = Node::UriNode { uri: Uri::new("http://example.org/graph".to_string()) } ;
+    ///
+    /// let quad = Quad::new(&subject, &predicate, &object, Some(&graph));
+    ///
+    /// assert_eq!(quad.graph(), Some(&graph));
+    /// ```
+    pub fn new(subject: &Node, predicate: &Node, object: &Node, graph: Option<&Node>) -> Quad {
+        Quad {
+            subject: subject.clone(),
+            predicate: predicate.clone(),
+            object: object.clone(),
+            graph: graph.cloned(),
+        }
+    }
+
+    /// Constructor for a quad of the provided triple and graph name.
+    pub fn from_triple(triple: &Triple, graph: Option<&Node>) -> Quad {
+        Quad::new(triple.subject(), triple.predicate(), triple.object(), graph)
+    }
+
+    /// Returns a reference to the subject node of the quad.
+    pub fn subject(&self) -> &Node {
+        &self.subject
+    }
+
+    /// Returns a reference to the predicate node of the quad.
+    pub fn predicate(&self) -> &Node {
+        &self.predicate
+    }
+
+    /// Returns a reference to the object node of the quad.
+    pub fn object(&self) -> &Node {
+        &self.object
+    }
+
+    /// Returns a reference to the graph name of the quad, `None` for the default graph.
+    pub fn graph(&self) -> Option<&Node> {
+        self.graph.as_ref()
+    }
+
+    /// Returns the triple of the quad without the graph name.
+    pub fn to_triple(&self) -> Triple {
+        Triple::new(&self.subject, &self.predicate, &self.object)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::*;
+    use crate::quad::Quad;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+
+    #[test]
+    fn quad_of_triple() {
+        let subject = Node::BlankNode {
+            id: "a".to_string(),
+        };
+        let predicate = Node::UriNode {
+            uri: Uri::new("http://example.org/p".to_string()),
+        };
+        let object = Node::BlankNode {
+            id: "b".to_string(),
+        };
+        let graph = Node::BlankNode {
+            id: "g".to_string(),
+        };
+
+        let triple = Triple::new(&subject, &predicate, &object);
+        let quad = Quad::from_triple(&triple, Some(&graph));
+
+        assert_eq!(quad, Quad::new(&subject, &predicate, &object, Some(&graph)));
+        assert_eq!(quad.to_triple(), triple);
+        assert_ne!(quad, Quad::from_triple(&triple, None));
+        assert!(Quad::from_triple(&triple, None).graph().is_none());
+    }
+}
@@ -0,0 +1,403 @@
+use crate::dataset::Dataset;
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::reader::n_quads_parser::NQuadsParser;
+use crate::reader::parser_options::ParserOptions;
+use crate::reader::rdf_parser::RdfParser;
+use crate::reader::turtle_parser::TurtleParser;
+use crate::triple::Triple;
+use crate::Result;
+use std::io::Read;
+
+/// Determines whether blank nodes of different sources denote the same node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlankNodeScope {
+    /// Blank nodes with the same label denote the same node, regardless of their source.
+    ///
+    /// Blank nodes that the Turtle parser generates for `[]` and collections have no label in
+    /// the input, hence their labels are still prefixed like with `PerSource` to keep them
+    /// distinct.
+    Global,
+
+    /// Blank nodes are scoped to their source. Labels are prefixed with `s` and the
+    /// number of previously loaded sources to keep them distinct, e.g. `_:x` of the
+    /// second source becomes `_:s1_x`.
+    PerSource,
+}
+
+/// Renames the blank nodes of one loaded source.
+struct SourceScope {
+    /// Prefix of the renamed labels, e.g. `s1_` for the second source.
+    prefix: String,
+
+    /// Rename all blank nodes, otherwise only the parser prefixes the generated blank nodes.
+    all_blank_nodes: bool,
+}
+
+impl SourceScope {
+    /// Prefixes the label of a blank node to be renamed, all other nodes are returned unchanged.
+    fn node(&self, node: &Node) -> Node {
+        match node {
+            Node::BlankNode { id } if self.all_blank_nodes => Node::BlankNode {
+                id: self.prefix.to_string() + id,
+            },
+            _ => node.clone(),
+        }
+    }
+
+    /// Returns the options for the Turtle parser of the source.
+    fn parser_options(&self) -> ParserOptions {
+        ParserOptions {
+            generated_blank_node_prefix: if self.all_blank_nodes {
+                None
+            } else {
+                Some(self.prefix.to_string())
+            },
+            ..Default::default()
+        }
+    }
+}
+
+/// Loads several RDF sources into one dataset.
+pub struct DatasetLoader {
+    dataset: Dataset,
+    blank_node_scope: BlankNodeScope,
+    loaded_sources: usize,
+}
+
+impl DatasetLoader {
+    /// Constructor of `DatasetLoader` using the provided blank node scope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::dataset_loader::{BlankNodeScope, DatasetLoader};
+    ///
+    /// let loader = DatasetLoader::new(BlankNodeScope::PerSource);
+    ///
+    /// assert!(loader.dataset().is_empty());
+    /// ```
+    pub fn new(blank_node_scope: BlankNodeScope) -> DatasetLoader {
+        DatasetLoader {
+            dataset: Dataset::new(),
+            blank_node_scope,
+            loaded_sources: 0,
+        }
+    }
+
+    /// Returns the blank node scope used for loading sources.
+    pub fn blank_node_scope(&self) -> BlankNodeScope {
+        self.blank_node_scope
+    }
+
+    /// Returns the dataset containing all loaded sources.
+    pub fn dataset(&self) -> &Dataset {
+        &self.dataset
+    }
+
+    /// Returns the dataset containing all loaded sources.
+    pub fn into_dataset(self) -> Dataset {
+        self.dataset
+    }
+
+    /// Parses N-Quads syntax and adds the statements to the dataset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::dataset_loader::{BlankNodeScope, DatasetLoader};
+    ///
+    /// let input = "_:x <http://example.org/p> _:y _:g .";
+    ///
+    /// let mut loader = DatasetLoader::new(BlankNodeScope::Global);
+    /// loader.load_n_quads(input.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(loader.dataset().graph_names().len(), 1);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with the N-Quads standard.
+    ///
+    pub fn load_n_quads<R: Read>(&mut self, input: R) -> Result<()> {
+        let source = NQuadsParser::from_reader(input).decode()?;
+        let scope = self.next_source_scope();
+
+        Self::add_triples(
+            self.dataset.default_graph_mut(),
+            source.default_graph(),
+            &scope,
+        );
+
+        for name in source.graph_names() {
+            if let Some(graph) = source.named_graph(name) {
+                let name = scope.node(name);
+                Self::add_triples(self.dataset.named_graph_mut(&name), graph, &scope);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parses Turtle or N-Triples syntax and adds the triples to the default graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::dataset_loader::{BlankNodeScope, DatasetLoader};
+    ///
+    /// let input = "_:x <http://example.org/p> [] .";
+    ///
+    /// let mut loader = DatasetLoader::new(BlankNodeScope::PerSource);
+    /// loader.load_turtle(input.as_bytes()).unwrap();
+    /// loader.load_turtle(input.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(loader.dataset().default_graph().count(), 2);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with the Turtle standard.
+    ///
+    pub fn load_turtle<R: Read>(&mut self, input: R) -> Result<()> {
+        let scope = self.next_source_scope();
+        let source = TurtleParser::with_options(input, scope.parser_options()).decode()?;
+
+        Self::add_triples(self.dataset.default_graph_mut(), &source, &scope);
+
+        Ok(())
+    }
+
+    /// Parses Turtle or N-Triples syntax and adds the triples to the named graph with the
+    /// provided name.
+    ///
+    /// A blank node used as name is scoped to the source like all other blank nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::node::Node;
+    /// use rdf::reader::dataset_loader::{BlankNodeScope, DatasetLoader};
+    /// use rdf::uri::Uri;
+    ///
+    /// let input = "_:x <http://example.org/p> \"o\" .";
+    /// let name = Node::UriNode { uri: Uri::new("http://example.org/g".to_string()) };
+    ///
+    /// let mut loader = DatasetLoader::new(BlankNodeScope::PerSource);
+    /// loader.load_named_turtle(&name, input.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(loader.dataset().named_graph(&name).unwrap().count(), 1);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with the Turtle standard.
+    ///
+    pub fn load_named_turtle<R: Read>(&mut self, name: &Node, input: R) -> Result<()> {
+        let scope = self.next_source_scope();
+        let source = TurtleParser::with_options(input, scope.parser_options()).decode()?;
+        let name = scope.node(name);
+
+        Self::add_triples(self.dataset.named_graph_mut(&name), &source, &scope);
+
+        Ok(())
+    }
+
+    /// Parses each source as Turtle or N-Triples syntax into the named graph with the
+    /// name of the source, in the provided order.
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input of a source that does not conform with the Turtle standard. The
+    ///   sources before it are loaded, the sources after it are not.
+    ///
+    pub fn load_named_sources<R, I>(&mut self, sources: I) -> Result<()>
+    where
+        R: Read,
+        I: IntoIterator<Item = (Node, R)>,
+    {
+        for (name, input) in sources {
+            self.load_named_turtle(&name, input)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the renaming of blank nodes for the next source.
+    fn next_source_scope(&mut self) -> SourceScope {
+        let source = self.loaded_sources;
+        self.loaded_sources += 1;
+
+        SourceScope {
+            prefix: format!("s{}_", source),
+            all_blank_nodes: self.blank_node_scope == BlankNodeScope::PerSource,
+        }
+    }
+
+    /// Adds the triples of the source graph to the target graph and renames blank nodes.
+    fn add_triples(target: &mut Graph, source: &Graph, scope: &SourceScope) {
+        for triple in source.triples_iter() {
+            target.add_triple(&Triple::new(
+                &scope.node(triple.subject()),
+                &scope.node(triple.predicate()),
+                &scope.node(triple.object()),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::Node;
+    use crate::reader::dataset_loader::{BlankNodeScope, DatasetLoader};
+    use crate::uri::Uri;
+
+    fn blank_node(id: &str) -> Node {
+        Node::BlankNode { id: id.to_string() }
+    }
+
+    #[test]
+    fn test_load_sources_with_global_blank_nodes() {
+        let first = "_:x <http://example.org/p> \"first\" .";
+        let second = "_:x <http://example.org/p> \"second\" .";
+
+        let mut loader = DatasetLoader::new(BlankNodeScope::Global);
+        loader.load_n_quads(first.as_bytes()).unwrap();
+        loader.load_turtle(second.as_bytes()).unwrap();
+
+        let dataset = loader.into_dataset();
+
+        assert_eq!(
+            dataset
+                .default_graph()
+                .get_triples_with_subject(&blank_node("x"))
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_keep_generated_blank_nodes_of_sources_apart() {
+        let first = "[] <http://ex/p> \"a\" .";
+        let second = "[] <http://ex/p> \"b\" .";
+
+        let mut loader = DatasetLoader::new(BlankNodeScope::Global);
+        loader.load_turtle(first.as_bytes()).unwrap();
+        loader.load_turtle(second.as_bytes()).unwrap();
+
+        let dataset = loader.into_dataset();
+        let graph = dataset.default_graph();
+
+        assert_eq!(graph.count(), 2);
+        assert_eq!(
+            graph
+                .get_triples_with_subject(&blank_node("s0_auto0"))
+                .len(),
+            1
+        );
+        assert_eq!(
+            graph
+                .get_triples_with_subject(&blank_node("s1_auto0"))
+                .len(),
+            1
+        );
+        assert!(graph
+            .get_triples_with_subject(&blank_node("auto0"))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_share_labeled_blank_nodes_like_generated_ones() {
+        let first = "_:auto0 <http://ex/p> \"a\" .";
+        let second = "_:auto0 <http://ex/p> [] .";
+
+        let mut loader = DatasetLoader::new(BlankNodeScope::Global);
+        loader.load_turtle(first.as_bytes()).unwrap();
+        loader.load_turtle(second.as_bytes()).unwrap();
+
+        let dataset = loader.into_dataset();
+        let graph = dataset.default_graph();
+
+        assert_eq!(
+            graph.get_triples_with_subject(&blank_node("auto0")).len(),
+            2
+        );
+        assert_eq!(
+            graph.get_triples_with_object(&blank_node("s1_auto0")).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_load_sources_with_blank_nodes_per_source() {
+        let first = "_:x <http://example.org/p> \"first\" _:g .";
+        let second = "_:x <http://example.org/p> \"second\" _:g .";
+
+        let mut loader = DatasetLoader::new(BlankNodeScope::PerSource);
+        loader.load_n_quads(first.as_bytes()).unwrap();
+        loader.load_n_quads(second.as_bytes()).unwrap();
+
+        let dataset = loader.into_dataset();
+        let first_graph = dataset.named_graph(&blank_node("s0_g")).unwrap();
+        let second_graph = dataset.named_graph(&blank_node("s1_g")).unwrap();
+
+        assert_eq!(dataset.graph_names().len(), 2);
+        assert_eq!(
+            first_graph
+                .get_triples_with_subject(&blank_node("s0_x"))
+                .len(),
+            1
+        );
+        assert_eq!(
+            second_graph
+                .get_triples_with_subject(&blank_node("s1_x"))
+                .len(),
+            1
+        );
+        assert!(first_graph
+            .get_triples_with_subject(&blank_node("s1_x"))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_load_named_sources() {
+        let people = "@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+                      _:x foaf:name \"Art\" .";
+        let places = "_:x <http://example.org/city> \"Boston\" .
+                      _:x <http://example.org/country> \"US\" .";
+
+        let people_name = Node::UriNode {
+            uri: Uri::new("http://example.org/people".to_string()),
+        };
+
+        let mut loader = DatasetLoader::new(BlankNodeScope::PerSource);
+        loader
+            .load_named_sources(vec![
+                (people_name.clone(), people.as_bytes()),
+                (blank_node("places"), places.as_bytes()),
+            ])
+            .unwrap();
+
+        let dataset = loader.into_dataset();
+        let people_graph = dataset.named_graph(&people_name).unwrap();
+        let places_graph = dataset.named_graph(&blank_node("s1_places")).unwrap();
+
+        assert!(dataset.default_graph().is_empty());
+        assert_eq!(dataset.graph_names().len(), 2);
+        assert_eq!(
+            people_graph
+                .get_triples_with_subject(&blank_node("s0_x"))
+                .len(),
+            1
+        );
+        assert_eq!(
+            places_graph
+                .get_triples_with_subject(&blank_node("s1_x"))
+                .len(),
+            2
+        );
+        assert!(places_graph
+            .get_triples_with_subject(&blank_node("s0_x"))
+            .is_empty());
+    }
+}
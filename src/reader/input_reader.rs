@@ -1,5 +1,8 @@
 use crate::error::{Error, ErrorType};
-use std::io::Read;
+use std::fmt;
+use std::collections::VecDeque;
+use std::io;
+use std::io::{ErrorKind, Read};
 use std::ops::Index;
 use std::str;
 use crate::Result;
@@ -25,12 +28,98 @@ impl InputReaderHelper {
 
     /// Returns `true` if the provided character is a digit.
     pub fn digit(c: char) -> bool {
-        c >= '0' && c <= '9'
+        c.is_ascii_digit()
     }
 
     /// Returns `true` if the provided character is a letter.
     pub fn letter(c: char) -> bool {
-        (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z')
+        c.is_ascii_lowercase() || c.is_ascii_uppercase()
+    }
+
+    /// Returns `true` if the input is valid UTF-8.
+    ///
+    /// See `InputReaderHelper::first_invalid_utf8_offset`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::input_reader::InputReaderHelper;
+    ///
+    /// assert!(InputReaderHelper::is_valid_utf8("_:a <p> \"ö\" .".as_bytes()).unwrap());
+    /// assert!(!InputReaderHelper::is_valid_utf8(&b"_:a <p> \"\xff\" ."[..]).unwrap());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Reading from the input fails.
+    ///
+    pub fn is_valid_utf8<R: Read>(input: R) -> Result<bool> {
+        Ok(InputReaderHelper::first_invalid_utf8_offset(input)?.is_none())
+    }
+
+    /// Returns the byte offset of the first byte of the input that is not valid UTF-8, or
+    /// `None` if the whole input is valid.
+    ///
+    /// The input is read in chunks without decoding characters, hence it is never loaded
+    /// as a whole. A character that is truncated by the end of the input is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::input_reader::InputReaderHelper;
+    ///
+    /// let input = &b"<s> <p> \"a\xc3\" ."[..];
+    ///
+    /// assert_eq!(InputReaderHelper::first_invalid_utf8_offset(input).unwrap(), Some(10));
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Reading from the input fails.
+    ///
+    pub fn first_invalid_utf8_offset<R: Read>(mut input: R) -> Result<Option<usize>> {
+        let mut buf = [0u8; 8192];
+
+        // offset of the first byte in the buffer and number of bytes of a truncated character
+        // that are kept at the beginning of the buffer
+        let mut offset = 0;
+        let mut pending = 0;
+
+        loop {
+            let read = match input.read(&mut buf[pending..]) {
+                Ok(read) => read,
+                Err(ref err) if err.kind() == ErrorKind::Interrupted => continue,
+                Err(_) => {
+                    return Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        "Unexpected error while reading input.",
+                    ))
+                }
+            };
+
+            if read == 0 {
+                return Ok(if pending > 0 { Some(offset) } else { None });
+            }
+
+            let len = pending + read;
+
+            match str::from_utf8(&buf[..len]) {
+                Ok(_) => {
+                    offset += len;
+                    pending = 0;
+                }
+                Err(err) if err.error_len().is_some() => {
+                    return Ok(Some(offset + err.valid_up_to()))
+                }
+                Err(err) => {
+                    // the last character may be completed by the next chunk
+                    let valid = err.valid_up_to();
+                    buf.copy_within(valid..len, 0);
+                    offset += valid;
+                    pending = len - valid;
+                }
+            }
+        }
     }
 }
 
@@ -42,15 +131,10 @@ pub struct InputChars {
     input_chars: Vec<InputChar>,
 }
 
-impl ToString for InputChars {
-    fn to_string(&self) -> String {
-        let s: String = self
-            .input_chars
-            .clone()
-            .into_iter()
-            .flat_map(|c| c)
-            .collect();
-        s
+impl fmt::Display for InputChars {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s: String = self.input_chars.iter().flatten().collect();
+        f.write_str(&s)
     }
 }
 
@@ -96,10 +180,127 @@ impl InputChars {
     }
 }
 
+/// Concatenates several readers to a single logical input stream.
+///
+/// A line break is inserted between two consecutive readers so that the last
+/// statement of one input and the first statement of the next one stay separated.
+///
+/// Since the inputs are parsed as one document, blank node labels are shared
+/// across all readers: `_:b` in the first and in the second input denote the same node.
+pub struct ChainReader<R: Read> {
+    readers: VecDeque<R>,
+    separator_pending: bool,
+}
+
+impl<R: Read> ChainReader<R> {
+    /// Constructor for `ChainReader`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::input_reader::ChainReader;
+    /// use std::io::Read;
+    ///
+    /// let mut reader = ChainReader::new(vec!["<a>".as_bytes(), "<b>".as_bytes()]);
+    /// let mut output = String::new();
+    /// reader.read_to_string(&mut output).unwrap();
+    ///
+    /// assert_eq!(output, "<a>\n<b>");
+    /// ```
+    pub fn new<I>(readers: I) -> ChainReader<R>
+    where
+        I: IntoIterator<Item = R>,
+    {
+        ChainReader {
+            readers: readers.into_iter().collect(),
+            separator_pending: false,
+        }
+    }
+}
+
+impl<R: Read> Read for ChainReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        loop {
+            if self.separator_pending {
+                self.separator_pending = false;
+                buf[0] = b'\n';
+                return Ok(1);
+            }
+
+            match self.readers.front_mut() {
+                Some(reader) => {
+                    let n = reader.read(buf)?;
+
+                    if n > 0 {
+                        return Ok(n);
+                    }
+
+                    // current reader is exhausted, continue with next one
+                    self.readers.pop_front();
+                    self.separator_pending = !self.readers.is_empty();
+                }
+                None => return Ok(0),
+            }
+        }
+    }
+}
+
+/// Default number of bytes that `InputReader` reads from its input at once.
+const DEFAULT_BUFFER_SIZE: usize = 8192;
+
+/// Number of line lengths that `InputReader` keeps to determine columns.
+const MAX_LINE_LENGTHS: usize = 1024;
+
+/// Determines how `InputReader` handles bytes that are not valid UTF-8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Utf8ErrorPolicy {
+    /// Returns an `InvalidByteEncoding` error.
+    #[default]
+    Error,
+
+    /// Decodes each invalid byte sequence to the replacement character `U+FFFD` and records
+    /// a warning, see `InputReader::warnings`.
+    Replace,
+}
+
 /// Reads input and transforms it to `InputChars`.
+///
+/// The input is read in chunks into an internal buffer, hence no further buffering of
+/// the input is needed.
 pub struct InputReader<R: Read> {
     input: R,
+
+    /// Bytes read from the input, the bytes from `buffer_pos` to `buffer_len` are not
+    /// decoded yet.
+    buffer: Vec<u8>,
+    buffer_pos: usize,
+    buffer_len: usize,
+
     peeked_chars: InputChars,
+    start_of_input: bool,
+
+    /// Number of line feeds decoded from the input, including peeked ones.
+    decoded_line_feeds: usize,
+
+    /// Number of bytes decoded from the input, including the bytes of peeked characters.
+    decoded_bytes: usize,
+
+    /// Number of characters decoded from the input, including peeked ones.
+    decoded_chars: usize,
+
+    /// Number of characters decoded since the last line feed, including peeked ones.
+    decoded_column: usize,
+
+    /// Number of characters before each of the most recently decoded line feeds, used to
+    /// determine the column if peeked characters contain a line feed.
+    line_lengths: VecDeque<usize>,
+
+    utf8_error_policy: Utf8ErrorPolicy,
+    warnings: Vec<String>,
 }
 
 impl<R: Read> InputReader<R> {
@@ -113,12 +314,184 @@ impl<R: Read> InputReader<R> {
     /// let reader = InputReader::new("_:auto0".as_bytes());
     /// ```
     pub fn new(input: R) -> InputReader<R> {
+        InputReader::with_capacity(input, DEFAULT_BUFFER_SIZE)
+    }
+
+    /// Constructor for `InputReader` that reads up to `capacity` bytes from the input at once.
+    ///
+    /// Characters are decoded the same way for any capacity, a character whose bytes are
+    /// split across two reads is completed by the second read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::input_reader::InputReader;
+    ///
+    /// let mut reader = InputReader::with_capacity("é".as_bytes(), 1);
+    ///
+    /// assert_eq!(reader.get_next_char().unwrap(), Some('é'));
+    /// ```
+    pub fn with_capacity(input: R, capacity: usize) -> InputReader<R> {
         InputReader {
             input,
+            buffer: vec![0u8; capacity.max(1)],
+            buffer_pos: 0,
+            buffer_len: 0,
             peeked_chars: InputChars::new(Vec::new()),
+            start_of_input: true,
+            decoded_line_feeds: 0,
+            decoded_bytes: 0,
+            decoded_chars: 0,
+            decoded_column: 0,
+            line_lengths: VecDeque::new(),
+            utf8_error_policy: Utf8ErrorPolicy::default(),
+            warnings: Vec::new(),
         }
     }
 
+    /// Sets how bytes that are not valid UTF-8 are handled, invalid bytes return an error
+    /// by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::input_reader::{InputReader, Utf8ErrorPolicy};
+    ///
+    /// let mut reader = InputReader::new(&b"a\xffb"[..])
+    ///     .with_utf8_error_policy(Utf8ErrorPolicy::Replace);
+    ///
+    /// assert_eq!(reader.get_next_k_chars(3).unwrap().to_string(), "a\u{FFFD}b");
+    /// assert_eq!(reader.warnings().len(), 1);
+    /// ```
+    pub fn with_utf8_error_policy(mut self, policy: Utf8ErrorPolicy) -> InputReader<R> {
+        self.utf8_error_policy = policy;
+        self
+    }
+
+    /// Returns the warnings recorded while decoding the input, e.g. for invalid bytes
+    /// replaced by `U+FFFD`.
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    /// Returns the number of the line containing the next character that is not consumed yet,
+    /// starting at 1.
+    ///
+    /// Lines are terminated by line feeds, hence `\r\n` counts as one line break.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::input_reader::InputReader;
+    ///
+    /// let mut reader = InputReader::new("a\nb".as_bytes());
+    /// assert_eq!(reader.line_number(), 1);
+    ///
+    /// let _ = reader.get_next_k_chars(2);
+    /// assert_eq!(reader.peek_next_char().unwrap(), Some('b'));
+    /// assert_eq!(reader.line_number(), 2);
+    /// ```
+    pub fn line_number(&self) -> usize {
+        let peeked_line_feeds = self
+            .peeked_chars
+            .to_vec()
+            .iter()
+            .filter(|&&c| c == Some('\n'))
+            .count();
+
+        1 + self.decoded_line_feeds - peeked_line_feeds
+    }
+
+    /// Returns the line and the column of the next character that is not consumed yet, both
+    /// starting at 1.
+    ///
+    /// Columns count characters, a tab counts as a single column. The column is only exact if
+    /// the peeked characters span less than 1024 lines, otherwise it falls back to 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::input_reader::InputReader;
+    ///
+    /// let mut reader = InputReader::new("ab\ncé\nd".as_bytes());
+    /// assert_eq!(reader.position(), (1, 1));
+    ///
+    /// let _ = reader.get_next_k_chars(5);
+    /// assert_eq!(reader.position(), (2, 3));
+    ///
+    /// assert_eq!(reader.peek_next_k_chars(2).unwrap().to_string(), "\nd");
+    /// assert_eq!(reader.position(), (2, 3));
+    /// ```
+    pub fn position(&self) -> (usize, usize) {
+        let peeked: Vec<char> = self.peeked_chars.to_vec().into_iter().flatten().collect();
+
+        let column = match peeked.iter().position(|&c| c == '\n') {
+            Some(first_line_feed) => {
+                let peeked_line_feeds = peeked.iter().filter(|&&c| c == '\n').count();
+
+                self.line_lengths
+                    .len()
+                    .checked_sub(peeked_line_feeds)
+                    .map_or(first_line_feed, |i| self.line_lengths[i])
+                    - first_line_feed
+            }
+            None => self.decoded_column - peeked.len(),
+        };
+
+        (self.line_number(), column + 1)
+    }
+
+    /// Returns the number of bytes of the input that are consumed, peeked characters are
+    /// not consumed.
+    ///
+    /// A leading byte order mark counts as consumed once the first character is consumed.
+    /// A peeked replacement character of `Utf8ErrorPolicy::Replace` counts as its three-byte
+    /// encoding, whatever the length of the invalid byte sequence it replaces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::input_reader::InputReader;
+    ///
+    /// let mut reader = InputReader::new("é!".as_bytes());
+    ///
+    /// assert_eq!(reader.get_next_char().unwrap(), Some('é'));
+    /// assert_eq!(reader.peek_next_char().unwrap(), Some('!'));
+    /// assert_eq!(reader.byte_offset(), 2);
+    /// ```
+    pub fn byte_offset(&self) -> usize {
+        let peeked_bytes: usize = self
+            .peeked_chars
+            .to_vec()
+            .iter()
+            .map(|c| c.map_or(0, char::len_utf8))
+            .sum();
+
+        self.decoded_bytes - peeked_bytes
+    }
+
+    /// Returns the number of characters of the input that are consumed, peeked characters are
+    /// not consumed.
+    ///
+    /// A leading byte order mark is not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::input_reader::InputReader;
+    ///
+    /// let mut reader = InputReader::new("é!".as_bytes());
+    ///
+    /// assert_eq!(reader.get_next_char().unwrap(), Some('é'));
+    /// assert_eq!(reader.peek_next_char().unwrap(), Some('!'));
+    /// assert_eq!(reader.char_offset(), 1);
+    /// ```
+    pub fn char_offset(&self) -> usize {
+        let peeked_chars = self.peeked_chars.to_vec().iter().filter(|c| c.is_some()).count();
+
+        self.decoded_chars - peeked_chars
+    }
+
     /// Returns the next `k` characters but does not consume them.
     ///
     /// # Examples
@@ -170,13 +543,15 @@ impl<R: Read> InputReader<R> {
 
     /// Returns the next character that is not a whitespace but does not consume it.
     ///
+    /// Spaces, tabs, line feeds and carriage returns are considered as whitespace.
+    ///
     /// # Examples
     ///
     /// ```
     /// use rdf::reader::input_reader::InputReader;
     /// use rdf::reader::input_reader::InputChars;
     ///
-    /// let mut reader = InputReader::new(" \n _:auto0".as_bytes());
+    /// let mut reader = InputReader::new(" \r\n _:auto0".as_bytes());
     /// assert_eq!(reader.peek_next_char_discard_leading_spaces().unwrap(), Some('_'));
     /// assert_eq!(reader.peek_next_char_discard_leading_spaces().unwrap(), Some('_'));
     /// ```
@@ -188,9 +563,8 @@ impl<R: Read> InputReader<R> {
     pub fn peek_next_char_discard_leading_spaces(&mut self) -> Result<InputChar> {
         match self.get_next_char_discard_leading_spaces() {
             Ok(Some(next_char)) => {
-                if self.peeked_chars.is_empty() {
-                    self.peeked_chars.push(Some(next_char));
-                }
+                // other characters may still be peeked, e.g. after peeking a numeric literal
+                self.peeked_chars.insert(0, Some(next_char));
 
                 Ok(Some(next_char))
             }
@@ -221,34 +595,72 @@ impl<R: Read> InputReader<R> {
             return Ok(self.peeked_chars.remove(0));
         }
 
+        let mut next_char = self.decode_next_char()?;
+
+        // a byte order mark is only allowed at the beginning of the input
+        if next_char == Some('\u{feff}') {
+            if !self.start_of_input {
+                return Err(Error::new(
+                    ErrorType::InvalidReaderInput,
+                    "Byte order mark in the middle of the input.",
+                ));
+            }
+
+            next_char = self.decode_next_char()?;
+        }
+
+        self.start_of_input = false;
+
+        if next_char.is_some() {
+            self.decoded_chars += 1;
+        }
+
+        match next_char {
+            Some('\n') => {
+                self.decoded_line_feeds += 1;
+
+                if self.line_lengths.len() == MAX_LINE_LENGTHS {
+                    self.line_lengths.pop_front();
+                }
+
+                self.line_lengths.push_back(self.decoded_column);
+                self.decoded_column = 0;
+            }
+            Some(_) => self.decoded_column += 1,
+            None => {}
+        }
+
+        Ok(next_char)
+    }
+
+    /// Decodes the next UTF-8 encoded character from the input source.
+    ///
+    /// An invalid byte sequence is decoded according to `utf8_error_policy`. When replacing,
+    /// the byte that turns out not to continue a character is left for the next character.
+    fn decode_next_char(&mut self) -> Result<InputChar> {
         const MAX_BYTES: usize = 4;
         let mut buf = [0u8; MAX_BYTES];
-
-        let input = &mut self.input;
-        let mut bytes = input.bytes();
+        let start = self.decoded_bytes;
 
         for pos in 0..MAX_BYTES {
-            let byte = match bytes.next() {
-                Some(Ok(b)) => b,
-                None => return Ok(None),
-                Some(Err(_)) => {
-                    return Err(Error::new(
-                        ErrorType::InvalidReaderInput,
-                        "Invalid input character.",
-                    ))
-                }
+            let byte = match self.read_byte()? {
+                Some(b) => b,
+                None if pos == 0 => return Ok(None),
+                // the input ends within the bytes of a character
+                None => return self.invalid_byte_encoding(start),
             };
 
             buf[pos] = byte;
 
             match str::from_utf8(&buf[..(pos + 1)]) {
                 Ok(s) => return Ok(s.chars().next()),
-                Err(_) if pos < MAX_BYTES - 1 => {}
-                _ => {
-                    return Err(Error::new(
-                        ErrorType::InvalidByteEncoding,
-                        "Invalid byte encoding of input.",
-                    ))
+                Err(err) if err.error_len().is_none() => {}
+                Err(_) => {
+                    if pos > 0 {
+                        self.unread_byte();
+                    }
+
+                    return self.invalid_byte_encoding(start);
                 }
             }
         }
@@ -259,6 +671,60 @@ impl<R: Read> InputReader<R> {
         ))
     }
 
+    /// Handles an invalid byte sequence starting at byte `offset` according to
+    /// `utf8_error_policy`.
+    fn invalid_byte_encoding(&mut self, offset: usize) -> Result<InputChar> {
+        match self.utf8_error_policy {
+            Utf8ErrorPolicy::Error => Err(Error::new(
+                ErrorType::InvalidByteEncoding,
+                "Invalid byte encoding of input.",
+            )),
+            Utf8ErrorPolicy::Replace => {
+                self.warnings.push(format!(
+                    "Invalid byte encoding at byte {} replaced by U+FFFD.",
+                    offset
+                ));
+
+                Ok(Some(char::REPLACEMENT_CHARACTER))
+            }
+        }
+    }
+
+    /// Returns the last byte read from the buffer so that it is read again.
+    ///
+    /// The last byte is always still in the buffer since it is only refilled before reading.
+    fn unread_byte(&mut self) {
+        self.buffer_pos -= 1;
+        self.decoded_bytes -= 1;
+    }
+
+    /// Reads a single byte from the buffer, which is refilled from the input source once all
+    /// bytes are read. Returns `None` at the end of the input.
+    fn read_byte(&mut self) -> Result<Option<u8>> {
+        while self.buffer_pos == self.buffer_len {
+            match self.input.read(&mut self.buffer) {
+                Ok(0) => return Ok(None),
+                Ok(read) => {
+                    self.buffer_pos = 0;
+                    self.buffer_len = read;
+                }
+                Err(ref err) if err.kind() == ErrorKind::Interrupted => {}
+                Err(_) => {
+                    return Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        "Invalid input character.",
+                    ))
+                }
+            }
+        }
+
+        let byte = self.buffer[self.buffer_pos];
+        self.buffer_pos += 1;
+        self.decoded_bytes += 1;
+
+        Ok(Some(byte))
+    }
+
     /// Returns the next `k` characters of an input source and consumes them.
     ///
     /// # Examples
@@ -337,7 +803,8 @@ impl<R: Read> InputReader<R> {
     /// - End of input reached.
     ///
     pub fn peek_until<F: Fn(char) -> bool>(&mut self, delimiter: F) -> Result<InputChars> {
-        let mut chars = self.get_until(delimiter)?;
+        let mut chars =
+            self.restore_on_end_of_input(|input_reader| input_reader.get_until(delimiter))?;
         let result = chars.clone();
         chars.append(&mut self.peeked_chars);
         self.peeked_chars = chars;
@@ -369,13 +836,28 @@ impl<R: Read> InputReader<R> {
         &mut self,
         delimiter: F,
     ) -> Result<InputChars> {
-        let mut chars = self.get_until_discard_leading_spaces(delimiter)?;
+        let mut chars = self.restore_on_end_of_input(|input_reader| {
+            input_reader.get_until_discard_leading_spaces(delimiter)
+        })?;
         let result = chars.clone();
         chars.append(&mut self.peeked_chars);
         self.peeked_chars = chars;
         Ok(result)
     }
 
+    /// Reads characters using the provided function and keeps them as peeked characters if
+    /// the end of the input is reached, so that they can still be read afterwards.
+    fn restore_on_end_of_input<F: FnOnce(&mut Self) -> Result<InputChars>>(
+        &mut self,
+        read: F,
+    ) -> Result<InputChars> {
+        read(self).inspect_err(|err| {
+            if let ErrorType::EndOfInput(chars) = err.error_type() {
+                self.peeked_chars = chars.clone();
+            }
+        })
+    }
+
     /// Returns all characters of a input source until a certain delimiter occurs.
     ///
     /// The delimiter itself is skipped.
@@ -451,3 +933,206 @@ impl<R: Read> InputReader<R> {
         self.get_until(delimiter)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ErrorType;
+    use crate::reader::input_reader::{InputReader, InputReaderHelper, Utf8ErrorPolicy};
+    use std::io;
+    use std::io::Read;
+
+    /// Reader that counts how often it is read from.
+    struct CountingReader<'a> {
+        input: &'a [u8],
+        reads: usize,
+    }
+
+    impl<'a> Read for CountingReader<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.reads += 1;
+            self.input.read(buf)
+        }
+    }
+
+    #[test]
+    fn test_read_input_in_chunks() {
+        let input = "<http://example.org/s> <http://example.org/p> \"é\" .\n".repeat(50_000);
+        let mut counting_reader = CountingReader {
+            input: input.as_bytes(),
+            reads: 0,
+        };
+
+        let mut reader = InputReader::new(&mut counting_reader);
+        let mut chars = 0;
+
+        while reader.get_next_char().unwrap().is_some() {
+            chars += 1;
+        }
+
+        assert_eq!(chars, input.chars().count());
+        assert!(counting_reader.reads <= input.len() / 8192 + 2);
+    }
+
+    #[test]
+    fn test_decode_characters_across_reads() {
+        let input = "aé€😀b";
+
+        for capacity in 1..=8 {
+            let mut reader = InputReader::with_capacity(input.as_bytes(), capacity);
+
+            assert_eq!(reader.peek_next_k_chars(3).unwrap().to_string(), "aé€");
+
+            for c in input.chars() {
+                assert_eq!(reader.get_next_char().unwrap(), Some(c));
+            }
+
+            assert_eq!(reader.get_next_char().unwrap(), None);
+        }
+
+        let truncated = &"a😀".as_bytes()[..3];
+        let mut reader = InputReader::with_capacity(truncated, 2);
+
+        assert_eq!(reader.get_next_char().unwrap(), Some('a'));
+        assert!(matches!(
+            reader.get_next_char().unwrap_err().error_type(),
+            ErrorType::InvalidByteEncoding
+        ));
+    }
+
+    #[test]
+    fn test_valid_utf8() {
+        // characters of up to four bytes, repeated to exceed a single read chunk
+        let input = "<http://example.org/s> <http://example.org/p> \"aö€𝄞\" .\n".repeat(500);
+
+        assert!(InputReaderHelper::is_valid_utf8(input.as_bytes()).unwrap());
+        assert!(InputReaderHelper::is_valid_utf8("".as_bytes()).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        let mut input = "<http://example.org/s> <http://example.org/p> \"€\" .\n"
+            .repeat(300)
+            .into_bytes();
+        let bad_offset = input.len() - 4;
+        input[bad_offset] = 0xff;
+
+        assert!(!InputReaderHelper::is_valid_utf8(&input[..]).unwrap());
+        assert_eq!(
+            InputReaderHelper::first_invalid_utf8_offset(&input[..]).unwrap(),
+            Some(bad_offset)
+        );
+
+        // truncated character at the end of the input
+        let truncated = "€".as_bytes()[..2].to_vec();
+        assert_eq!(
+            InputReaderHelper::first_invalid_utf8_offset(&truncated[..]).unwrap(),
+            Some(0)
+        );
+
+        // invalid character split across two reads
+        let split = (&b"ab\xe2\x82"[..]).chain(&b"xy"[..]);
+        assert_eq!(InputReaderHelper::first_invalid_utf8_offset(split).unwrap(), Some(2));
+    }
+
+    #[test]
+    fn test_peek_after_peeking_until_delimiter() {
+        let mut reader = InputReader::new(".ex:s".as_bytes());
+
+        assert_eq!(reader.get_next_char().unwrap(), Some('.'));
+        assert_eq!(reader.peek_until(|c| c == ' ').unwrap_err().to_string(), "End of input.");
+        assert_eq!(reader.peek_next_char_discard_leading_spaces().unwrap(), Some('e'));
+        assert_eq!(reader.get_next_k_chars(4).unwrap().to_string(), "ex:s");
+    }
+
+    #[test]
+    fn test_utf8_error_policy() {
+        // invalid lead byte, truncated character, character truncated by the end of the input
+        let input = &b"a\xffb\xe2\x82c\xf0\x9f"[..];
+
+        for capacity in [1, 3, 8192] {
+            let mut reader = InputReader::with_capacity(input, capacity);
+
+            assert_eq!(reader.get_next_char().unwrap(), Some('a'));
+            assert!(matches!(
+                reader.get_next_char().unwrap_err().error_type(),
+                ErrorType::InvalidByteEncoding
+            ));
+
+            let mut reader = InputReader::with_capacity(input, capacity)
+                .with_utf8_error_policy(Utf8ErrorPolicy::Replace);
+            let mut decoded = String::new();
+
+            while let Some(c) = reader.get_next_char().unwrap() {
+                decoded.push(c);
+            }
+
+            assert_eq!(decoded, "a\u{FFFD}b\u{FFFD}c\u{FFFD}");
+            assert_eq!(
+                reader.warnings(),
+                [
+                    "Invalid byte encoding at byte 1 replaced by U+FFFD.",
+                    "Invalid byte encoding at byte 3 replaced by U+FFFD.",
+                    "Invalid byte encoding at byte 6 replaced by U+FFFD.",
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_position() {
+        let input = "<a>\n\t<b> \"é\"\n\n.";
+        let mut reader = InputReader::with_capacity(input.as_bytes(), 2);
+        let mut expected = vec![];
+        let (mut line, mut column) = (1, 1);
+
+        for c in input.chars() {
+            expected.push((line, column));
+
+            if c == '\n' {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
+            }
+        }
+
+        for (i, &position) in expected.iter().enumerate() {
+            assert_eq!(reader.position(), position);
+
+            // peeking several characters across line breaks keeps the position
+            let _ = reader.peek_next_k_chars(input.chars().count() - i);
+            assert_eq!(reader.position(), position);
+
+            reader.get_next_char().unwrap();
+        }
+
+        assert_eq!(reader.position(), (4, 2));
+    }
+
+    #[test]
+    fn test_char_offset() {
+        let input = "\u{feff}_:é <ö> \"€\" .";
+        let mut reader = InputReader::with_capacity(input.as_bytes(), 3);
+        let mut offsets = vec![];
+
+        while let Ok(token) =
+            reader.get_until_discard_leading_spaces(InputReaderHelper::whitespace)
+        {
+            // peeking the delimiter and further characters does not consume them
+            let _ = reader.peek_next_k_chars(3);
+            offsets.push((token.to_string(), reader.char_offset()));
+        }
+
+        assert_eq!(
+            offsets,
+            vec![
+                ("_:é".to_string(), 3),
+                ("<ö>".to_string(), 7),
+                ("\"€\"".to_string(), 11),
+            ]
+        );
+
+        assert_eq!(reader.get_next_char().unwrap(), None);
+        assert_eq!(reader.char_offset(), 13);
+    }
+}
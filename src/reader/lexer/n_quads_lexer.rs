@@ -0,0 +1,167 @@
+use crate::reader::lexer::n_triples_lexer::NTriplesLexer;
+use crate::reader::lexer::rdf_lexer::{LexerMode, LexerOptions, RdfLexer};
+use crate::reader::lexer::token::Token;
+use crate::Result;
+use std::io::Read;
+
+/// Produces tokens from N-Quads input.
+///
+/// An N-Quads statement is an N-Triples statement with an optional graph label, a URI or
+/// blank node, before the `.`. All terms are lexed by the N-Triples rules, hence the lexer
+/// does not distinguish triples from quads, this is left to the parser.
+pub struct NQuadsLexer<R: Read> {
+    lexer: NTriplesLexer<R>,
+}
+
+impl<R: Read> RdfLexer<R> for NQuadsLexer<R> {
+    /// Constructor for `NQuadsLexer`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::lexer::rdf_lexer::RdfLexer;
+    /// use rdf::reader::lexer::n_quads_lexer::NQuadsLexer;
+    ///
+    /// let input = "<example.org/a>".as_bytes();
+    ///
+    /// NQuadsLexer::new(input);
+    /// ```
+    fn new(input: R) -> NQuadsLexer<R> {
+        NQuadsLexer {
+            lexer: NTriplesLexer::new(input),
+        }
+    }
+
+    /// Determines the next token from the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::lexer::rdf_lexer::RdfLexer;
+    /// use rdf::reader::lexer::n_quads_lexer::NQuadsLexer;
+    /// use rdf::reader::lexer::token::Token;
+    ///
+    /// let input = "_:auto <example.org/b> \"test\" _:g .".as_bytes();
+    ///
+    /// let mut lexer = NQuadsLexer::new(input);
+    ///
+    /// assert_eq!(lexer.get_next_token().unwrap(), Token::BlankNode("auto".to_string()));
+    /// assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("example.org/b".to_string()));
+    /// assert_eq!(lexer.get_next_token().unwrap(), Token::Literal("test".to_string()));
+    /// assert_eq!(lexer.get_next_token().unwrap(), Token::BlankNode("g".to_string()));
+    /// assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Input that does not conform to the N-Quads standard.
+    ///
+    fn get_next_token(&mut self) -> Result<Token> {
+        self.lexer.get_next_token()
+    }
+
+    /// Determines the next token without consuming it.
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with the N-Quads standard.
+    ///
+    fn peek_next_token(&mut self) -> Result<Token> {
+        self.lexer.peek_next_token()
+    }
+
+    /// Determines the token `n` positions after the next token without consuming any token.
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with the N-Quads standard.
+    ///
+    fn peek_nth_token(&mut self, n: usize) -> Result<Token> {
+        self.lexer.peek_nth_token(n)
+    }
+
+    /// Returns the line at which the token that was determined last starts.
+    fn token_position(&self) -> Option<usize> {
+        self.lexer.token_position()
+    }
+}
+
+impl<R: Read> NQuadsLexer<R> {
+    /// Constructor for `NQuadsLexer` using the provided options.
+    pub fn with_options(input: R, options: LexerOptions) -> NQuadsLexer<R> {
+        NQuadsLexer {
+            lexer: NTriplesLexer::with_options(input, options),
+        }
+    }
+
+    /// Constructor for `NQuadsLexer` using the options of the provided mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::lexer::rdf_lexer::{LexerMode, RdfLexer};
+    /// use rdf::reader::lexer::n_quads_lexer::NQuadsLexer;
+    /// use rdf::reader::lexer::token::Token;
+    ///
+    /// let input = "<example.org/a\tb>";
+    ///
+    /// let mut strict = NQuadsLexer::with_mode(input.as_bytes(), LexerMode::Strict);
+    /// let mut lenient = NQuadsLexer::with_mode(input.as_bytes(), LexerMode::Lenient);
+    ///
+    /// assert!(strict.get_next_token().is_err());
+    /// assert_eq!(lenient.get_next_token().unwrap(), Token::Uri("example.org/a\tb".to_string()));
+    /// ```
+    pub fn with_mode(input: R, mode: LexerMode) -> NQuadsLexer<R> {
+        NQuadsLexer::with_options(input, LexerOptions::from_mode(mode))
+    }
+
+    /// Returns the line at which the token that was determined last starts, starting at 1.
+    pub fn token_line(&self) -> usize {
+        self.lexer.token_line()
+    }
+
+    /// Returns the column at which the token that was determined last starts, starting at 1.
+    pub fn token_column(&self) -> usize {
+        self.lexer.token_column()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::reader::lexer::n_quads_lexer::NQuadsLexer;
+    use crate::reader::lexer::rdf_lexer::RdfLexer;
+    use crate::reader::lexer::token::Token;
+
+    #[test]
+    fn lex_triples_and_quads() {
+        let input = "<example.org/s> <example.org/p> \"o\"@en .
+                     _:s <example.org/p> <example.org/o> <example.org/g> .
+                     _:s <example.org/p> \"1\"^^<example.org/int> _:g .";
+
+        let mut lexer = NQuadsLexer::new(input.as_bytes());
+
+        let expected = vec![
+            Token::Uri("example.org/s".to_string()),
+            Token::Uri("example.org/p".to_string()),
+            Token::LiteralWithLanguageSpecification("o".to_string(), "en".to_string()),
+            Token::TripleDelimiter,
+            Token::BlankNode("s".to_string()),
+            Token::Uri("example.org/p".to_string()),
+            Token::Uri("example.org/o".to_string()),
+            Token::Uri("example.org/g".to_string()),
+            Token::TripleDelimiter,
+            Token::BlankNode("s".to_string()),
+            Token::Uri("example.org/p".to_string()),
+            Token::LiteralWithUrlDatatype("1".to_string(), "example.org/int".to_string()),
+            Token::BlankNode("g".to_string()),
+            Token::TripleDelimiter,
+            Token::EndOfInput,
+        ];
+
+        for token in expected {
+            assert_eq!(lexer.get_next_token().unwrap(), token);
+        }
+
+        assert_eq!(lexer.token_line(), 3);
+    }
+}
@@ -1,14 +1,31 @@
 use crate::error::{Error, ErrorType};
-use crate::reader::input_reader::{InputReader, InputReaderHelper};
-use crate::reader::lexer::rdf_lexer::{RdfLexer, TokensFromRdf};
+use crate::reader::input_reader::{InputChars, InputReader, InputReaderHelper};
+use crate::reader::lexer::rdf_lexer::{LexerMode, LexerOptions, RdfLexer, TokensFromRdf};
 use crate::reader::lexer::token::Token;
-use std::io::Read;
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{Cursor, Read};
+use std::path::Path;
 use crate::Result;
 
 /// Produces tokens from NTriples input.
 pub struct NTriplesLexer<R: Read> {
     input_reader: InputReader<R>,
-    peeked_token: Option<Token>,
+    peeked_tokens: VecDeque<Token>,
+    options: LexerOptions,
+
+    /// Line and column of the input at which the token that was determined last starts.
+    token_line: usize,
+    token_column: usize,
+
+    /// Whether no token was determined since the last line break.
+    at_line_start: bool,
+
+    /// Whether the blank line was reached if `LexerOptions::stop_at_blank_line` is enabled.
+    blank_line_reached: bool,
+
+    /// Whether iterating over the tokens stopped at an error.
+    iteration_failed: bool,
 }
 
 /// Contains all implemented rules for creating tokens from NTriples syntax.
@@ -19,11 +36,17 @@ pub trait TokensFromNTriples<R: Read>: TokensFromRdf<R> {
 
         match input_reader.get_until_discard_leading_spaces(|c| c == '\n' || c == '\r') {
             Ok(chars) => {
-                Self::consume_next_char(input_reader); // consume comment delimiter
+                // consume comment delimiter, which is '\n', '\r' or '\r\n'
+                if input_reader.get_next_char()? == Some('\r')
+                    && input_reader.peek_next_char()? == Some('\n')
+                {
+                    Self::consume_next_char(input_reader);
+                }
+
                 Ok(Token::Comment(chars.to_string()))
             }
             Err(err) => match err.error_type() {
-                &ErrorType::EndOfInput(ref chars) => Ok(Token::Comment(chars.to_string())),
+                ErrorType::EndOfInput(chars) => Ok(Token::Comment(chars.to_string())),
                 _ => Err(Error::new(
                     ErrorType::InvalidReaderInput,
                     "Invalid input while parsing comment.",
@@ -33,23 +56,44 @@ pub trait TokensFromNTriples<R: Read>: TokensFromRdf<R> {
     }
 
     /// Parses the language specification from the input and returns it as token.
+    ///
+    /// Language tags must consist of ASCII letters optionally followed by subtags of
+    /// ASCII letters and digits separated by `-`, hence escape sequences are rejected.
     fn get_language_specification(input_reader: &mut InputReader<R>) -> Result<String> {
-        match input_reader.get_until(InputReaderHelper::node_delimiter) {
-            Ok(chars) => Ok(chars.to_string()),
+        let language = match input_reader.get_until(InputReaderHelper::node_delimiter) {
+            Ok(chars) => chars.to_string(),
             Err(err) => match err.error_type() {
-                &ErrorType::EndOfInput(ref chars) => Ok(chars.to_string()),
-                _ => Err(Error::new(
-                    ErrorType::InvalidReaderInput,
-                    "Invalid input for while parsing language specification.",
-                )),
+                ErrorType::EndOfInput(chars) => chars.to_string(),
+                _ => {
+                    return Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        "Invalid input for while parsing language specification.",
+                    ))
+                }
             },
+        };
+
+        let mut subtags = language.split('-');
+        let primary = subtags.next().unwrap_or("");
+
+        let is_valid = !primary.is_empty()
+            && primary.chars().all(|c| c.is_ascii_alphabetic())
+            && subtags.all(|tag| !tag.is_empty() && tag.chars().all(|c| c.is_ascii_alphanumeric()));
+
+        if !is_valid {
+            return Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Invalid language specification: ".to_string() + &language,
+            ));
         }
+
+        Ok(language)
     }
 
     /// Parses a literal from the input and returns it as token.
-    fn get_literal(input_reader: &mut InputReader<R>) -> Result<Token> {
+    fn get_literal(input_reader: &mut InputReader<R>, options: &LexerOptions) -> Result<Token> {
         Self::consume_next_char(input_reader); // consume '"'
-        let literal = input_reader.get_until(|c| c == '"')?.to_string();
+        let literal = Self::get_literal_until(input_reader, '"', true, options)?;
         Self::consume_next_char(input_reader); // consume '"'
 
         match input_reader.peek_next_char()? {
@@ -65,7 +109,7 @@ pub trait TokensFromNTriples<R: Read>: TokensFromRdf<R> {
                 match input_reader.peek_next_char()? {
                     Some('<') => {
                         // data type is an URI (NTriples allows only URI data types)
-                        match Self::get_uri(input_reader)? {
+                        match Self::get_uri(input_reader, options)? {
                             Token::Uri(datatype_uri) => {
                                 Ok(Token::LiteralWithUrlDatatype(literal, datatype_uri))
                             }
@@ -82,19 +126,48 @@ pub trait TokensFromNTriples<R: Read>: TokensFromRdf<R> {
                     None => Err(Error::new(ErrorType::InvalidReaderInput, "Invalid input.")),
                 }
             }
-            _ => {
-                Self::consume_next_char(input_reader); // consume '"'
-                Ok(Token::Literal(literal))
-            }
+            _ => Ok(Token::Literal(literal)),
         }
     }
 
     /// Parses a URI from the input and returns it as token.
-    fn get_uri(input_reader: &mut InputReader<R>) -> Result<Token> {
+    ///
+    /// The URI is read character by character. Unicode escape sequences are replaced by the
+    /// characters they represent, hence an escaped `>` does not end the URI. Characters
+    /// that are not allowed in IRI references are rejected, unless they are escaped.
+    fn get_uri(input_reader: &mut InputReader<R>, options: &LexerOptions) -> Result<Token> {
         Self::consume_next_char(input_reader); // consume '<'
-        let chars = input_reader.get_until(|c| c == '>')?;
-        Self::consume_next_char(input_reader); // consume '>'
-        Ok(Token::Uri(chars.to_string()))
+        let mut uri = "".to_string();
+
+        loop {
+            match input_reader.get_next_char()? {
+                Some('>') => return Ok(Token::Uri(uri)),
+                Some('\\') => uri.push(Self::consume_unicode_escape(input_reader)?),
+                Some(c) => {
+                    Self::check_uri_char(c, &uri, options)?;
+                    uri.push(c);
+                }
+                None => {
+                    return Err(Error::new(
+                        ErrorType::EndOfInput(InputChars::new(uri.chars().map(Some).collect())),
+                        "End of input.",
+                    ))
+                }
+            }
+        }
+    }
+
+    /// Checks that an unescaped character of a URI is allowed in IRI references, unless
+    /// `LexerOptions::lenient_uris` is enabled.
+    fn check_uri_char(c: char, uri: &str, options: &LexerOptions) -> Result<()> {
+        if options.lenient_uris || (c > ' ' && !"<\"{}|^`".contains(c)) {
+            return Ok(());
+        }
+
+        Err(Error::new(
+            ErrorType::InvalidReaderInput,
+            format!("Invalid character {:?} in URI: {}", c, uri),
+        ))
     }
 
     /// Parses a blank node ID from the input and returns it as token.
@@ -118,17 +191,58 @@ pub trait TokensFromNTriples<R: Read>: TokensFromRdf<R> {
             }
         }
 
-        match input_reader.get_until(InputReaderHelper::node_delimiter) {
-            Ok(chars) => Ok(Token::BlankNode(chars.to_string())),
-            Err(err) => match err.error_type() {
-                &ErrorType::EndOfInput(ref chars) => Ok(Token::BlankNode(chars.to_string())),
-                _ => Err(Error::new(
-                    ErrorType::InvalidReaderInput,
-                    "Invalid input for lexer while parsing blank node.",
-                )),
-            },
+        let mut label = "".to_string();
+
+        loop {
+            match input_reader.get_until(InputReaderHelper::node_delimiter) {
+                Ok(chars) => label.push_str(&chars.to_string()),
+                Err(err) => match err.error_type() {
+                    ErrorType::EndOfInput(chars) => {
+                        label.push_str(&chars.to_string());
+                        return Ok(Token::BlankNode(label));
+                    }
+                    _ => {
+                        return Err(Error::new(
+                            ErrorType::InvalidReaderInput,
+                            "Invalid input for lexer while parsing blank node.",
+                        ))
+                    }
+                },
+            }
+
+            // dots are part of the label only if they are followed by another label character,
+            // e.g. in '_:a.b' or '_:a..b', otherwise the dot is the triple delimiter as in '_:b.'
+            let mut dots = 0;
+
+            let next_char = loop {
+                let next_chars = input_reader.peek_next_k_chars(dots + 1)?;
+
+                match next_chars[dots] {
+                    Some('.') => dots += 1,
+                    next_char => break next_char,
+                }
+            };
+
+            match next_char {
+                Some(c) if dots > 0 && Self::blank_node_label_char(c) => {
+                    for _ in 0..dots {
+                        Self::consume_next_char(input_reader); // consume '.'
+                        label.push('.');
+                    }
+                }
+                _ => return Ok(Token::BlankNode(label)),
+            }
         }
     }
+
+    /// Returns `true` if the provided character can follow a dot in a blank node label.
+    fn blank_node_label_char(c: char) -> bool {
+        InputReaderHelper::letter(c)
+            || InputReaderHelper::digit(c)
+            || c == '_'
+            || c == '-'
+            || !c.is_ascii()
+    }
 }
 
 impl<R: Read> TokensFromRdf<R> for NTriplesLexer<R> {}
@@ -150,7 +264,13 @@ impl<R: Read> RdfLexer<R> for NTriplesLexer<R> {
     fn new(input: R) -> NTriplesLexer<R> {
         NTriplesLexer {
             input_reader: InputReader::new(input),
-            peeked_token: None,
+            peeked_tokens: VecDeque::new(),
+            token_line: 1,
+            token_column: 1,
+            at_line_start: true,
+            blank_line_reached: false,
+            iteration_failed: false,
+            options: LexerOptions::default(),
         }
     }
 
@@ -178,25 +298,9 @@ impl<R: Read> RdfLexer<R> for NTriplesLexer<R> {
     /// - Input that does not conform to the NTriples standard.
     ///
     fn get_next_token(&mut self) -> Result<Token> {
-        if let Some(token) = self.peeked_token.clone() {
-            self.peeked_token = None;
-            return Ok(token);
-        }
-
-        match self.input_reader.peek_next_char_discard_leading_spaces()? {
-            Some('#') => self.get_comment(),
-            Some('"') => self.get_literal(),
-            Some('<') => self.get_uri(),
-            Some('_') => self.get_blank_node(),
-            Some('.') => {
-                self.consume_next_char(); // consume '.'
-                Ok(Token::TripleDelimiter)
-            }
-            None => Ok(Token::EndOfInput),
-            Some(c) => Err(Error::new(
-                ErrorType::InvalidReaderInput,
-                "Invalid NTriples input: ".to_string() + &c.to_string(),
-            )),
+        match self.peeked_tokens.pop_front() {
+            Some(token) => Ok(token),
+            None => self.read_next_token(),
         }
     }
 
@@ -225,159 +329,329 @@ impl<R: Read> RdfLexer<R> for NTriplesLexer<R> {
     /// - Invalid input that does not conform with NTriples standard.
     ///
     fn peek_next_token(&mut self) -> Result<Token> {
-        match self.peeked_token.clone() {
-            Some(token) => Ok(token),
-            None => {
-                let next = self.get_next_token()?;
-                self.peeked_token = Some(next.clone());
-                Ok(next)
-            }
+        self.peek_nth_token(0)
+    }
+
+    /// Determines the token `n` positions after the next token without consuming any token,
+    /// `peek_nth_token(0)` is the same as `peek_next_token()`.
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with the NTriples standard.
+    ///
+    fn peek_nth_token(&mut self, n: usize) -> Result<Token> {
+        while self.peeked_tokens.len() <= n {
+            let next = self.read_next_token()?;
+            self.peeked_tokens.push_back(next);
         }
+
+        Ok(self.peeked_tokens[n].clone())
+    }
+
+    /// Returns the line at which the token that was determined last starts.
+    fn token_position(&self) -> Option<usize> {
+        Some(self.token_line)
     }
 }
 
-impl<R: Read> NTriplesLexer<R> {
-    /// Consumes the next character of the input reader.
-    fn consume_next_char(&mut self) {
-        let _ = self.input_reader.get_next_char();
+impl NTriplesLexer<Cursor<Vec<u8>>> {
+    /// Constructor for `NTriplesLexer` from input string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::lexer::rdf_lexer::RdfLexer;
+    /// use rdf::reader::lexer::n_triples_lexer::NTriplesLexer;
+    /// use rdf::reader::lexer::token::Token;
+    ///
+    /// let mut lexer = NTriplesLexer::from_string("<example.org/a>");
+    ///
+    /// assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("example.org/a".to_string()));
+    /// ```
+    pub fn from_string<S>(input: S) -> NTriplesLexer<Cursor<Vec<u8>>>
+    where
+        S: Into<String>,
+    {
+        NTriplesLexer::new(Cursor::new(input.into().into_bytes()))
     }
+}
 
-    /// Parses the comment from the input and returns it as token.
-    fn get_comment(&mut self) -> Result<Token> {
-        self.consume_next_char(); // consume '#'
+impl NTriplesLexer<File> {
+    /// Constructor for `NTriplesLexer` reading the file at the provided path.
+    ///
+    /// # Failures
+    ///
+    /// - The file cannot be opened.
+    ///
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<NTriplesLexer<File>> {
+        let path = path.as_ref();
 
-        match self
-            .input_reader
-            .get_until_discard_leading_spaces(|c| c == '\n' || c == '\r')
-        {
-            Ok(chars) => {
-                self.consume_next_char(); // consume comment delimiter
-                Ok(Token::Comment(chars.to_string()))
-            }
-            Err(err) => match *err.error_type() {
-                ErrorType::EndOfInput(ref chars) => Ok(Token::Comment(chars.to_string())),
-                _ => Err(Error::new(
-                    ErrorType::InvalidReaderInput,
-                    "Invalid input for Turtle lexer while parsing comment.",
-                )),
-            },
+        match File::open(path) {
+            Ok(file) => Ok(NTriplesLexer::new(file)),
+            Err(err) => Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                format!("Cannot open {}: {}", path.display(), err),
+            )),
         }
     }
+}
 
-    /// Parses the language specification from the input and returns it as token.
-    fn get_language_specification(&mut self) -> Result<String> {
-        match self
-            .input_reader
-            .get_until(|c| c == '\n' || c == '\r' || c == ' ' || c == '.')
-        {
-            Ok(chars) => Ok(chars.to_string()),
-            Err(err) => match *err.error_type() {
-                ErrorType::EndOfInput(ref chars) => Ok(chars.to_string()),
-                _ => Err(Error::new(
-                    ErrorType::InvalidReaderInput,
-                    "Invalid input for NTriples lexer while parsing language specification.",
-                )),
-            },
+impl<R: Read> NTriplesLexer<R> {
+    /// Constructor for `NTriplesLexer` using the provided options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::lexer::rdf_lexer::{LexerOptions, RdfLexer};
+    /// use rdf::reader::lexer::n_triples_lexer::NTriplesLexer;
+    /// use rdf::reader::lexer::token::Token;
+    ///
+    /// let options = LexerOptions { preserve_whitespace: true, ..Default::default() };
+    /// let mut lexer = NTriplesLexer::with_options("  <example.org/a>".as_bytes(), options);
+    ///
+    /// assert_eq!(lexer.get_next_token().unwrap(), Token::Whitespace("  ".to_string()));
+    /// assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("example.org/a".to_string()));
+    /// ```
+    pub fn with_options(input: R, options: LexerOptions) -> NTriplesLexer<R> {
+        NTriplesLexer {
+            input_reader: InputReader::new(input),
+            peeked_tokens: VecDeque::new(),
+            token_line: 1,
+            token_column: 1,
+            at_line_start: true,
+            blank_line_reached: false,
+            iteration_failed: false,
+            options,
         }
     }
 
-    /// Parses a literal from the input and returns it as token.
-    fn get_literal(&mut self) -> Result<Token> {
-        self.consume_next_char(); // consume '"'
-        let literal = self.input_reader.get_until(|c| c == '"')?.to_string();
-        self.consume_next_char(); // consume '"'
+    /// Constructor for `NTriplesLexer` using the options of the provided mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::lexer::rdf_lexer::{LexerMode, RdfLexer};
+    /// use rdf::reader::lexer::n_triples_lexer::NTriplesLexer;
+    /// use rdf::reader::lexer::token::Token;
+    ///
+    /// let input = "<example.org/a\tb>";
+    ///
+    /// let mut strict = NTriplesLexer::with_mode(input.as_bytes(), LexerMode::Strict);
+    /// let mut lenient = NTriplesLexer::with_mode(input.as_bytes(), LexerMode::Lenient);
+    ///
+    /// assert!(strict.get_next_token().is_err());
+    /// assert_eq!(lenient.get_next_token().unwrap(), Token::Uri("example.org/a\tb".to_string()));
+    /// ```
+    pub fn with_mode(input: R, mode: LexerMode) -> NTriplesLexer<R> {
+        NTriplesLexer::with_options(input, LexerOptions::from_mode(mode))
+    }
 
-        match self.input_reader.peek_next_char()? {
-            Some('@') => {
-                self.consume_next_char(); // consume '@'
-                let language = self.get_language_specification()?;
-                Ok(Token::LiteralWithLanguageSpecification(literal, language))
-            }
-            Some('^') => {
-                self.consume_next_char(); // consume '^'
-                self.consume_next_char(); // consume '^'
+    /// Returns the line at which the token that was determined last starts, starting at 1.
+    ///
+    /// A peeked token counts as determined, hence after peeking this is the line of the peeked token.
+    pub fn token_line(&self) -> usize {
+        self.token_line
+    }
 
-                match self.input_reader.peek_next_char()? {
-                    Some('<') => {
-                        // data type is an URI (NTriples allows only URI data types)
-                        match self.get_uri()? {
-                            Token::Uri(datatype_uri) => {
-                                Ok(Token::LiteralWithUrlDatatype(literal, datatype_uri))
-                            }
-                            _ => Err(Error::new(
-                                ErrorType::InvalidReaderInput,
-                                "Invalid data type URI for NTriples literal.",
-                            )),
-                        }
-                    }
-                    Some(c) => Err(Error::new(
-                        ErrorType::InvalidReaderInput,
-                        "Invalid data type token for NTriples: ".to_string() + &c.to_string(),
-                    )),
-                    None => Err(Error::new(
-                        ErrorType::InvalidReaderInput,
-                        "Invalid NTriples input.",
-                    )),
+    /// Returns the column at which the token that was determined last starts, starting at 1.
+    ///
+    /// See `InputReader::position`.
+    pub fn token_column(&self) -> usize {
+        self.token_column
+    }
+
+    /// Returns the number of bytes of the input that are consumed by the determined tokens,
+    /// including peeked tokens.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::lexer::rdf_lexer::{LexerOptions, RdfLexer};
+    /// use rdf::reader::lexer::n_triples_lexer::NTriplesLexer;
+    /// use rdf::reader::lexer::token::Token;
+    ///
+    /// let options = LexerOptions { stop_at_blank_line: true, ..Default::default() };
+    /// let mut lexer = NTriplesLexer::with_options("<a>\n\n<b>".as_bytes(), options);
+    ///
+    /// assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("a".to_string()));
+    /// assert_eq!(lexer.get_next_token().unwrap(), Token::EndOfInput);
+    /// assert_eq!(lexer.byte_offset(), 5);
+    /// ```
+    pub fn byte_offset(&self) -> usize {
+        self.input_reader.byte_offset()
+    }
+
+    /// Determines the next token from the input, ignoring the peeked tokens.
+    fn read_next_token(&mut self) -> Result<Token> {
+        if !self.options.stop_at_blank_line {
+            return self.read_token();
+        }
+
+        if !self.blank_line_reached {
+            let whitespace = self.consume_whitespace_until_blank_line()?;
+
+            if !self.blank_line_reached {
+                if self.options.preserve_whitespace && !whitespace.is_empty() {
+                    return Ok(Token::Whitespace(whitespace));
                 }
+
+                let token = self.read_token()?;
+
+                // a comment includes the line break terminating it
+                self.at_line_start = matches!(token, Token::Comment(_));
+
+                return Ok(token);
+            }
+        }
+
+        Ok(Token::EndOfInput)
+    }
+
+    /// Consumes whitespace until the next character that is not a whitespace or until after
+    /// the line break ending the first blank line, and returns the consumed whitespace.
+    fn consume_whitespace_until_blank_line(&mut self) -> Result<String> {
+        let mut whitespace = "".to_string();
+
+        loop {
+            let c = match self.input_reader.peek_next_char()? {
+                Some(c) if c == ' ' || c == '\t' || c == '\n' || c == '\r' => c,
+                _ => return Ok(whitespace),
+            };
+
+            NTriplesLexer::consume_next_char(&mut self.input_reader);
+            whitespace.push(c);
+
+            if c == '\r' && self.input_reader.peek_next_char()? == Some('\n') {
+                NTriplesLexer::consume_next_char(&mut self.input_reader);
+                whitespace.push('\n');
             }
-            _ => {
-                self.consume_next_char(); // consume '"'
-                Ok(Token::Literal(literal))
+
+            if c == '\n' || c == '\r' {
+                if self.at_line_start {
+                    self.blank_line_reached = true;
+                    return Ok(whitespace);
+                }
+
+                self.at_line_start = true;
             }
         }
     }
 
-    /// Parses a URI from the input and returns it as token.
-    fn get_uri(&mut self) -> Result<Token> {
-        self.consume_next_char(); // consume '<'
-        let chars = self.input_reader.get_until(|c| c == '>')?;
-        self.consume_next_char(); // consume '>'
-        Ok(Token::Uri(chars.to_string()))
+    /// Determines the next token from the input without considering blank lines.
+    fn read_token(&mut self) -> Result<Token> {
+        if self.options.preserve_whitespace {
+            if let Some(token) = NTriplesLexer::get_whitespace(&mut self.input_reader)? {
+                return Ok(token);
+            }
+        }
+
+        let next_char = self.input_reader.peek_next_char_discard_leading_spaces()?;
+        let (line, column) = self.input_reader.position();
+        self.token_line = line;
+        self.token_column = column;
+
+        self.get_token(next_char)
+            .map_err(|err| NTriplesLexer::<R>::locate_error(err, line, column))
     }
 
-    /// Parses a blank node ID from the input and returns it as token.
-    fn get_blank_node(&mut self) -> Result<Token> {
-        self.consume_next_char(); // consume '_'
+    /// Determines the token starting with the provided next character.
+    fn get_token(&mut self, next_char: Option<char>) -> Result<Token> {
+        match next_char {
+            Some('#') => NTriplesLexer::get_comment(&mut self.input_reader),
+            Some('"') => NTriplesLexer::get_literal(&mut self.input_reader, &self.options),
+            Some('<') => NTriplesLexer::get_uri(&mut self.input_reader, &self.options),
+            Some('_') => NTriplesLexer::get_blank_node(&mut self.input_reader),
+            Some('.') => {
+                NTriplesLexer::consume_next_char(&mut self.input_reader); // consume '.'
+                Ok(Token::TripleDelimiter)
+            }
+            None => Ok(Token::EndOfInput),
+            Some(c) => Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Invalid NTriples input: ".to_string() + &c.to_string(),
+            )),
+        }
+    }
 
-        // get colon after under score
-        match self.input_reader.get_next_char()? {
-            Some(':') => {}
-            Some(c) => {
-                return Err(Error::new(
-                    ErrorType::InvalidReaderInput,
-                    "Invalid character while parsing NTriples blank node: ".to_string()
-                        + &c.to_string(),
-                ))
+    /// Skips the input until after the next triple delimiter that is not part of a literal,
+    /// URI, comment or blank node label, or until the end of the input.
+    ///
+    /// A dot is only taken as triple delimiter if it is followed by whitespace, a comment or
+    /// the end of the input, e.g. the dots of `_:a..b` are skipped.
+    ///
+    /// Used to resynchronize after an invalid statement.
+    pub(crate) fn skip_statement(&mut self) {
+        while let Some(token) = self.peeked_tokens.pop_front() {
+            if matches!(token, Token::TripleDelimiter | Token::EndOfInput) {
+                return;
             }
-            None => {
-                return Err(Error::new(
-                    ErrorType::InvalidReaderInput,
-                    "Error while parsing NTriples blank node.",
-                ))
+        }
+
+        // closing character of the literal, URI or comment that is currently skipped
+        let mut closing = None;
+
+        while let Ok(Some(c)) = self.input_reader.get_next_char() {
+            match (closing, c) {
+                (Some('"'), '\\') => {
+                    let _ = self.input_reader.get_next_char(); // skip escaped character
+                }
+                (Some(closing_char), c) if c == closing_char => closing = None,
+                (Some(_), _) => {}
+                (None, '"') => closing = Some('"'),
+                (None, '<') => closing = Some('>'),
+                (None, '#') => closing = Some('\n'),
+                (None, '.') => match self.input_reader.peek_next_char() {
+                    Ok(Some(c)) if !c.is_whitespace() && c != '#' => {}
+                    _ => return,
+                },
+                (None, _) => {}
             }
         }
+    }
+}
 
-        match self
-            .input_reader
-            .get_until(|c| c == '\n' || c == '\r' || c == ' ' || c == '.')
-        {
-            Ok(chars) => Ok(Token::BlankNode(chars.to_string())),
-            Err(err) => match *err.error_type() {
-                ErrorType::EndOfInput(ref chars) => Ok(Token::BlankNode(chars.to_string())),
-                _ => Err(Error::new(
-                    ErrorType::InvalidReaderInput,
-                    "Invalid input for NTriples lexer while parsing blank node.",
-                )),
-            },
+impl<R: Read> Iterator for NTriplesLexer<R> {
+    type Item = Result<Token>;
+
+    /// Returns the next token, the iteration ends before `Token::EndOfInput` and after the
+    /// first error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::lexer::n_triples_lexer::NTriplesLexer;
+    /// use rdf::reader::lexer::rdf_lexer::RdfLexer;
+    /// use rdf::reader::lexer::token::Token;
+    ///
+    /// let lexer = NTriplesLexer::new("<s> <p> \"o\" .".as_bytes());
+    /// let uris = lexer.filter(|token| matches!(token, Ok(Token::Uri(_)))).count();
+    ///
+    /// assert_eq!(uris, 2);
+    /// ```
+    fn next(&mut self) -> Option<Result<Token>> {
+        if self.iteration_failed {
+            return None;
+        }
+
+        match self.get_next_token() {
+            Ok(Token::EndOfInput) => None,
+            Ok(token) => Some(Ok(token)),
+            Err(err) => {
+                self.iteration_failed = true;
+                Some(Err(err))
+            }
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::error::{Error, ErrorType};
     use crate::reader::lexer::n_triples_lexer::NTriplesLexer;
-    use crate::reader::lexer::rdf_lexer::RdfLexer;
+    use crate::reader::lexer::rdf_lexer::{LexerMode, LexerOptions, RdfLexer};
     use crate::reader::lexer::token::Token;
+    use std::env;
+    use std::fs;
 
     #[test]
     fn test_n_triples_parse_comment() {
@@ -395,6 +669,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_n_triples_parse_comments_with_line_endings() {
+        let mut lexer = NTriplesLexer::new("# a\r\n# b".as_bytes());
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Comment("a".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Comment("b".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::EndOfInput);
+
+        let options = LexerOptions {
+            preserve_whitespace: true,
+            ..Default::default()
+        };
+        let mut lexer = NTriplesLexer::with_options("# a\r\n<s>".as_bytes(), options);
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Comment("a".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("s".to_string()));
+
+        let mut lexer = NTriplesLexer::new("# a\r<s>".as_bytes());
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Comment("a".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("s".to_string()));
+
+        let mut lexer = NTriplesLexer::new("# a".as_bytes());
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Comment("a".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::EndOfInput);
+    }
+
+    #[test]
+    fn test_n_triples_parse_comment_after_triple_delimiter() {
+        let input = "<s> <p> <o> . # note\n<s> <p> <o> .".as_bytes();
+
+        let mut lexer = NTriplesLexer::new(input);
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("s".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("p".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("o".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Comment("note".to_string())
+        );
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("s".to_string()));
+    }
+
+    #[test]
+    fn test_n_triples_parse_crlf_after_triple_delimiter() {
+        let input = "<s> <p> <o> .\r\n<s> <p> <o> .\r\n".as_bytes();
+
+        let mut lexer = NTriplesLexer::new(input);
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("s".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("p".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("o".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("s".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("p".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("o".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::EndOfInput);
+    }
+
     #[test]
     fn test_n_triples_parse_literal() {
         let input = "\"a\"".as_bytes();
@@ -407,6 +743,110 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_n_triples_parse_literal_with_raw_control_characters() {
+        // the grammar only excludes unescaped line feeds and carriage returns
+        let mut lexer = NTriplesLexer::new("\"a\tb\u{7}c\"".as_bytes());
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Literal("a\tb\u{7}c".to_string()));
+
+        for input in ["\"a\nb\" .", "\"a\rb\" ."] {
+            let mut lexer = NTriplesLexer::new(input.as_bytes());
+
+            match lexer.get_next_token() {
+                Ok(token) => panic!("Line break must be rejected: {:?}", token),
+                Err(e) => {
+                    assert!(matches!(e.error_type(), ErrorType::InvalidReaderInput));
+                    assert!(e.to_string().contains("Unescaped line break"));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_n_triples_parse_literal_with_escapes() {
+        let input = "\"a\\tb\\\\c\"".as_bytes();
+
+        let mut lexer = NTriplesLexer::new(input);
+
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Literal("a\tb\\c".to_string())
+        );
+    }
+
+    #[test]
+    fn test_n_triples_parse_literal_with_escaped_quotes() {
+        let input = "\"she said \\\"hi\\\"\" \"it\\'s\".".as_bytes();
+
+        let mut lexer = NTriplesLexer::new(input);
+
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Literal("she said \"hi\"".to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Literal("it's".to_string())
+        );
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+    }
+
+    #[test]
+    fn test_n_triples_parse_literal_with_line_escapes() {
+        let input = "\"line1\\nline2\\r\\n\\tend\"@en".as_bytes();
+
+        let mut lexer = NTriplesLexer::new(input);
+
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::LiteralWithLanguageSpecification(
+                "line1\nline2\r\n\tend".to_string(),
+                "en".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_n_triples_reject_trailing_backslash() {
+        let input = "\"abc\\".as_bytes();
+
+        let mut lexer = NTriplesLexer::new(input);
+
+        match lexer.get_next_token() {
+            Ok(token) => panic!("Incomplete escape must be rejected: {:?}", token),
+            Err(e) => {
+                assert!(matches!(e.error_type(), ErrorType::InvalidReaderInput));
+                assert!(e.to_string().contains("incomplete escape sequence"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_n_triples_parse_unknown_escape_strict() {
+        let input = "\"http:\\/\\/example.org\"".as_bytes();
+
+        let mut lexer = NTriplesLexer::new(input);
+
+        assert!(lexer.get_next_token().is_err());
+    }
+
+    #[test]
+    fn test_n_triples_parse_unknown_escape_lenient() {
+        let input = "\"http:\\/\\/example.org\"".as_bytes();
+        let options = LexerOptions {
+            lenient_escapes: true,
+            ..Default::default()
+        };
+
+        let mut lexer = NTriplesLexer::with_options(input, options);
+
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Literal("http://example.org".to_string())
+        );
+    }
+
     #[test]
     fn test_n_triples_parse_uri() {
         let input = "<example.org/a>".as_bytes();
@@ -443,6 +883,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_n_triples_parse_blank_node_before_triple_delimiter() {
+        for input in ["_:b.", "_:b ."] {
+            let mut lexer = NTriplesLexer::new(input.as_bytes());
+
+            assert_eq!(lexer.get_next_token().unwrap(), Token::BlankNode("b".to_string()));
+            assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+            assert_eq!(lexer.get_next_token().unwrap(), Token::EndOfInput);
+        }
+
+        let mut lexer = NTriplesLexer::new("_:a.b.c.\n".as_bytes());
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::BlankNode("a.b.c".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+
+        let mut lexer = NTriplesLexer::new("_:a..b .".as_bytes());
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::BlankNode("a..b".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::EndOfInput);
+    }
+
     #[test]
     fn test_n_triples_parse_literal_with_data_type() {
         let input = "\"a\"^^<example.org/abc>".as_bytes();
@@ -468,4 +930,300 @@ mod tests {
         );
         assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
     }
+
+    #[test]
+    fn test_n_triples_preserve_whitespace() {
+        let input = "<a>  <b>\t\"c\"@en .\n\n  _:a <b> \"1\"^^<int>   .\r\n";
+        let options = LexerOptions {
+            preserve_whitespace: true,
+            ..Default::default()
+        };
+
+        let mut lexer = NTriplesLexer::with_options(input.as_bytes(), options);
+        let mut document = "".to_string();
+
+        loop {
+            let text = match lexer.get_next_token().unwrap() {
+                Token::EndOfInput => break,
+                Token::Whitespace(chars) => chars,
+                Token::Uri(uri) => format!("<{}>", uri),
+                Token::BlankNode(id) => format!("_:{}", id),
+                Token::LiteralWithLanguageSpecification(literal, language) => {
+                    format!("\"{}\"@{}", literal, language)
+                }
+                Token::LiteralWithUrlDatatype(literal, data_type) => {
+                    format!("\"{}\"^^<{}>", literal, data_type)
+                }
+                Token::TripleDelimiter => ".".to_string(),
+                token => panic!("Unexpected token {:?}", token),
+            };
+
+            document.push_str(&text);
+        }
+
+        assert_eq!(document, input);
+    }
+
+    #[test]
+    fn test_n_triples_parse_uri_with_query_and_fragment() {
+        let input = "<http://ex/path?x=1&y=2#frag> <http://ex/p#> <http://ex/?#> .".as_bytes();
+
+        let mut lexer = NTriplesLexer::new(input);
+
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Uri("http://ex/path?x=1&y=2#frag".to_string())
+        );
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("http://ex/p#".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("http://ex/?#".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+    }
+
+    #[test]
+    fn test_n_triples_reject_escaped_language_specification() {
+        let input = "\"a\"@e\\u006e .".as_bytes();
+
+        let mut lexer = NTriplesLexer::new(input);
+
+        match lexer.get_next_token() {
+            Ok(token) => panic!("Escaped language tag must be rejected: {:?}", token),
+            Err(e) => assert!(matches!(e.error_type(), ErrorType::InvalidReaderInput)),
+        }
+    }
+
+    #[test]
+    fn test_n_triples_parse_language_specification_with_subtags() {
+        let input = "\"a\"@de-CH-1996 .".as_bytes();
+
+        let mut lexer = NTriplesLexer::new(input);
+
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::LiteralWithLanguageSpecification("a".to_string(), "de-CH-1996".to_string())
+        );
+    }
+
+    #[test]
+    fn test_n_triples_validate_language_tags() {
+        for language in ["en", "en-US", "zh-Hans-CN"] {
+            let input = format!("\"a\"@{} .", language);
+            let mut lexer = NTriplesLexer::new(input.as_bytes());
+
+            assert_eq!(
+                lexer.get_next_token().unwrap(),
+                Token::LiteralWithLanguageSpecification("a".to_string(), language.to_string())
+            );
+        }
+
+        for input in ["\"a\"@ .", "\"a\"@", "\"a\"@1 .", "\"a\"@en- .", "\"a\"@123abc! ."] {
+            let mut lexer = NTriplesLexer::new(input.as_bytes());
+
+            match lexer.get_next_token() {
+                Ok(token) => panic!("Invalid language tag must be rejected: {:?}", token),
+                Err(e) => assert!(matches!(e.error_type(), ErrorType::InvalidReaderInput)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_n_triples_parse_character_across_buffer_refill() {
+        // the input is read in chunks of 8192 bytes, the emoji occupies the bytes 8190 to 8193
+        let literal = "a".repeat(8189) + "😀";
+        let input = format!("\"{}\" .", literal);
+
+        let mut lexer = NTriplesLexer::new(input.as_bytes());
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Literal(literal));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+    }
+
+    #[test]
+    fn test_n_triples_parse_unicode_escapes() {
+        let input = "\"caf\\u00E9\" \"\\U0001F600!\" <http://ex/\\u0041> .".as_bytes();
+
+        let mut lexer = NTriplesLexer::new(input);
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Literal("café".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Literal("😀!".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("http://ex/A".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+    }
+
+    #[test]
+    fn test_n_triples_reject_short_unicode_escape() {
+        for input in ["\"caf\\u00E\" .", "<http://ex/\\U0041> ."] {
+            let mut lexer = NTriplesLexer::new(input.as_bytes());
+
+            match lexer.get_next_token() {
+                Ok(token) => panic!("Short unicode escape must be rejected: {:?}", token),
+                Err(e) => assert!(matches!(e.error_type(), ErrorType::InvalidReaderInput)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_n_triples_reject_invalid_code_points() {
+        for input in ["\"\\uD800\" .", "<http://ex/\\U00110000> ."] {
+            let mut lexer = NTriplesLexer::new(input.as_bytes());
+
+            match lexer.get_next_token() {
+                Ok(token) => panic!("Invalid code point must be rejected: {:?}", token),
+                Err(e) => assert!(matches!(e.error_type(), ErrorType::InvalidReaderInput)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_n_triples_parse_uri_with_escaped_characters() {
+        let input = "<http://ex/a\\u003Eb\\u0020c> <http://ex/\\U0000007B> .".as_bytes();
+
+        let mut lexer = NTriplesLexer::new(input);
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("http://ex/a>b c".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("http://ex/{".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+    }
+
+    #[test]
+    fn test_n_triples_reject_unescaped_space_in_uri() {
+        for input in ["<http://ex/a b> .", "<http://ex/a\u{1}b> .", "<http://ex/a\nb> ."] {
+            let mut lexer = NTriplesLexer::new(input.as_bytes());
+
+            match lexer.get_next_token() {
+                Ok(token) => panic!("Unescaped control character must be rejected: {:?}", token),
+                Err(e) => assert!(matches!(e.error_type(), ErrorType::InvalidReaderInput)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_n_triples_iterate_over_tokens() {
+        let lexer = NTriplesLexer::new("<s> <p> \"o\"@en .".as_bytes());
+        let tokens: Vec<Token> = lexer.collect::<crate::Result<_>>().unwrap();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Uri("s".to_string()),
+                Token::Uri("p".to_string()),
+                Token::LiteralWithLanguageSpecification("o".to_string(), "en".to_string()),
+                Token::TripleDelimiter,
+            ]
+        );
+
+        // the iteration stops at the first error
+        let mut lexer = NTriplesLexer::new("<s> ? <p> .".as_bytes());
+
+        assert_eq!(lexer.next().unwrap().unwrap(), Token::Uri("s".to_string()));
+        assert!(lexer.next().unwrap().is_err());
+        assert!(lexer.next().is_none());
+    }
+
+    #[test]
+    fn test_n_triples_parse_uris_without_whitespace() {
+        let input = "<a><b><c>.".as_bytes();
+
+        let mut lexer = NTriplesLexer::new(input);
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("a".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("b".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("c".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::EndOfInput);
+    }
+
+    #[test]
+    fn test_n_triples_debug_tokens() {
+        let input = "# data\n<s> <p> \"o\"@en .\n_:b <p>\n  \"1\"^^<int> .\n".as_bytes();
+
+        let mut lexer = NTriplesLexer::new(input);
+
+        assert_eq!(
+            lexer.debug_tokens().unwrap(),
+            "1: Comment(\"data\")\n\
+             2: Uri(\"s\")\n\
+             2: Uri(\"p\")\n\
+             2: LiteralWithLanguageSpecification(\"o\", \"en\")\n\
+             2: TripleDelimiter\n\
+             3: BlankNode(\"b\")\n\
+             3: Uri(\"p\")\n\
+             4: LiteralWithUrlDatatype(\"1\", \"int\")\n\
+             4: TripleDelimiter\n"
+        );
+    }
+
+    #[test]
+    fn test_n_triples_lex_in_strict_and_lenient_mode() {
+        let input = "<http://example.org/a\tb> <http://example.org/p> \"a\\/b\" .";
+
+        let tokenize = |mut lexer: NTriplesLexer<&[u8]>| -> Result<Vec<Token>, Error> {
+            let mut tokens = Vec::new();
+
+            loop {
+                match lexer.get_next_token()? {
+                    Token::EndOfInput => return Ok(tokens),
+                    token => tokens.push(token),
+                }
+            }
+        };
+
+        assert_eq!(
+            tokenize(NTriplesLexer::with_mode(input.as_bytes(), LexerMode::Lenient)).unwrap(),
+            vec![
+                Token::Uri("http://example.org/a\tb".to_string()),
+                Token::Uri("http://example.org/p".to_string()),
+                Token::Literal("a/b".to_string()),
+                Token::TripleDelimiter,
+            ]
+        );
+
+        for lexer in [
+            NTriplesLexer::new(input.as_bytes()),
+            NTriplesLexer::with_mode(input.as_bytes(), LexerMode::Strict),
+        ] {
+            match tokenize(lexer) {
+                Ok(tokens) => panic!("Malformed input must be rejected: {:?}", tokens),
+                Err(e) => assert!(matches!(e.error_type(), ErrorType::InvalidReaderInput)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_n_triples_lex_from_string() {
+        let mut lexer = NTriplesLexer::from_string("_:s <http://example.org/p> \"o\"@en .");
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::BlankNode("s".to_string()));
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Uri("http://example.org/p".to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::LiteralWithLanguageSpecification("o".to_string(), "en".to_string())
+        );
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::EndOfInput);
+    }
+
+    #[test]
+    fn test_n_triples_lex_from_path() {
+        let path = env::temp_dir().join(format!("rdf-lexer-test-{}.nt", std::process::id()));
+        fs::write(&path, "<http://example.org/s> <http://example.org/p> _:o .\n").unwrap();
+
+        let mut lexer = NTriplesLexer::from_path(&path).unwrap();
+
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Uri("http://example.org/s".to_string())
+        );
+        fs::remove_file(&path).unwrap();
+
+        match NTriplesLexer::from_path(&path) {
+            Ok(_) => panic!("Opening a nonexistent file must fail"),
+            Err(e) => {
+                assert!(matches!(e.error_type(), ErrorType::InvalidReaderInput));
+                assert!(e.to_string().contains("rdf-lexer-test-"));
+            }
+        }
+    }
 }
@@ -1,8 +1,68 @@
-use crate::reader::input_reader::InputReader;
+use crate::error::{Error, ErrorType};
+use crate::reader::input_reader::{InputChars, InputReader, InputReaderHelper};
 use crate::reader::lexer::token::Token;
 use std::io::Read;
 use crate::Result;
 
+/// Options to configure the behaviour of RDF lexers.
+#[derive(Debug, Clone, Default)]
+pub struct LexerOptions {
+    /// Emit `Token::Whitespace` tokens containing the whitespace between tokens
+    /// instead of silently discarding it.
+    ///
+    /// The line break terminating a comment is part of the comment and not emitted separately.
+    pub preserve_whitespace: bool,
+
+    /// Resolve unknown escape sequences in literals to the escaped character
+    /// instead of returning an error, e.g. `\/` becomes `/`.
+    pub lenient_escapes: bool,
+
+    /// Emit `Token::EndOfInput` at the first blank line, i.e. a line that is empty or only
+    /// contains spaces and tabs, and leave the input after it unread, e.g. to parse a header
+    /// block that is separated from the data by a blank line.
+    ///
+    /// Only supported by the N-Triples and N-Quads lexers.
+    pub stop_at_blank_line: bool,
+
+    /// Accept characters in URIs that are not allowed in IRI references, e.g. spaces, tabs
+    /// and `{`, instead of returning an error.
+    pub lenient_uris: bool,
+}
+
+impl LexerOptions {
+    /// Returns the options of the provided mode, all other options are disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::lexer::rdf_lexer::{LexerMode, LexerOptions};
+    ///
+    /// assert!(LexerOptions::from_mode(LexerMode::Lenient).lenient_uris);
+    /// assert!(!LexerOptions::from_mode(LexerMode::Strict).lenient_escapes);
+    /// ```
+    pub fn from_mode(mode: LexerMode) -> LexerOptions {
+        let lenient = mode == LexerMode::Lenient;
+
+        LexerOptions {
+            lenient_escapes: lenient,
+            lenient_uris: lenient,
+            ..Default::default()
+        }
+    }
+}
+
+/// Determines how strictly RDF lexers apply the syntax standard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LexerMode {
+    /// Returns an error for any input that does not conform with the standard.
+    #[default]
+    Strict,
+
+    /// Tolerates common mistakes of real-world data where the intended token is
+    /// unambiguous, see `LexerOptions::lenient_escapes` and `LexerOptions::lenient_uris`.
+    Lenient,
+}
+
 /// Trait implemented by RDF lexer.
 pub trait RdfLexer<R: Read> {
     /// Constructor.
@@ -13,6 +73,56 @@ pub trait RdfLexer<R: Read> {
 
     // Determines the next token without consuming it.
     fn peek_next_token(&mut self) -> Result<Token>;
+
+    /// Determines the token `n` positions after the next token without consuming any token.
+    fn peek_nth_token(&mut self, n: usize) -> Result<Token>;
+
+    /// Returns the line at which the token that was determined last starts, if the lexer
+    /// tracks positions.
+    fn token_position(&self) -> Option<usize> {
+        None
+    }
+
+    /// Determines all remaining tokens and returns them one per line, for debugging.
+    ///
+    /// Each line is prefixed with the line of the token in the input, if the lexer tracks
+    /// positions. The final `Token::EndOfInput` is not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::lexer::n_triples_lexer::NTriplesLexer;
+    /// use rdf::reader::lexer::rdf_lexer::RdfLexer;
+    ///
+    /// let mut lexer = NTriplesLexer::new("<s> <p> \"o\" .".as_bytes());
+    ///
+    /// assert_eq!(
+    ///     lexer.debug_tokens().unwrap(),
+    ///     "1: Uri(\"s\")\n1: Uri(\"p\")\n1: Literal(\"o\")\n1: TripleDelimiter\n"
+    /// );
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that cannot be tokenized.
+    ///
+    fn debug_tokens(&mut self) -> Result<String> {
+        let mut dump = "".to_string();
+
+        loop {
+            let token = self.get_next_token()?;
+
+            if token == Token::EndOfInput {
+                return Ok(dump);
+            }
+
+            if let Some(line) = self.token_position() {
+                dump.push_str(&format!("{}: ", line));
+            }
+
+            dump.push_str(&format!("{:?}\n", token));
+        }
+    }
 }
 
 /// Contains implemented rules for parsing RDF input.
@@ -21,4 +131,173 @@ pub trait TokensFromRdf<R: Read> {
     fn consume_next_char(input_reader: &mut InputReader<R>) {
         let _ = input_reader.get_next_char();
     }
+
+    /// Adds the line and column of the token to the message of an invalid input error, other
+    /// errors are returned unchanged.
+    fn locate_error(error: Error, line: usize, column: usize) -> Error {
+        match error.error_type() {
+            ErrorType::InvalidReaderInput => {
+                let message = error.to_string();
+
+                Error::new(
+                    ErrorType::InvalidReaderInput,
+                    format!(
+                        "{} at line {}, column {}.",
+                        message.strip_suffix('.').unwrap_or(&message),
+                        line,
+                        column
+                    ),
+                )
+            }
+            _ => error,
+        }
+    }
+
+    /// Consumes all whitespace characters and returns them as token, if any.
+    fn get_whitespace(input_reader: &mut InputReader<R>) -> Result<Option<Token>> {
+        let mut whitespace = "".to_string();
+
+        while let Some(c) = input_reader.peek_next_char()? {
+            if c != ' ' && c != '\t' && c != '\n' && c != '\r' {
+                break;
+            }
+
+            whitespace.push(c);
+            Self::consume_next_char(input_reader);
+        }
+
+        if whitespace.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(Token::Whitespace(whitespace)))
+        }
+    }
+
+    /// Returns the characters of a literal until the delimiter that is not escaped.
+    ///
+    /// Escape sequences are replaced by the characters they represent, hence an escaped
+    /// delimiter does not end the literal. The delimiter itself is not consumed.
+    ///
+    /// A single-line literal must not contain unescaped line feeds or carriage returns, other
+    /// characters such as tabs may appear unescaped as the grammars allow.
+    ///
+    /// # Failures
+    ///
+    /// - End of input reached before the delimiter.
+    /// - Invalid or incomplete escape sequence, see `consume_escape`.
+    /// - Unescaped line break in a single-line literal.
+    ///
+    fn get_literal_until(
+        input_reader: &mut InputReader<R>,
+        delimiter: char,
+        single_line: bool,
+        options: &LexerOptions,
+    ) -> Result<String> {
+        let mut literal = "".to_string();
+
+        loop {
+            match input_reader.peek_next_char()? {
+                Some(c) if c == delimiter => return Ok(literal),
+                Some('\\') => literal.push(Self::consume_escape(input_reader, options)?),
+                Some(c) if single_line && InputReaderHelper::line_break(c) => {
+                    return Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        format!("Unescaped line break {:?} in single-line literal: {}", c, literal),
+                    ))
+                }
+                Some(c) => {
+                    Self::consume_next_char(input_reader);
+                    literal.push(c);
+                }
+                None => {
+                    return Err(Error::new(
+                        ErrorType::EndOfInput(InputChars::new(literal.chars().map(Some).collect())),
+                        "End of input.",
+                    ))
+                }
+            }
+        }
+    }
+
+    /// Consumes an escape sequence of a literal and returns the character it represents.
+    ///
+    /// Unknown escape sequences result in an error, unless lenient escapes are enabled.
+    fn consume_escape(input_reader: &mut InputReader<R>, options: &LexerOptions) -> Result<char> {
+        Self::consume_next_char(input_reader); // consume '\'
+
+        if matches!(input_reader.peek_next_char()?, Some('u') | Some('U')) {
+            return Self::consume_unicode_escape(input_reader);
+        }
+
+        match input_reader.get_next_char()? {
+            Some('t') => Ok('\t'),
+            Some('b') => Ok('\u{8}'),
+            Some('n') => Ok('\n'),
+            Some('r') => Ok('\r'),
+            Some('f') => Ok('\u{c}'),
+            Some('"') => Ok('"'),
+            Some('\'') => Ok('\''),
+            Some('\\') => Ok('\\'),
+            Some(c) if options.lenient_escapes => Ok(c),
+            Some(c) => Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Invalid escape sequence in literal: \\".to_string() + &c.to_string(),
+            )),
+            None => Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Literal ends with an incomplete escape sequence.",
+            )),
+        }
+    }
+
+    /// Consumes a unicode escape sequence `uXXXX` or `UXXXXXXXX` following a backslash and
+    /// returns the character it represents.
+    ///
+    /// # Failures
+    ///
+    /// - Less than 4 or 8 hexadecimal digits.
+    /// - The code point is a surrogate or greater than `0x10FFFF`.
+    ///
+    fn consume_unicode_escape(input_reader: &mut InputReader<R>) -> Result<char> {
+        let length = match input_reader.get_next_char()? {
+            Some('u') => 4,
+            Some('U') => 8,
+            _ => {
+                return Err(Error::new(
+                    ErrorType::InvalidReaderInput,
+                    "Expected unicode escape sequence.",
+                ))
+            }
+        };
+
+        let mut digits = "".to_string();
+
+        for _ in 0..length {
+            match input_reader.peek_next_char()? {
+                Some(c) if c.is_ascii_hexdigit() => {
+                    Self::consume_next_char(input_reader);
+                    digits.push(c);
+                }
+                _ => {
+                    return Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        format!(
+                            "Unicode escape sequence requires {} hexadecimal digits: {}",
+                            length, digits
+                        ),
+                    ))
+                }
+            }
+        }
+
+        u32::from_str_radix(&digits, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorType::InvalidReaderInput,
+                    "Invalid code point in unicode escape sequence: ".to_string() + &digits,
+                )
+            })
+    }
 }
@@ -1,17 +1,18 @@
 use crate::error::{Error, ErrorType};
 use crate::reader::input_reader::{InputReader, InputReaderHelper};
 use crate::reader::lexer::n_triples_lexer::TokensFromNTriples;
-use crate::reader::lexer::rdf_lexer::{RdfLexer, TokensFromRdf};
+use crate::reader::lexer::rdf_lexer::{LexerOptions, RdfLexer, TokensFromRdf};
 use crate::reader::lexer::token::Token;
 use crate::reader::lexer::turtle_lexer::TokensFromTurtle;
 use crate::specs::sparql_specs::SparqlKeyword;
+use std::collections::VecDeque;
 use std::io::Read;
 use crate::Result;
 
 /// Produces tokens from SPARQL input.
 pub struct SparqlLexer<R: Read> {
     input_reader: InputReader<R>,
-    peeked_token: Option<Token>,
+    peeked_tokens: VecDeque<Token>,
 }
 
 impl<R: Read> RdfLexer<R> for SparqlLexer<R> {
@@ -30,7 +31,7 @@ impl<R: Read> RdfLexer<R> for SparqlLexer<R> {
     fn new(input: R) -> SparqlLexer<R> {
         SparqlLexer {
             input_reader: InputReader::new(input),
-            peeked_token: None,
+            peeked_tokens: VecDeque::new(),
         }
     }
 
@@ -53,78 +54,10 @@ impl<R: Read> RdfLexer<R> for SparqlLexer<R> {
     /// - Input that does not conform to the SPARQL syntax standard.
     ///
     fn get_next_token(&mut self) -> Result<Token> {
-        // first read peeked characters
-        match self.peeked_token.clone() {
-            Some(token) => {
-                self.peeked_token = None;
-                return Ok(token);
-            }
-            None => {}
-        }
-
-        // todo
-        match self.input_reader.peek_next_char_discard_leading_spaces()? {
-            Some('#') => return SparqlLexer::get_comment(&mut self.input_reader),
-            Some('P') | Some('B') => {
-                // try parsing PREFIX or BASE
-                match <SparqlLexer<R> as TokensFromTurtle<R>>::get_base_or_prefix(
-                    &mut self.input_reader,
-                ) {
-                    Ok(token) => return Ok(token),
-                    _ => {} // continue, because it could still be a QName
-                }
-            }
-            Some('"') | Some('\'') => {
-                return <SparqlLexer<R> as TokensFromTurtle<R>>::get_literal(&mut self.input_reader)
-            }
-            Some('<') => return SparqlLexer::get_uri(&mut self.input_reader),
-            Some('_') => return SparqlLexer::get_blank_node(&mut self.input_reader),
-            Some('.') => {
-                // try to parse a decimal, if there is an error then it is a triple delimiter
-                return SparqlLexer::get_numeric(&mut self.input_reader)
-                    .or_else(|_| Ok(Token::TripleDelimiter));
-            }
-            Some('[') => {
-                SparqlLexer::consume_next_char(&mut self.input_reader); // consume '['
-                return Ok(Token::UnlabeledBlankNodeStart);
-            }
-            Some(']') => {
-                SparqlLexer::consume_next_char(&mut self.input_reader); // consume ']'
-                return Ok(Token::UnlabeledBlankNodeEnd);
-            }
-            Some('{') => {
-                SparqlLexer::consume_next_char(&mut self.input_reader); // consume '{'
-                return Ok(Token::GroupStart);
-            }
-            Some('}') => {
-                SparqlLexer::consume_next_char(&mut self.input_reader); // consume '}'
-                return Ok(Token::GroupEnd);
-            }
-            Some(',') => {
-                SparqlLexer::consume_next_char(&mut self.input_reader); // consume ','
-                return Ok(Token::ObjectListDelimiter);
-            }
-            Some(';') => {
-                SparqlLexer::consume_next_char(&mut self.input_reader); // consume ';'
-                return Ok(Token::PredicateListDelimiter);
-            }
-            Some('*') => {
-                SparqlLexer::consume_next_char(&mut self.input_reader); // consume '*'
-                return Ok(Token::Asterisk);
-            }
-            Some('?') | Some('$') => {
-                SparqlLexer::consume_next_char(&mut self.input_reader); // consume either '?' or '$'
-                return SparqlLexer::get_variable(&mut self.input_reader);
-            }
-            Some('+') | Some('-') => return SparqlLexer::get_numeric(&mut self.input_reader),
-            Some(c) if InputReaderHelper::digit(c) => {
-                return SparqlLexer::get_numeric(&mut self.input_reader)
-            }
-            Some(_) => {}
-            None => return Ok(Token::EndOfInput),
+        match self.peeked_tokens.pop_front() {
+            Some(token) => Ok(token),
+            None => self.read_next_token(),
         }
-
-        SparqlLexer::get_qname_or_keyword(&mut self.input_reader)
     }
 
     /// Determines the next token without consuming the input.
@@ -147,26 +80,36 @@ impl<R: Read> RdfLexer<R> for SparqlLexer<R> {
     /// - Invalid input that does not conform with NTriples standard.
     ///
     fn peek_next_token(&mut self) -> Result<Token> {
-        match self.peeked_token.clone() {
-            Some(token) => Ok(token),
-            None => match self.get_next_token() {
-                Ok(next) => {
-                    self.peeked_token = Some(next.clone());
-                    return Ok(next);
-                }
-                Err(err) => return Err(err),
-            },
+        self.peek_nth_token(0)
+    }
+
+    /// Determines the token `n` positions after the next token without consuming any token,
+    /// `peek_nth_token(0)` is the same as `peek_next_token()`.
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with the SPARQL syntax standard.
+    ///
+    fn peek_nth_token(&mut self, n: usize) -> Result<Token> {
+        while self.peeked_tokens.len() <= n {
+            let next = self.read_next_token()?;
+            self.peeked_tokens.push_back(next);
         }
+
+        Ok(self.peeked_tokens[n].clone())
     }
 }
 
 /// Contains all implemented rules for creating tokens from SPARQL syntax.
 pub trait TokensFromSparql<R: Read>: TokensFromTurtle<R> {
     /// Parses the base or prefix definition.
-    fn get_base_or_prefix(input_reader: &mut InputReader<R>) -> Result<Token> {
+    fn get_base_or_prefix(
+        input_reader: &mut InputReader<R>,
+        options: &LexerOptions,
+    ) -> Result<Token> {
         match input_reader.peek_next_char()? {
-            Some('B') => Self::get_base_directive(input_reader),
-            Some('P') => Self::get_prefix_directive(input_reader),
+            Some('B') => Self::get_base_directive(input_reader, options),
+            Some('P') => Self::get_prefix_directive(input_reader, options),
             None | Some(_) => Err(Error::new(
                 ErrorType::InvalidReaderInput,
                 "Invalid input while trying to parse base or prefix definition.",
@@ -213,6 +156,79 @@ pub trait TokensFromSparql<R: Read>: TokensFromTurtle<R> {
     }
 }
 
+impl<R: Read> SparqlLexer<R> {
+    /// Determines the next token from the input, ignoring the peeked tokens.
+    fn read_next_token(&mut self) -> Result<Token> {
+        // todo
+        match self.input_reader.peek_next_char_discard_leading_spaces()? {
+            Some('#') => return SparqlLexer::get_comment(&mut self.input_reader),
+            Some('P') | Some('B') => {
+                // try parsing PREFIX or BASE, otherwise continue because it could still be a QName
+                if let Ok(token) = <SparqlLexer<R> as TokensFromTurtle<R>>::get_base_or_prefix(
+                    &mut self.input_reader,
+                    &LexerOptions::default(),
+                ) {
+                    return Ok(token);
+                }
+            }
+            Some('"') | Some('\'') => {
+                return <SparqlLexer<R> as TokensFromTurtle<R>>::get_literal(
+                    &mut self.input_reader,
+                    &LexerOptions::default(),
+                )
+            }
+            Some('<') => return SparqlLexer::get_uri(
+                    &mut self.input_reader,
+                    &LexerOptions::default(),
+                ),
+            Some('_') => return SparqlLexer::get_blank_node(&mut self.input_reader),
+            Some('.') => {
+                return SparqlLexer::get_number_or_triple_delimiter(&mut self.input_reader)
+            }
+            Some('[') => {
+                SparqlLexer::consume_next_char(&mut self.input_reader); // consume '['
+                return Ok(Token::BlankNodePropertyListStart);
+            }
+            Some(']') => {
+                SparqlLexer::consume_next_char(&mut self.input_reader); // consume ']'
+                return Ok(Token::BlankNodePropertyListEnd);
+            }
+            Some('{') => {
+                SparqlLexer::consume_next_char(&mut self.input_reader); // consume '{'
+                return Ok(Token::GroupStart);
+            }
+            Some('}') => {
+                SparqlLexer::consume_next_char(&mut self.input_reader); // consume '}'
+                return Ok(Token::GroupEnd);
+            }
+            Some(',') => {
+                SparqlLexer::consume_next_char(&mut self.input_reader); // consume ','
+                return Ok(Token::ObjectListDelimiter);
+            }
+            Some(';') => {
+                SparqlLexer::consume_next_char(&mut self.input_reader); // consume ';'
+                return Ok(Token::PredicateListDelimiter);
+            }
+            Some('*') => {
+                SparqlLexer::consume_next_char(&mut self.input_reader); // consume '*'
+                return Ok(Token::Asterisk);
+            }
+            Some('?') | Some('$') => {
+                SparqlLexer::consume_next_char(&mut self.input_reader); // consume either '?' or '$'
+                return SparqlLexer::get_variable(&mut self.input_reader);
+            }
+            Some('+') | Some('-') => return SparqlLexer::get_number(&mut self.input_reader),
+            Some(c) if InputReaderHelper::digit(c) => {
+                return SparqlLexer::get_number(&mut self.input_reader)
+            }
+            Some(_) => {}
+            None => return Ok(Token::EndOfInput),
+        }
+
+        SparqlLexer::get_qname_or_keyword(&mut self.input_reader)
+    }
+}
+
 impl<R: Read> TokensFromRdf<R> for SparqlLexer<R> {}
 impl<R: Read> TokensFromNTriples<R> for SparqlLexer<R> {}
 impl<R: Read> TokensFromTurtle<R> for SparqlLexer<R> {}
@@ -2,10 +2,14 @@
 /// Tokens are produced by RDF lexers.
 pub enum Token {
     Comment(String),
+    Whitespace(String), // only emitted if whitespace is preserved
     Literal(String),
     LiteralWithUrlDatatype(String, String), // first element is the literal, second the data type URL
     LiteralWithQNameDatatype(String, String, String), // first element is the literal, second the prefix of the QName data type, third the QName path
     LiteralWithLanguageSpecification(String, String),
+    IntegerLiteral(String), // e.g. for Turtle syntax -> -42
+    DecimalLiteral(String), // e.g. for Turtle syntax -> .5
+    DoubleLiteral(String),  // e.g. for Turtle syntax -> 6.022e+23
     Uri(String),
     BlankNode(String),
     TripleDelimiter,
@@ -13,13 +17,13 @@ pub enum Token {
     BaseDirective(String),
     QName(String, String),
     Prefix(String),
-    KeywordA,                // 'a'
-    PredicateListDelimiter,  // e.g. for Turtle syntax -> ;
-    ObjectListDelimiter,     // e.g. for Turtle syntax -> ,
-    CollectionStart,         // e.g. for Turtle syntax -> (
-    CollectionEnd,           // e.g. for Turtle syntax -> )
-    UnlabeledBlankNodeStart, // e.g. for Turtle syntax -> [
-    UnlabeledBlankNodeEnd,   // e.g. for Turtle syntax -> ]
+    KeywordA,                   // 'a'
+    PredicateListDelimiter,     // e.g. for Turtle syntax -> ;
+    ObjectListDelimiter,        // e.g. for Turtle syntax -> ,
+    CollectionStart,            // e.g. for Turtle syntax -> (
+    CollectionEnd,              // e.g. for Turtle syntax -> )
+    BlankNodePropertyListStart, // e.g. for Turtle syntax -> [
+    BlankNodePropertyListEnd,   // e.g. for Turtle syntax -> ]
     EndOfInput,
 
     // SPARQL
@@ -1,27 +1,36 @@
 use crate::error::{Error, ErrorType};
 use crate::reader::input_reader::{InputReader, InputReaderHelper};
 use crate::reader::lexer::n_triples_lexer::TokensFromNTriples;
-use crate::reader::lexer::rdf_lexer::RdfLexer;
+use crate::reader::lexer::rdf_lexer::{LexerMode, LexerOptions, RdfLexer};
 use crate::reader::lexer::rdf_lexer::TokensFromRdf;
 use crate::reader::lexer::token::Token;
 use crate::specs::turtle_specs::TurtleSpecs;
 use crate::specs::xml_specs::XmlDataTypes;
+use std::collections::VecDeque;
 use std::io::Read;
 use crate::Result;
 
 /// Produces tokens from Turtle syntax input.
 pub struct TurtleLexer<R: Read> {
     input_reader: InputReader<R>,
-    peeked_token: Option<Token>,
+    peeked_tokens: VecDeque<Token>,
+    options: LexerOptions,
+
+    /// Line and column of the input at which the token that was determined last starts.
+    token_line: usize,
+    token_column: usize,
 }
 
 /// Contains all implemented rules for creating tokens from Turtle syntax.
 pub trait TokensFromTurtle<R: Read>: TokensFromNTriples<R> {
     /// Parses the base or prefix definition.
-    fn get_base_or_prefix(input_reader: &mut InputReader<R>) -> Result<Token> {
+    fn get_base_or_prefix(
+        input_reader: &mut InputReader<R>,
+        options: &LexerOptions,
+    ) -> Result<Token> {
         match input_reader.peek_next_char()? {
-            Some('b') | Some('B') => Self::get_base_directive(input_reader),
-            Some('p') | Some('P') => Self::get_prefix_directive(input_reader),
+            Some('b') | Some('B') => Self::get_base_directive(input_reader, options),
+            Some('p') | Some('P') => Self::get_prefix_directive(input_reader, options),
             None | Some(_) => Err(Error::new(
                 ErrorType::InvalidReaderInput,
                 "Invalid input while trying to parse base or prefix definition.",
@@ -30,7 +39,10 @@ pub trait TokensFromTurtle<R: Read>: TokensFromNTriples<R> {
     }
 
     /// Parses the base directive.
-    fn get_base_directive(input_reader: &mut InputReader<R>) -> Result<Token> {
+    fn get_base_directive(
+        input_reader: &mut InputReader<R>,
+        options: &LexerOptions,
+    ) -> Result<Token> {
         let base_directive = input_reader.peek_next_k_chars(5)?;
 
         if base_directive.to_string().to_lowercase() != "base " {
@@ -42,7 +54,7 @@ pub trait TokensFromTurtle<R: Read>: TokensFromNTriples<R> {
 
         let _ = input_reader.get_until(|c| c == '<'); // consume 'base'
 
-        match Self::get_uri(input_reader)? {
+        match Self::get_uri(input_reader, options)? {
             Token::Uri(base_uri) => Ok(Token::BaseDirective(base_uri)),
             _ => Err(Error::new(
                 ErrorType::InvalidReaderInput,
@@ -52,7 +64,10 @@ pub trait TokensFromTurtle<R: Read>: TokensFromNTriples<R> {
     }
 
     /// Parses the prefix directive.
-    fn get_prefix_directive(input_reader: &mut InputReader<R>) -> Result<Token> {
+    fn get_prefix_directive(
+        input_reader: &mut InputReader<R>,
+        options: &LexerOptions,
+    ) -> Result<Token> {
         let prefix_directive = input_reader.peek_next_k_chars(7)?;
 
         if prefix_directive.to_string().to_lowercase() != "prefix " {
@@ -72,7 +87,7 @@ pub trait TokensFromTurtle<R: Read>: TokensFromNTriples<R> {
 
         let _ = input_reader.get_until(|c| c == '<'); // consume characters until URI begin
 
-        match Self::get_uri(input_reader)? {
+        match Self::get_uri(input_reader, options)? {
             Token::Uri(prefix_uri) => Ok(Token::PrefixDirective(name, prefix_uri)),
             _ => Err(Error::new(
                 ErrorType::InvalidReaderInput,
@@ -81,86 +96,113 @@ pub trait TokensFromTurtle<R: Read>: TokensFromNTriples<R> {
         }
     }
 
-    /// Parses integer, decimals and doubles.
-    fn get_numeric(input_reader: &mut InputReader<R>) -> Result<Token> {
-        let numeric =
-            input_reader.get_until_discard_leading_spaces(InputReaderHelper::node_delimiter)?;
-
-        // check if delimiter was '.' and if it is part of a decimal or if it is a delimiter
-        if input_reader.get_next_char()? == Some('.') {
-            let mut complete_numeric = numeric.clone();
-            match input_reader.peek_until(InputReaderHelper::node_delimiter) {
-                Ok(mut input_chars) => {
-                    complete_numeric.push(Some('.'));
-                    complete_numeric.append(&mut input_chars);
-
-                    if TurtleSpecs::is_double_literal(&complete_numeric.to_string()) {
-                        let _ = input_reader
-                            .get_until_discard_leading_spaces(InputReaderHelper::node_delimiter)?; // consume
-                        return Ok(Token::LiteralWithUrlDatatype(
-                            complete_numeric.to_string(),
-                            XmlDataTypes::Double.to_string(),
-                        ));
-                    }
-                }
-                _ => {}
-            }
+    /// Parses an integer, decimal or double and returns it as `Token::IntegerLiteral`,
+    /// `Token::DecimalLiteral` or `Token::DoubleLiteral`.
+    ///
+    /// Numbers with an exponent are doubles, numbers with a `.` but without exponent are
+    /// decimals. A `.` that is not followed by a digit or an exponent is not part of the
+    /// number, e.g. the triple delimiter of `5.`, and is not consumed.
+    fn get_number(input_reader: &mut InputReader<R>) -> Result<Token> {
+        let mut number = Self::get_bare_word(input_reader)?;
+
+        let next_chars = input_reader.peek_next_k_chars(2)?;
+        let is_fraction = |c| InputReaderHelper::digit(c) || c == 'e' || c == 'E';
+
+        if next_chars[0] == Some('.') && next_chars[1].is_some_and(is_fraction) {
+            Self::consume_next_char(input_reader); // consume '.'
+            number.push('.');
+            number.push_str(&Self::get_bare_word(input_reader)?);
         }
 
-        if TurtleSpecs::is_integer_literal(&numeric.to_string()) {
-            return Ok(Token::LiteralWithUrlDatatype(
-                numeric.to_string(),
-                XmlDataTypes::Integer.to_string(),
-            ));
-        } else if TurtleSpecs::is_double_literal(&numeric.to_string()) {
-            return Ok(Token::LiteralWithUrlDatatype(
-                numeric.to_string(),
-                XmlDataTypes::Double.to_string(),
-            ));
-        } else {
+        if !TurtleSpecs::is_numeric_literal(&number) {
             return Err(Error::new(
                 ErrorType::InvalidReaderInput,
-                "Invalid input for numeric literal.",
+                "Invalid input for numeric literal: ".to_string() + &number,
             ));
         }
+
+        if number.contains(['e', 'E']) {
+            Ok(Token::DoubleLiteral(number))
+        } else if number.contains('.') {
+            Ok(Token::DecimalLiteral(number))
+        } else {
+            Ok(Token::IntegerLiteral(number))
+        }
+    }
+
+    /// Parses a decimal or double starting with `.`, otherwise the `.` is a triple delimiter.
+    fn get_number_or_triple_delimiter(input_reader: &mut InputReader<R>) -> Result<Token> {
+        match input_reader.peek_next_k_chars(2)?[1] {
+            Some(c) if InputReaderHelper::digit(c) => Self::get_number(input_reader),
+            _ => {
+                Self::consume_next_char(input_reader); // consume '.'
+                Ok(Token::TripleDelimiter)
+            }
+        }
+    }
+
+    /// Returns and consumes the characters until the next node delimiter or the end of the input.
+    fn get_bare_word(input_reader: &mut InputReader<R>) -> Result<String> {
+        match input_reader.get_until_discard_leading_spaces(InputReaderHelper::node_delimiter) {
+            Ok(chars) => Ok(chars.to_string()),
+            Err(err) => match err.error_type() {
+                ErrorType::EndOfInput(chars) => Ok(chars.to_string()),
+                _ => Err(err),
+            },
+        }
     }
 
     /// Parses a boolean value and returns it as token.
     fn get_boolean_literal(input_reader: &mut InputReader<R>) -> Result<Token> {
-        let boolean =
-            input_reader.peek_until_discard_leading_spaces(InputReaderHelper::node_delimiter)?;
+        let boolean = Self::peek_bare_word(input_reader)?;
+
+        if TurtleSpecs::is_boolean_literal(&boolean) {
+            let _ = input_reader.get_next_k_chars(boolean.chars().count()); // consume
 
-        if TurtleSpecs::is_boolean_literal(&boolean.to_string()) {
-            return Ok(Token::LiteralWithUrlDatatype(
-                boolean.to_string(),
+            Ok(Token::LiteralWithUrlDatatype(
+                boolean,
                 XmlDataTypes::Boolean.to_string(),
-            ));
+            ))
         } else {
-            return Err(Error::new(
+            Err(Error::new(
                 ErrorType::InvalidReaderInput,
                 "Invalid input for boolean.",
-            ));
+            ))
         }
     }
 
     /// Parses the 'a' keyword.
     fn get_a_keyword(input_reader: &mut InputReader<R>) -> Result<Token> {
-        let a =
-            input_reader.peek_until_discard_leading_spaces(InputReaderHelper::node_delimiter)?;
-
-        if a.len() == 1 && a[0] == Some('a') {
-            return Ok(Token::KeywordA);
+        if Self::peek_bare_word(input_reader)? == "a" {
+            Self::consume_next_char(input_reader); // consume 'a'
+            Ok(Token::KeywordA)
         } else {
-            return Err(Error::new(
+            Err(Error::new(
                 ErrorType::InvalidReaderInput,
                 "Invalid input for keyword 'a'.",
-            ));
+            ))
+        }
+    }
+
+    /// Returns the characters until the next node delimiter or the end of the input without
+    /// consuming them.
+    fn peek_bare_word(input_reader: &mut InputReader<R>) -> Result<String> {
+        match input_reader.peek_until_discard_leading_spaces(InputReaderHelper::node_delimiter) {
+            Ok(chars) => Ok(chars.to_string()),
+            Err(err) => match err.error_type() {
+                ErrorType::EndOfInput(chars) => Ok(chars.to_string()),
+                _ => Err(err),
+            },
         }
     }
 
     /// Parses a literal from the input and returns it as token.
-    /// Parses a literal from the input and returns it as token.
-    fn get_literal(input_reader: &mut InputReader<R>) -> Result<Token> {
+    ///
+    /// Literals are delimited by `"` or `'`. Long literals are delimited by three of these
+    /// characters and may contain line breaks as well as one or two unescaped delimiters in a
+    /// row, they end at the first three delimiters in a row. Both result in `Token::Literal`
+    /// or one of the other literal tokens.
+    fn get_literal(input_reader: &mut InputReader<R>, options: &LexerOptions) -> Result<Token> {
         let literal_delimiter = input_reader.get_next_char()?;
         let mut is_multiline = false;
 
@@ -174,33 +216,30 @@ pub trait TokensFromTurtle<R: Read>: TokensFromNTriples<R> {
             let _ = input_reader.get_next_k_chars(2); // consume
         }
 
-        let mut found_literal_end = false;
         let mut literal = "".to_string();
 
-        while !found_literal_end {
-            literal.push_str(
-                &input_reader
-                    .get_until(|c| c == literal_delimiter.unwrap())?
-                    .to_string(),
-            );
+        loop {
+            literal.push_str(&Self::get_literal_until(
+                input_reader,
+                literal_delimiter.unwrap(),
+                !is_multiline,
+                options,
+            )?);
 
-            if is_multiline {
-                // if not escaped check if the literal is complete
-                let potential_literal_delimiters = input_reader.peek_next_k_chars(2)?.to_vec();
+            if !is_multiline {
+                break;
+            }
 
-                if potential_literal_delimiters[0] == literal_delimiter
-                    && potential_literal_delimiters[1] == literal_delimiter
-                {
-                    Self::consume_next_char(input_reader);
-                    Self::consume_next_char(input_reader);
+            // a multiline literal only ends with three delimiters in a row
+            let potential_literal_delimiters = input_reader.peek_next_k_chars(3)?.to_vec();
 
-                    found_literal_end = true;
-                } else {
-                    literal.push_str(&input_reader.get_next_k_chars(1)?.to_string());
-                }
-            } else {
-                found_literal_end = true;
+            if potential_literal_delimiters.iter().all(|&c| c == literal_delimiter) {
+                let _ = input_reader.get_next_k_chars(2); // consume, the last one is consumed below
+                break;
             }
+
+            Self::consume_next_char(input_reader);
+            literal.push(literal_delimiter.unwrap());
         }
 
         Self::consume_next_char(input_reader); // consume '"'
@@ -218,7 +257,7 @@ pub trait TokensFromTurtle<R: Read>: TokensFromNTriples<R> {
                 match input_reader.peek_next_char()? {
                     Some('<') => {
                         // data type is an URI (NTriples allows only URI data types)
-                        match Self::get_uri(input_reader)? {
+                        match Self::get_uri(input_reader, options)? {
                             Token::Uri(datatype_uri) => {
                                 Ok(Token::LiteralWithUrlDatatype(literal, datatype_uri))
                             }
@@ -256,7 +295,7 @@ pub trait TokensFromTurtle<R: Read>: TokensFromNTriples<R> {
         match input_reader.get_until(InputReaderHelper::node_delimiter) {
             Ok(chars) => Ok(Token::QName(prefix, chars.to_string())),
             Err(err) => match err.error_type() {
-                &ErrorType::EndOfInput(ref chars) => Ok(Token::QName(prefix, chars.to_string())),
+                ErrorType::EndOfInput(chars) => Ok(Token::QName(prefix, chars.to_string())),
                 _ => Err(Error::new(
                     ErrorType::InvalidReaderInput,
                     "Invalid input for Turtle lexer while parsing QName.",
@@ -266,78 +305,102 @@ pub trait TokensFromTurtle<R: Read>: TokensFromNTriples<R> {
     }
 }
 
-impl<R: Read> TokensFromRdf<R> for TurtleLexer<R> {}
-impl<R: Read> TokensFromNTriples<R> for TurtleLexer<R> {}
-impl<R: Read> TokensFromTurtle<R> for TurtleLexer<R> {}
-
-impl<R: Read> RdfLexer<R> for TurtleLexer<R> {
-    /// Constructor for `TurtleLexer`.
+impl<R: Read> TurtleLexer<R> {
+    /// Constructor for `TurtleLexer` using the provided options.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rdf::reader::lexer::rdf_lexer::RdfLexer;
+    /// use rdf::reader::lexer::rdf_lexer::{LexerOptions, RdfLexer};
     /// use rdf::reader::lexer::turtle_lexer::TurtleLexer;
+    /// use rdf::reader::lexer::token::Token;
     ///
-    /// let input = "<example.org/a>".as_bytes();
+    /// let options = LexerOptions { preserve_whitespace: true, ..Default::default() };
+    /// let mut lexer = TurtleLexer::with_options("  <example.org/a>".as_bytes(), options);
     ///
-    /// TurtleLexer::new(input);
+    /// assert_eq!(lexer.get_next_token().unwrap(), Token::Whitespace("  ".to_string()));
+    /// assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("example.org/a".to_string()));
     /// ```
-    fn new(input: R) -> TurtleLexer<R> {
+    pub fn with_options(input: R, options: LexerOptions) -> TurtleLexer<R> {
         TurtleLexer {
             input_reader: InputReader::new(input),
-            peeked_token: None,
+            peeked_tokens: VecDeque::new(),
+            token_line: 1,
+            token_column: 1,
+            options,
         }
     }
 
-    /// Determines the next token from the input and consumes the read input characters.
+    /// Constructor for `TurtleLexer` using the options of the provided mode.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rdf::reader::lexer::rdf_lexer::RdfLexer;
+    /// use rdf::reader::lexer::rdf_lexer::{LexerMode, RdfLexer};
     /// use rdf::reader::lexer::turtle_lexer::TurtleLexer;
     /// use rdf::reader::lexer::token::Token;
     ///
-    /// let input = "_:auto <example.org/b> \"test\" .".as_bytes();
+    /// let input = "<example.org/a\tb>";
     ///
-    /// let mut lexer = TurtleLexer::new(input);
+    /// let mut strict = TurtleLexer::with_mode(input.as_bytes(), LexerMode::Strict);
+    /// let mut lenient = TurtleLexer::with_mode(input.as_bytes(), LexerMode::Lenient);
     ///
-    /// assert_eq!(lexer.get_next_token().unwrap(), Token::BlankNode("auto".to_string()));
-    /// assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("example.org/b".to_string()));
-    /// assert_eq!(lexer.get_next_token().unwrap(), Token::Literal("test".to_string()));
-    /// assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+    /// assert!(strict.get_next_token().is_err());
+    /// assert_eq!(lenient.get_next_token().unwrap(), Token::Uri("example.org/a\tb".to_string()));
     /// ```
+    pub fn with_mode(input: R, mode: LexerMode) -> TurtleLexer<R> {
+        TurtleLexer::with_options(input, LexerOptions::from_mode(mode))
+    }
+
+    /// Returns the line at which the token that was determined last starts, starting at 1.
     ///
-    /// # Failures
-    ///
-    /// - Input that does not conform to the Turtle syntax standard.
+    /// A peeked token counts as determined, hence after peeking this is the line of the peeked token.
+    pub fn token_line(&self) -> usize {
+        self.token_line
+    }
+
+    /// Returns the column at which the token that was determined last starts, starting at 1.
     ///
-    fn get_next_token(&mut self) -> Result<Token> {
-        // first read peeked characters
-        match self.peeked_token.clone() {
-            Some(token) => {
-                self.peeked_token = None;
+    /// See `InputReader::position`.
+    pub fn token_column(&self) -> usize {
+        self.token_column
+    }
+
+    /// Determines the next token from the input, ignoring the peeked tokens.
+    fn read_next_token(&mut self) -> Result<Token> {
+        if self.options.preserve_whitespace {
+            if let Some(token) = TurtleLexer::get_whitespace(&mut self.input_reader)? {
                 return Ok(token);
             }
-            None => {}
         }
 
-        match self.input_reader.peek_next_char_discard_leading_spaces()? {
+        let next_char = self.input_reader.peek_next_char_discard_leading_spaces()?;
+        let (line, column) = self.input_reader.position();
+        self.token_line = line;
+        self.token_column = column;
+
+        self.get_token(next_char)
+            .map_err(|err| TurtleLexer::<R>::locate_error(err, line, column))
+    }
+
+    /// Determines the token starting with the provided next character.
+    fn get_token(&mut self, next_char: Option<char>) -> Result<Token> {
+        match next_char {
             Some('#') => return TurtleLexer::get_comment(&mut self.input_reader),
             Some('@') => {
                 TurtleLexer::consume_next_char(&mut self.input_reader); // consume '@'
-                return TurtleLexer::get_base_or_prefix(&mut self.input_reader);
+                return TurtleLexer::get_base_or_prefix(&mut self.input_reader, &self.options);
             }
             Some('"') | Some('\'') => {
-                return <TurtleLexer<R> as TokensFromTurtle<R>>::get_literal(&mut self.input_reader)
+                return <TurtleLexer<R> as TokensFromTurtle<R>>::get_literal(
+                    &mut self.input_reader,
+                    &self.options,
+                )
             }
-            Some('<') => return TurtleLexer::get_uri(&mut self.input_reader),
+            Some('<') => return TurtleLexer::get_uri(&mut self.input_reader, &self.options),
             Some('_') => return TurtleLexer::get_blank_node(&mut self.input_reader),
             Some('.') => {
-                // try to parse a decimal, if there is an error then it is a triple delimiter
-                return TurtleLexer::get_numeric(&mut self.input_reader)
-                    .or_else(|_| Ok(Token::TripleDelimiter));
+                return TurtleLexer::get_number_or_triple_delimiter(&mut self.input_reader)
             }
             Some(',') => {
                 TurtleLexer::consume_next_char(&mut self.input_reader); // consume ','
@@ -357,39 +420,35 @@ impl<R: Read> RdfLexer<R> for TurtleLexer<R> {
             }
             Some('[') => {
                 TurtleLexer::consume_next_char(&mut self.input_reader); // consume '['
-                return Ok(Token::UnlabeledBlankNodeStart);
+                return Ok(Token::BlankNodePropertyListStart);
             }
             Some(']') => {
                 TurtleLexer::consume_next_char(&mut self.input_reader); // consume ']'
-                return Ok(Token::UnlabeledBlankNodeEnd);
+                return Ok(Token::BlankNodePropertyListEnd);
             }
             Some('P') | Some('B') => {
-                // try parsing PREFIX or BASE
-                match TurtleLexer::get_base_or_prefix(&mut self.input_reader) {
-                    Ok(token) => return Ok(token),
-                    _ => {} // continue, because it could still be a QName
+                // try parsing PREFIX or BASE, otherwise continue because it could still be a QName
+                if let Ok(token) =
+                    TurtleLexer::get_base_or_prefix(&mut self.input_reader, &self.options)
+                {
+                    return Ok(token);
                 }
             }
             Some('t') | Some('f') => {
-                // try parsing 'true' or 'false'
-                match TurtleLexer::get_boolean_literal(&mut self.input_reader) {
-                    Ok(token) => return Ok(token),
-                    _ => {} // continue, because it could still be a QName
+                // try parsing 'true' or 'false', otherwise continue because it could still be a QName
+                if let Ok(token) = TurtleLexer::get_boolean_literal(&mut self.input_reader) {
+                    return Ok(token);
                 }
             }
             Some('a') => {
-                // try parsing the 'a' keyword
-                match TurtleLexer::get_a_keyword(&mut self.input_reader) {
-                    Ok(token) => {
-                        TurtleLexer::consume_next_char(&mut self.input_reader);
-                        return Ok(token)
-                    },
-                    _ => {} // continue, because it could still be a QName
+                // try parsing the 'a' keyword, otherwise continue because it could still be a QName
+                if let Ok(token) = TurtleLexer::get_a_keyword(&mut self.input_reader) {
+                    return Ok(token);
                 }
             }
-            Some('+') | Some('-') => return TurtleLexer::get_numeric(&mut self.input_reader),
+            Some('+') | Some('-') => return TurtleLexer::get_number(&mut self.input_reader),
             Some(c) if InputReaderHelper::digit(c) => {
-                return TurtleLexer::get_numeric(&mut self.input_reader)
+                return TurtleLexer::get_number(&mut self.input_reader)
             }
             Some(_) => {}
             None => return Ok(Token::EndOfInput),
@@ -397,6 +456,64 @@ impl<R: Read> RdfLexer<R> for TurtleLexer<R> {
 
         TurtleLexer::get_qname(&mut self.input_reader)
     }
+}
+
+impl<R: Read> TokensFromRdf<R> for TurtleLexer<R> {}
+impl<R: Read> TokensFromNTriples<R> for TurtleLexer<R> {}
+impl<R: Read> TokensFromTurtle<R> for TurtleLexer<R> {}
+
+impl<R: Read> RdfLexer<R> for TurtleLexer<R> {
+    /// Constructor for `TurtleLexer`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::lexer::rdf_lexer::RdfLexer;
+    /// use rdf::reader::lexer::turtle_lexer::TurtleLexer;
+    ///
+    /// let input = "<example.org/a>".as_bytes();
+    ///
+    /// TurtleLexer::new(input);
+    /// ```
+    fn new(input: R) -> TurtleLexer<R> {
+        TurtleLexer {
+            input_reader: InputReader::new(input),
+            peeked_tokens: VecDeque::new(),
+            token_line: 1,
+            token_column: 1,
+            options: LexerOptions::default(),
+        }
+    }
+
+    /// Determines the next token from the input and consumes the read input characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::lexer::rdf_lexer::RdfLexer;
+    /// use rdf::reader::lexer::turtle_lexer::TurtleLexer;
+    /// use rdf::reader::lexer::token::Token;
+    ///
+    /// let input = "_:auto <example.org/b> \"test\" .".as_bytes();
+    ///
+    /// let mut lexer = TurtleLexer::new(input);
+    ///
+    /// assert_eq!(lexer.get_next_token().unwrap(), Token::BlankNode("auto".to_string()));
+    /// assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("example.org/b".to_string()));
+    /// assert_eq!(lexer.get_next_token().unwrap(), Token::Literal("test".to_string()));
+    /// assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Input that does not conform to the Turtle syntax standard.
+    ///
+    fn get_next_token(&mut self) -> Result<Token> {
+        match self.peeked_tokens.pop_front() {
+            Some(token) => Ok(token),
+            None => self.read_next_token(),
+        }
+    }
 
     /// Determines the next token without consuming the input.
     ///
@@ -423,26 +540,105 @@ impl<R: Read> RdfLexer<R> for TurtleLexer<R> {
     /// - Invalid input that does not conform with NTriples standard.
     ///
     fn peek_next_token(&mut self) -> Result<Token> {
-        match self.peeked_token.clone() {
-            Some(token) => Ok(token),
-            None => match self.get_next_token() {
-                Ok(next) => {
-                    self.peeked_token = Some(next.clone());
-                    return Ok(next);
-                }
-                Err(err) => return Err(err),
-            },
+        self.peek_nth_token(0)
+    }
+
+    /// Determines the token `n` positions after the next token without consuming any token,
+    /// `peek_nth_token(0)` is the same as `peek_next_token()`.
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with the Turtle syntax standard.
+    ///
+    fn peek_nth_token(&mut self, n: usize) -> Result<Token> {
+        while self.peeked_tokens.len() <= n {
+            let next = self.read_next_token()?;
+            self.peeked_tokens.push_back(next);
         }
+
+        Ok(self.peeked_tokens[n].clone())
+    }
+
+    /// Returns the line at which the token that was determined last starts.
+    fn token_position(&self) -> Option<usize> {
+        Some(self.token_line)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::error::{Error, ErrorType};
+    use crate::reader::lexer::rdf_lexer::{LexerMode, LexerOptions};
     use crate::reader::lexer::rdf_lexer::RdfLexer;
     use crate::reader::lexer::token::Token;
     use crate::reader::lexer::turtle_lexer::TurtleLexer;
     use crate::specs::xml_specs::XmlDataTypes;
 
+    #[test]
+    fn peek_multiple_tokens() {
+        let input = "<example.org/a> <example.org/b> \"c\" ; <example.org/d> 1 .".as_bytes();
+
+        let mut lexer = TurtleLexer::new(input);
+
+        let peeked = vec![
+            lexer.peek_nth_token(0).unwrap(),
+            lexer.peek_nth_token(1).unwrap(),
+            lexer.peek_nth_token(2).unwrap(),
+        ];
+
+        assert_eq!(peeked[1], Token::Uri("example.org/b".to_string()));
+        assert_eq!(lexer.peek_next_token().unwrap(), peeked[0]);
+        assert_eq!(lexer.peek_nth_token(3).unwrap(), Token::PredicateListDelimiter);
+
+        for token in peeked {
+            assert_eq!(lexer.get_next_token().unwrap(), token);
+        }
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::PredicateListDelimiter);
+        assert_eq!(
+            lexer.peek_nth_token(1).unwrap(),
+            Token::IntegerLiteral("1".to_string())
+        );
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("example.org/d".to_string()));
+    }
+
+    #[test]
+    fn interleave_peek_and_get_around_directives() {
+        let input = "@prefix ex: <http://example.org/> .ex:s ex:p ex:o .
+                     PREFIX b: <http://b.example/> . b:s a \"x\" .
+                     @base <http://base/> .<s> ex:p 1 .";
+
+        let mut reference = TurtleLexer::new(input.as_bytes());
+        let mut expected = Vec::new();
+
+        loop {
+            let token = reference.get_next_token().unwrap();
+            expected.push(token.clone());
+
+            if token == Token::EndOfInput {
+                break;
+            }
+        }
+
+        assert_eq!(expected.len(), 19);
+        assert_eq!(expected[2], Token::QName("ex:".to_string(), "s".to_string()));
+        assert_eq!(expected[14], Token::Uri("s".to_string()));
+
+        // peek a varying number of tokens ahead before consuming each token
+        let mut lexer = TurtleLexer::new(input.as_bytes());
+
+        for (i, token) in expected.iter().enumerate() {
+            let lookahead = i % 3;
+
+            if i + lookahead < expected.len() {
+                assert_eq!(&lexer.peek_nth_token(lookahead).unwrap(), &expected[i + lookahead]);
+            }
+
+            assert_eq!(&lexer.peek_next_token().unwrap(), token);
+            assert_eq!(&lexer.get_next_token().unwrap(), token);
+        }
+    }
+
     #[test]
     fn parse_base_directive() {
         let input = "@base <http://example.org/> .".as_bytes();
@@ -517,6 +713,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_comment_after_triple_delimiter() {
+        let input = "<s> <p> <o> . # note\n<s> <p> <o> .".as_bytes();
+
+        let mut lexer = TurtleLexer::new(input);
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("s".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("p".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("o".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Comment("note".to_string())
+        );
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("s".to_string()));
+    }
+
+    #[test]
+    fn parse_literal_with_unknown_escape() {
+        let input = "\"a\\/b\" .".as_bytes();
+
+        let mut lexer = TurtleLexer::new(input);
+        assert!(lexer.get_next_token().is_err());
+
+        let options = LexerOptions {
+            lenient_escapes: true,
+            ..Default::default()
+        };
+
+        let mut lexer = TurtleLexer::with_options(input, options);
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Literal("a/b".to_string())
+        );
+    }
+
     #[test]
     fn parse_literal() {
         let input = "\"a\"".as_bytes();
@@ -586,6 +818,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_list_delimiters() {
+        let input = "<s> <p> \"a\", <o> ; ex:q ex:o , _:b .".as_bytes();
+
+        let mut lexer = TurtleLexer::new(input);
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("s".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("p".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Literal("a".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::ObjectListDelimiter);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("o".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::PredicateListDelimiter);
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::QName("ex:".to_string(), "q".to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::QName("ex:".to_string(), "o".to_string())
+        );
+        assert_eq!(lexer.get_next_token().unwrap(), Token::ObjectListDelimiter);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::BlankNode("b".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+    }
+
+    #[test]
+    fn parse_collection_delimiters() {
+        let input = "( <a> <b> ) ()".as_bytes();
+
+        let mut lexer = TurtleLexer::new(input);
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::CollectionStart);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("a".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("b".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::CollectionEnd);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::CollectionStart);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::CollectionEnd);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::EndOfInput);
+    }
+
+    #[test]
+    fn parse_blank_node_property_list_delimiters() {
+        let input = "[ <p> <o> ] []".as_bytes();
+
+        let mut lexer = TurtleLexer::new(input);
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::BlankNodePropertyListStart);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("p".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("o".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::BlankNodePropertyListEnd);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::BlankNodePropertyListStart);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::BlankNodePropertyListEnd);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::EndOfInput);
+    }
+
     #[test]
     fn parse_literal_with_data_type() {
         let input = "\"a\"^^<example.org/abc>".as_bytes();
@@ -640,34 +927,231 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_long_literals() {
+        let input = "\"\"\"line 1\n\"line\" \"\"2\"\"\"@en '''a ' b '' c''' \"\"\"\"\"\" .".as_bytes();
+
+        let mut lexer = TurtleLexer::new(input);
+
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::LiteralWithLanguageSpecification(
+                "line 1\n\"line\" \"\"2".to_string(),
+                "en".to_string()
+            )
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Literal("a ' b '' c".to_string())
+        );
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Literal("".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+    }
+
+    #[test]
+    fn reject_unterminated_long_literal() {
+        for input in ["\"\"\"abc\n", "\"\"\"abc\"\" .", "'''abc\"\"\" ."] {
+            let mut lexer = TurtleLexer::new(input.as_bytes());
+
+            assert!(lexer.get_next_token().is_err());
+        }
+    }
+
+    #[test]
+    fn reject_line_break_in_short_literal() {
+        for input in ["\"a\nb\" .", "'a\rb' ."] {
+            let mut lexer = TurtleLexer::new(input.as_bytes());
+
+            assert!(matches!(
+                lexer.get_next_token().unwrap_err().error_type(),
+                ErrorType::InvalidReaderInput
+            ));
+        }
+
+        let mut lexer = TurtleLexer::new("'a\tb' \"\"\"a\nb\"\"\"".as_bytes());
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Literal("a\tb".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Literal("a\nb".to_string()));
+    }
+
     #[test]
     fn parse_numeric_literals() {
-        let input = "4 1.2 -5.123 -.123 .123 5e10 .".as_bytes();
+        let input = "4 1.2 -5.123 -.123 .123 5e10 +7 .5 6.022e+23 -1.5E-3 2.e1 .".as_bytes();
+        let mut lexer = TurtleLexer::new(input);
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::IntegerLiteral("4".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::DecimalLiteral("1.2".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::DecimalLiteral("-5.123".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::DecimalLiteral("-.123".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::DecimalLiteral(".123".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::DoubleLiteral("5e10".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::IntegerLiteral("+7".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::DecimalLiteral(".5".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::DoubleLiteral("6.022e+23".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::DoubleLiteral("-1.5E-3".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::DoubleLiteral("2.e1".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::EndOfInput);
+    }
+
+    #[test]
+    fn parse_numeric_literal_before_triple_delimiter() {
+        let mut lexer = TurtleLexer::new("<s> <p> 5.\n<s> <p> -2.5.".as_bytes());
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("s".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("p".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::IntegerLiteral("5".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("s".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("p".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::DecimalLiteral("-2.5".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::EndOfInput);
+    }
+
+    #[test]
+    fn reject_invalid_numeric_literals() {
+        for input in ["1e .", "+ .", "-e3 .", "1.5e+ ."] {
+            let mut lexer = TurtleLexer::new(input.as_bytes());
+
+            assert!(lexer.get_next_token().is_err(), "{} must be rejected", input);
+        }
+    }
+
+    #[test]
+    fn parse_preserving_whitespace() {
+        let input = "@prefix ex: <http://ex/> .\n\nex:a  a [ ex:b \"c\"@en ] ;\n\tex:d ( 1 2.5 ) ,  _:e .\n";
+        let options = LexerOptions {
+            preserve_whitespace: true,
+            ..Default::default()
+        };
+
+        let mut lexer = TurtleLexer::with_options(input.as_bytes(), options);
+        let mut document = "".to_string();
+
+        loop {
+            let text = match lexer.get_next_token().unwrap() {
+                Token::EndOfInput => break,
+                Token::Whitespace(chars) => chars,
+                Token::PrefixDirective(prefix, uri) => format!("@prefix {} <{}>", prefix, uri),
+                Token::QName(prefix, path) => prefix + &path,
+                Token::BlankNode(id) => format!("_:{}", id),
+                Token::LiteralWithLanguageSpecification(literal, language) => {
+                    format!("\"{}\"@{}", literal, language)
+                }
+                Token::IntegerLiteral(literal) | Token::DecimalLiteral(literal) => literal,
+                Token::KeywordA => "a".to_string(),
+                Token::TripleDelimiter => ".".to_string(),
+                Token::PredicateListDelimiter => ";".to_string(),
+                Token::ObjectListDelimiter => ",".to_string(),
+                Token::CollectionStart => "(".to_string(),
+                Token::CollectionEnd => ")".to_string(),
+                Token::BlankNodePropertyListStart => "[".to_string(),
+                Token::BlankNodePropertyListEnd => "]".to_string(),
+                token => panic!("Unexpected token {:?}", token),
+            };
+
+            document.push_str(&text);
+        }
+
+        assert_eq!(document, input);
+    }
+
+    #[test]
+    fn parse_uri_with_query_and_fragment() {
+        let input = "<http://ex/path?x=1&y=2#frag> <http://ex/p#> \"o\"^^<http://ex/t?a=b#c> .".as_bytes();
         let mut lexer = TurtleLexer::new(input);
 
         assert_eq!(
             lexer.get_next_token().unwrap(),
-            Token::LiteralWithUrlDatatype("4".to_string(), XmlDataTypes::Integer.to_string())
+            Token::Uri("http://ex/path?x=1&y=2#frag".to_string())
         );
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("http://ex/p#".to_string()));
         assert_eq!(
             lexer.get_next_token().unwrap(),
-            Token::LiteralWithUrlDatatype("1.2".to_string(), XmlDataTypes::Double.to_string())
+            Token::LiteralWithUrlDatatype("o".to_string(), "http://ex/t?a=b#c".to_string())
         );
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+    }
+
+    #[test]
+    fn parse_uris_without_whitespace() {
+        let input = "<a><b><c>.".as_bytes();
+
+        let mut lexer = TurtleLexer::new(input);
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("a".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("b".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("c".to_string()));
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::EndOfInput);
+    }
+
+    #[test]
+    fn parse_keyword_a_and_boolean_literals() {
+        let input = "a a:x abc:y ab:z true false truex:t a".as_bytes();
+        let boolean = |value: &str| {
+            Token::LiteralWithUrlDatatype(value.to_string(), XmlDataTypes::Boolean.to_string())
+        };
+
+        let mut lexer = TurtleLexer::new(input);
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::KeywordA);
         assert_eq!(
             lexer.get_next_token().unwrap(),
-            Token::LiteralWithUrlDatatype("-5.123".to_string(), XmlDataTypes::Double.to_string())
+            Token::QName("a:".to_string(), "x".to_string())
         );
         assert_eq!(
             lexer.get_next_token().unwrap(),
-            Token::LiteralWithUrlDatatype("-.123".to_string(), XmlDataTypes::Double.to_string())
+            Token::QName("abc:".to_string(), "y".to_string())
         );
         assert_eq!(
             lexer.get_next_token().unwrap(),
-            Token::LiteralWithUrlDatatype(".123".to_string(), XmlDataTypes::Double.to_string())
+            Token::QName("ab:".to_string(), "z".to_string())
         );
+        assert_eq!(lexer.get_next_token().unwrap(), boolean("true"));
+        assert_eq!(lexer.get_next_token().unwrap(), boolean("false"));
         assert_eq!(
             lexer.get_next_token().unwrap(),
-            Token::LiteralWithUrlDatatype("5e10".to_string(), XmlDataTypes::Double.to_string())
+            Token::QName("truex:".to_string(), "t".to_string())
         );
+        assert_eq!(lexer.get_next_token().unwrap(), Token::KeywordA);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::EndOfInput);
+    }
+
+    #[test]
+    fn lex_in_strict_and_lenient_mode() {
+        let input = "<http://example.org/a\tb> <http://example.org/p> \"a\\/b\" .";
+
+        let tokenize = |mut lexer: TurtleLexer<&[u8]>| -> Result<Vec<Token>, Error> {
+            let mut tokens = Vec::new();
+
+            loop {
+                match lexer.get_next_token()? {
+                    Token::EndOfInput => return Ok(tokens),
+                    token => tokens.push(token),
+                }
+            }
+        };
+
+        assert_eq!(
+            tokenize(TurtleLexer::with_mode(input.as_bytes(), LexerMode::Lenient)).unwrap(),
+            vec![
+                Token::Uri("http://example.org/a\tb".to_string()),
+                Token::Uri("http://example.org/p".to_string()),
+                Token::Literal("a/b".to_string()),
+                Token::TripleDelimiter,
+            ]
+        );
+
+        for lexer in [
+            TurtleLexer::new(input.as_bytes()),
+            TurtleLexer::with_mode(input.as_bytes(), LexerMode::Strict),
+        ] {
+            match tokenize(lexer) {
+                Ok(tokens) => panic!("Malformed input must be rejected: {:?}", tokens),
+                Err(e) => assert!(matches!(e.error_type(), ErrorType::InvalidReaderInput)),
+            }
+        }
     }
 }
@@ -0,0 +1,79 @@
+use std::collections::HashSet;
+use std::rc::Rc;
+
+/// Pool of literal values in which equal values share their storage.
+///
+/// Parsed literal nodes own their values, hence the parsers do not fill a pool. It is
+/// meant for callers that keep literal values in their own structures, e.g. a column of
+/// category strings that repeat across millions of triples.
+#[derive(Debug, Default, Clone)]
+pub struct LiteralPool {
+    values: HashSet<Rc<str>>,
+}
+
+impl LiteralPool {
+    /// Constructor of an empty `LiteralPool`.
+    pub fn new() -> LiteralPool {
+        LiteralPool::default()
+    }
+
+    /// Returns the pooled value equal to the provided value, it is added if not yet pooled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::literal_pool::LiteralPool;
+    /// use std::rc::Rc;
+    ///
+    /// let mut pool = LiteralPool::new();
+    ///
+    /// let first = pool.intern("category");
+    /// let second = pool.intern("category");
+    ///
+    /// assert!(Rc::ptr_eq(&first, &second));
+    /// assert_eq!(pool.len(), 1);
+    /// ```
+    pub fn intern(&mut self, value: &str) -> Rc<str> {
+        if let Some(pooled) = self.values.get(value) {
+            return Rc::clone(pooled);
+        }
+
+        let pooled: Rc<str> = Rc::from(value);
+        self.values.insert(Rc::clone(&pooled));
+        pooled
+    }
+
+    /// Returns the pooled value equal to the provided value, if any.
+    pub fn get(&self, value: &str) -> Option<Rc<str>> {
+        self.values.get(value).cloned()
+    }
+
+    /// Returns the number of distinct pooled values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if no value is pooled.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::reader::literal_pool::LiteralPool;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_intern_distinct_values() {
+        let mut pool = LiteralPool::new();
+
+        let first = pool.intern("a");
+        let other = pool.intern("b");
+
+        assert!(!Rc::ptr_eq(&first, &other));
+        assert!(Rc::ptr_eq(&first, &pool.get("a").unwrap()));
+        assert!(pool.get("c").is_none());
+        assert_eq!(pool.len(), 2);
+    }
+}
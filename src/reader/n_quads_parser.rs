@@ -0,0 +1,339 @@
+use crate::dataset::Dataset;
+use crate::error::{Error, ErrorType};
+use crate::node::Node;
+use crate::quad::Quad;
+use crate::reader::lexer::n_quads_lexer::NQuadsLexer;
+use crate::reader::lexer::rdf_lexer::RdfLexer;
+use crate::reader::lexer::token::Token;
+use crate::uri::Uri;
+use crate::Result;
+use std::io::Cursor;
+use std::io::Read;
+
+/// RDF parser to generate an RDF dataset from N-Quads syntax.
+///
+/// Statements without graph label are added to the default graph, all others
+/// are added to the named graph identified by the URI or blank node label.
+///
+/// Blank node labels are scoped to the whole document: a blank node used as
+/// graph label denotes the same node as the same label used as subject or object,
+/// regardless of the graph the statement belongs to.
+pub struct NQuadsParser<R: Read> {
+    lexer: NQuadsLexer<R>,
+}
+
+impl NQuadsParser<Cursor<Vec<u8>>> {
+    /// Constructor of `NQuadsParser` from input string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_quads_parser::NQuadsParser;
+    ///
+    /// let input = "<http://example.org/s> <http://example.org/p> <http://example.org/o> <http://example.org/g> .";
+    ///
+    /// let reader = NQuadsParser::from_string(input.to_string());
+    /// ```
+    pub fn from_string<S>(input: S) -> NQuadsParser<Cursor<Vec<u8>>>
+    where
+        S: Into<String>,
+    {
+        NQuadsParser::from_reader(Cursor::new(input.into().into_bytes()))
+    }
+}
+
+impl<R: Read> NQuadsParser<R> {
+    /// Constructor of `NQuadsParser` from input reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_quads_parser::NQuadsParser;
+    ///
+    /// let input = "<http://example.org/s> <http://example.org/p> <http://example.org/o> _:g .";
+    ///
+    /// let reader = NQuadsParser::from_reader(input.as_bytes());
+    /// ```
+    pub fn from_reader(input: R) -> NQuadsParser<R> {
+        NQuadsParser {
+            lexer: NQuadsLexer::new(input),
+        }
+    }
+
+    /// Generates an RDF dataset from N-Quads syntax.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_quads_parser::NQuadsParser;
+    /// use rdf::node::Node;
+    ///
+    /// let input = "<http://example.org/s> <http://example.org/p> <http://example.org/o> .
+    ///              <http://example.org/s> <http://example.org/p> <http://example.org/o> _:g .";
+    ///
+    /// let mut reader = NQuadsParser::from_string(input.to_string());
+    /// let dataset = reader.decode().unwrap();
+    ///
+    /// assert_eq!(dataset.default_graph().count(), 1);
+    /// assert_eq!(dataset.named_graph(&Node::BlankNode { id: "g".to_string() }).unwrap().count(), 1);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with the N-Quads standard.
+    /// - Invalid node type for triple segment or graph label.
+    ///
+    pub fn decode(&mut self) -> Result<Dataset> {
+        let mut dataset = Dataset::new();
+
+        loop {
+            match self.lexer.peek_next_token()? {
+                Token::Comment(_) => {
+                    // ignore comments
+                    let _ = self.lexer.get_next_token();
+                    continue;
+                }
+                Token::EndOfInput => return Ok(dataset),
+                Token::TripleDelimiter => {
+                    return Err(Error::new(
+                        ErrorType::UnexpectedToken,
+                        "Empty statement: expected N-Quads subject instead of '.'",
+                    ))
+                }
+                _ => {}
+            }
+
+            match self.read_quad() {
+                Ok(quad) => {
+                    let graph = match quad.graph() {
+                        Some(graph_name) => dataset.named_graph_mut(graph_name),
+                        None => dataset.default_graph_mut(),
+                    };
+
+                    graph.add_triple(&quad.to_triple());
+                }
+                Err(err) => match *err.error_type() {
+                    ErrorType::EndOfInput(_) => return Ok(dataset),
+                    _ => return Err(err),
+                },
+            }
+        }
+    }
+
+    /// Creates a quad with the optional graph label from the parsed tokens.
+    fn read_quad(&mut self) -> Result<Quad> {
+        let subject = self.read_subject()?;
+        let predicate = self.read_predicate()?;
+        let object = self.read_object()?;
+        let graph_name = self.read_graph_label()?;
+
+        match self.lexer.get_next_token() {
+            Ok(Token::TripleDelimiter) => {}
+            _ => {
+                return Err(Error::new(
+                    ErrorType::InvalidReaderInput,
+                    "Expected N-Quads statement delimiter.",
+                ))
+            }
+        }
+
+        Ok(Quad::new(
+            &subject,
+            &predicate,
+            &object,
+            graph_name.as_ref(),
+        ))
+    }
+
+    /// Get the next token and check if it is a valid subject and create a new subject node.
+    fn read_subject(&mut self) -> Result<Node> {
+        match self.lexer.get_next_token() {
+            Ok(Token::BlankNode(id)) => Ok(Node::BlankNode { id }),
+            Ok(Token::Uri(uri)) => Ok(Node::UriNode { uri: Uri::new(uri) }),
+            _ => Err(Error::new(
+                ErrorType::InvalidToken,
+                "Invalid token for N-Quads subject.",
+            )),
+        }
+    }
+
+    /// Get the next token and check if it is a valid predicate and create a new predicate node.
+    fn read_predicate(&mut self) -> Result<Node> {
+        match self.lexer.get_next_token() {
+            Ok(Token::Uri(uri)) => Ok(Node::UriNode { uri: Uri::new(uri) }),
+            _ => Err(Error::new(
+                ErrorType::InvalidToken,
+                "Invalid token for N-Quads predicate.",
+            )),
+        }
+    }
+
+    /// Get the next token and check if it is a valid object and create a new object node.
+    fn read_object(&mut self) -> Result<Node> {
+        match self.lexer.get_next_token()? {
+            Token::BlankNode(id) => Ok(Node::BlankNode { id }),
+            Token::Uri(uri) => Ok(Node::UriNode { uri: Uri::new(uri) }),
+            Token::LiteralWithLanguageSpecification(literal, lang) => Ok(Node::LiteralNode {
+                literal,
+                data_type: None,
+                language: Some(lang),
+            }),
+            Token::LiteralWithUrlDatatype(literal, datatype) => Ok(Node::LiteralNode {
+                literal,
+                data_type: Some(Uri::new(datatype)),
+                language: None,
+            }),
+            Token::Literal(literal) => Ok(Node::LiteralNode {
+                literal,
+                data_type: None,
+                language: None,
+            }),
+            _ => Err(Error::new(
+                ErrorType::InvalidToken,
+                "Invalid token for N-Quads object.",
+            )),
+        }
+    }
+
+    /// Checks if the next token is a graph label and creates the graph name node.
+    ///
+    /// Returns `None` if the statement belongs to the default graph. An empty IRI `<>` is
+    /// rejected since it does not identify a graph.
+    fn read_graph_label(&mut self) -> Result<Option<Node>> {
+        match self.lexer.peek_next_token()? {
+            Token::TripleDelimiter => Ok(None),
+            Token::BlankNode(id) => {
+                let _ = self.lexer.get_next_token()?;
+                Ok(Some(Node::BlankNode { id }))
+            }
+            Token::Uri(ref uri) if uri.is_empty() => Err(Error::new(
+                ErrorType::InvalidToken,
+                "Empty IRI is not allowed as N-Quads graph label.",
+            )),
+            Token::Uri(uri) => {
+                let _ = self.lexer.get_next_token()?;
+                Ok(Some(Node::UriNode { uri: Uri::new(uri) }))
+            }
+            Token::Literal(_)
+            | Token::LiteralWithLanguageSpecification(_, _)
+            | Token::LiteralWithUrlDatatype(_, _) => Err(Error::new(
+                ErrorType::InvalidToken,
+                "Literals are not allowed as N-Quads graph label.",
+            )),
+            _ => Err(Error::new(
+                ErrorType::InvalidToken,
+                "Invalid token for N-Quads graph label.",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ErrorType;
+    use crate::node::Node;
+    use crate::reader::n_quads_parser::NQuadsParser;
+    use crate::uri::Uri;
+
+    #[test]
+    fn test_read_n_quads_with_blank_node_graph_label() {
+        let input = "<http://example.org/s> <http://example.org/p> <http://example.org/o> _:g .
+                 _:g <http://example.org/p> \"o\" <http://example.org/g> .
+                 <http://example.org/s> <http://example.org/p> _:g .";
+
+        let mut reader = NQuadsParser::from_string(input.to_string());
+
+        match reader.decode() {
+            Ok(dataset) => {
+                let blank_graph = Node::BlankNode {
+                    id: "g".to_string(),
+                };
+                let uri_graph = Node::UriNode {
+                    uri: Uri::new("http://example.org/g".to_string()),
+                };
+
+                assert_eq!(dataset.count(), 3);
+                assert_eq!(dataset.default_graph().count(), 1);
+                assert_eq!(dataset.named_graph(&blank_graph).unwrap().count(), 1);
+                assert_eq!(dataset.named_graph(&uri_graph).unwrap().count(), 1);
+
+                // the blank node label is shared between graph label and subject or object
+                assert_eq!(
+                    dataset
+                        .named_graph(&uri_graph)
+                        .unwrap()
+                        .get_triples_with_subject(&blank_graph)
+                        .len(),
+                    1
+                );
+            }
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_reject_literal_graph_label() {
+        let input = "<http://example.org/s> <http://example.org/p> <http://example.org/o> \"g\" .";
+
+        let mut reader = NQuadsParser::from_string(input.to_string());
+
+        match reader.decode() {
+            Ok(_) => panic!("Literal graph label must be rejected"),
+            Err(e) => assert!(matches!(e.error_type(), ErrorType::InvalidToken)),
+        }
+    }
+
+    #[test]
+    fn test_reject_empty_iri_graph_label() {
+        let input = "<http://example.org/s> <http://example.org/p> <http://example.org/o> <> .";
+
+        let mut reader = NQuadsParser::from_string(input.to_string());
+
+        match reader.decode() {
+            Ok(_) => panic!("Empty IRI graph label must be rejected"),
+            Err(e) => {
+                assert!(matches!(e.error_type(), ErrorType::InvalidToken));
+                assert!(e.to_string().contains("Empty IRI"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_empty_iri_as_subject_and_object() {
+        let input = "<> <http://example.org/p> <> <http://example.org/g> .";
+
+        let mut reader = NQuadsParser::from_string(input.to_string());
+
+        match reader.decode() {
+            Ok(dataset) => {
+                let graph_name = Node::UriNode {
+                    uri: Uri::new("http://example.org/g".to_string()),
+                };
+                let empty = Node::UriNode {
+                    uri: Uri::new("".to_string()),
+                };
+                let graph = dataset.named_graph(&graph_name).unwrap();
+
+                assert_eq!(
+                    graph
+                        .get_triples_with_subject_and_object(&empty, &empty)
+                        .len(),
+                    1
+                );
+            }
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_reject_empty_statement() {
+        let input = "<http://example.org/s> <http://example.org/p> <http://example.org/o> . .";
+
+        let mut reader = NQuadsParser::from_string(input.to_string());
+
+        match reader.decode() {
+            Ok(_) => panic!("Empty statement must be rejected"),
+            Err(e) => assert!(matches!(e.error_type(), ErrorType::UnexpectedToken)),
+        }
+    }
+}
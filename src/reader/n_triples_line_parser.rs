@@ -0,0 +1,165 @@
+use crate::error::{Error, ErrorType};
+use crate::reader::n_triples_parser::NTriplesParser;
+use crate::triple::Triple;
+use crate::Result;
+use std::io::{BufRead, ErrorKind};
+
+/// RDF parser that reads N-Triples syntax line by line.
+///
+/// Each line is parsed on its own, hence a malformed line only results in an error for
+/// this line and parsing can continue with the next one.
+pub struct NTriplesLineParser<B: BufRead> {
+    input: B,
+    line_number: usize,
+}
+
+impl<B: BufRead> NTriplesLineParser<B> {
+    /// Constructor of `NTriplesLineParser` from a buffered input reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_triples_line_parser::NTriplesLineParser;
+    ///
+    /// let input = "_:art <http://xmlns.com/foaf/0.1/name> \"Art Barstow\" .";
+    ///
+    /// let reader = NTriplesLineParser::new(input.as_bytes());
+    /// ```
+    pub fn new(input: B) -> NTriplesLineParser<B> {
+        NTriplesLineParser {
+            input,
+            line_number: 0,
+        }
+    }
+
+    /// Returns the number of the line that was read last, starting at 1.
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+
+    /// Parses the next line containing a triple, blank lines and comment lines are skipped.
+    ///
+    /// Returns `None` once the end of the input is reached. After an error the next call
+    /// continues with the line following the malformed one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_triples_line_parser::NTriplesLineParser;
+    ///
+    /// let input = "<http://example.org/s> \"p\" \"o\" .
+    ///              _:art <http://xmlns.com/foaf/0.1/name> \"Art Barstow\" .";
+    ///
+    /// let mut reader = NTriplesLineParser::new(input.as_bytes());
+    ///
+    /// assert!(reader.next_triple().is_err());
+    /// assert!(reader.next_triple().unwrap().is_some());
+    /// assert!(reader.next_triple().unwrap().is_none());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid byte encoding of the input.
+    /// - A line that does not contain exactly one triple conforming with the NTriples standard.
+    ///
+    pub fn next_triple(&mut self) -> Result<Option<Triple>> {
+        let mut line = String::new();
+
+        loop {
+            line.clear();
+
+            match self.input.read_line(&mut line) {
+                Ok(0) => return Ok(None),
+                Ok(_) => self.line_number += 1,
+                Err(ref e) if e.kind() == ErrorKind::InvalidData => {
+                    self.line_number += 1;
+
+                    return Err(Error::new(
+                        ErrorType::InvalidByteEncoding,
+                        format!("Invalid byte encoding in line {}.", self.line_number),
+                    ));
+                }
+                Err(_) => {
+                    return Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        "Unexpected error while reading input.",
+                    ))
+                }
+            }
+
+            let statement = line.trim();
+
+            if statement.is_empty() || statement.starts_with('#') {
+                continue;
+            }
+
+            return self.parse_line(statement).map(Some);
+        }
+    }
+
+    /// Parses a line that must contain exactly one triple.
+    fn parse_line(&self, line: &str) -> Result<Triple> {
+        let mut parser = NTriplesParser::from_string(line);
+
+        match (parser.next_triple(), parser.next_triple()) {
+            (Ok(Some(triple)), Ok(None)) => Ok(triple),
+            _ => Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                format!("Invalid NTriples syntax in line {}.", self.line_number),
+            )),
+        }
+    }
+}
+
+impl<B: BufRead> Iterator for NTriplesLineParser<B> {
+    type Item = Result<Triple>;
+
+    /// Returns the triple of the next line that is not blank or a comment.
+    fn next(&mut self) -> Option<Result<Triple>> {
+        self.next_triple().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ErrorType;
+    use crate::reader::n_triples_line_parser::NTriplesLineParser;
+
+    #[test]
+    fn test_skip_blank_and_comment_lines() {
+        let input = "<http://example.org/s> <http://example.org/p> <http://example.org/o> .
+
+                 # comment only
+                 <http://example.org/s> <http://example.org/p> \"o\" . # trailing comment
+                 \r
+                 _:b <http://example.org/p> \"o\"@en .
+";
+
+        let mut reader = NTriplesLineParser::new(input.as_bytes());
+
+        assert!(reader.next_triple().unwrap().is_some());
+        assert!(reader.next_triple().unwrap().is_some());
+        assert_eq!(reader.line_number(), 4);
+        assert!(reader.next_triple().unwrap().is_some());
+        assert!(reader.next_triple().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_continue_after_malformed_line() {
+        let input = "<http://example.org/s> <http://example.org/p> <http://example.org/o> .
+                 <http://example.org/s> <http://example.org/p> .
+                 <http://example.org/s> <http://example.org/p> \"a\" . <http://example.org/s> <http://example.org/p> \"b\" .
+                 <http://example.org/s> <http://example.org/p> \"o\" .";
+
+        let results: Vec<_> = NTriplesLineParser::new(input.as_bytes()).collect();
+
+        assert_eq!(results.len(), 4);
+        assert!(results[0].is_ok());
+        assert!(matches!(
+            results[1].as_ref().unwrap_err().error_type(),
+            ErrorType::InvalidReaderInput
+        ));
+        assert!(results[2].is_err());
+        assert!(results[3].is_ok());
+    }
+}
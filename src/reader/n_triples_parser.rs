@@ -2,8 +2,12 @@ use crate::error::{Error, ErrorType};
 use crate::graph::Graph;
 use crate::node::Node;
 use crate::reader::lexer::n_triples_lexer::NTriplesLexer;
-use crate::reader::lexer::rdf_lexer::RdfLexer;
+use crate::reader::input_reader::ChainReader;
+use crate::reader::lexer::rdf_lexer::{LexerOptions, RdfLexer};
 use crate::reader::lexer::token::Token;
+use crate::reader::parse_stats::ParseStats;
+use crate::reader::parser_builder::ConfigurableParser;
+use crate::reader::parser_options::{BlankNodeLabels, ParserOptions};
 use crate::reader::rdf_parser::RdfParser;
 use std::io::Cursor;
 use std::io::Read;
@@ -14,6 +18,15 @@ use crate::Result;
 /// RDF parser to generate an RDF graph from N-Triples syntax.
 pub struct NTriplesParser<R: Read> {
     lexer: NTriplesLexer<R>,
+    options: ParserOptions,
+    blank_node_labels: BlankNodeLabels,
+    stats: ParseStats,
+
+    /// Line at which the statement that was parsed last starts.
+    statement_line: usize,
+
+    /// Column at which the statement that was parsed last starts.
+    statement_column: usize,
 }
 
 impl<R: Read> RdfParser for NTriplesParser<R> {
@@ -46,30 +59,30 @@ impl<R: Read> RdfParser for NTriplesParser<R> {
     fn decode(&mut self) -> Result<Graph> {
         let mut graph = Graph::new(None);
 
-        loop {
-            match self.lexer.peek_next_token()? {
-                Token::Comment(_) => {
-                    // ignore comments
-                    let _ = self.lexer.get_next_token();
-                    continue;
-                }
-                Token::EndOfInput => return Ok(graph),
-                _ => {}
+        while let Some(triple) = self.next_triple()? {
+            if !graph.add_triple(&triple) {
+                self.stats.add_duplicate_triple();
             }
+        }
 
-            match self.read_triple() {
-                Ok(triple) => graph.add_triple(&triple),
-                Err(err) => match *err.error_type() {
-                    ErrorType::EndOfInput(_) => return Ok(graph),
-                    _ => {
-                        println!("Error: {}", err.to_string());
-                        return Err(Error::new(
-                            ErrorType::InvalidReaderInput,
-                            "Error while parsing NTriples syntax.",
-                        ));
-                    }
-                },
-            }
+        Ok(graph)
+    }
+}
+
+impl<R: Read> ConfigurableParser<R> for NTriplesParser<R> {
+    /// Constructor of `NTriplesParser` from input reader using the provided lexer and parser options.
+    fn with_configuration(
+        input: R,
+        lexer_options: LexerOptions,
+        parser_options: ParserOptions,
+    ) -> NTriplesParser<R> {
+        NTriplesParser {
+            lexer: NTriplesLexer::with_options(input, lexer_options),
+            blank_node_labels: BlankNodeLabels::new(&parser_options),
+            options: parser_options,
+            stats: ParseStats::new(),
+            statement_line: 1,
+            statement_column: 1,
         }
     }
 }
@@ -96,6 +109,33 @@ impl NTriplesParser<Cursor<Vec<u8>>> {
     }
 }
 
+impl<R: Read> NTriplesParser<ChainReader<R>> {
+    /// Constructor of `NTriplesParser` that parses several readers as one document.
+    ///
+    /// The inputs are concatenated, hence blank node labels are shared across
+    /// all readers instead of being kept apart like when merging graphs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_triples_parser::NTriplesParser;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    ///
+    /// let first = "_:art <http://xmlns.com/foaf/0.1/name> \"Art Barstow\" .";
+    /// let second = "<http://www.w3.org/2001/sw/RDFCore/ntriples/> <http://xmlns.com/foaf/0.1/maker> _:art .";
+    ///
+    /// let mut reader = NTriplesParser::from_readers(vec![first.as_bytes(), second.as_bytes()]);
+    ///
+    /// assert_eq!(reader.decode().unwrap().count(), 2);
+    /// ```
+    pub fn from_readers<I>(inputs: I) -> NTriplesParser<ChainReader<R>>
+    where
+        I: IntoIterator<Item = R>,
+    {
+        NTriplesParser::from_reader(ChainReader::new(inputs))
+    }
+}
+
 impl<R: Read> NTriplesParser<R> {
     /// Constructor of `NTriplesParser` from input reader.
     ///
@@ -111,16 +151,307 @@ impl<R: Read> NTriplesParser<R> {
     /// let reader = NTriplesParser::from_reader(input.as_bytes());
     /// ```
     pub fn from_reader(input: R) -> NTriplesParser<R> {
-        NTriplesParser {
-            lexer: NTriplesLexer::new(input),
+        NTriplesParser::with_options(input, ParserOptions::default())
+    }
+
+    /// Constructor of `NTriplesParser` from input reader using the provided options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_triples_parser::NTriplesParser;
+    /// use rdf::reader::parser_options::ParserOptions;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    ///
+    /// let input = "<http://example.org/s> <http://example.org/p> \"01\"^^<http://www.w3.org/2001/XMLSchema#integer> .";
+    ///
+    /// let options = ParserOptions {
+    ///     canonicalize_numeric_literals: true,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut reader = NTriplesParser::with_options(input.as_bytes(), options);
+    /// let graph = reader.decode().unwrap();
+    ///
+    /// let one = graph.create_literal_node_with_data_type("1".to_string(), &XmlDataTypes::Integer.to_uri());
+    ///
+    /// assert_eq!(graph.triples_iter().next().unwrap().object(), &one);
+    /// ```
+    pub fn with_options(input: R, options: ParserOptions) -> NTriplesParser<R> {
+        NTriplesParser::with_configuration(input, LexerOptions::default(), options)
+    }
+
+    /// Returns the statistics collected while parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_triples_parser::NTriplesParser;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    ///
+    /// let input = "_:art <http://xmlns.com/foaf/0.1/name> \"Art Barstow\" .
+    ///              _:art <http://xmlns.com/foaf/0.1/name> \"Art Barstow\" .";
+    ///
+    /// let mut reader = NTriplesParser::from_string(input.to_string());
+    /// reader.decode().unwrap();
+    ///
+    /// assert_eq!(reader.stats().duplicate_triples(), 1);
+    /// ```
+    pub fn stats(&self) -> &ParseStats {
+        &self.stats
+    }
+
+    /// Returns the number of bytes of the input that are consumed, e.g. to continue reading a
+    /// seekable input after the blank line that ended parsing.
+    ///
+    /// See `LexerOptions::stop_at_blank_line`.
+    pub fn byte_offset(&self) -> usize {
+        self.lexer.byte_offset()
+    }
+
+    /// Parses the next triple of the input.
+    ///
+    /// Returns `None` once the end of the input is reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_triples_parser::NTriplesParser;
+    ///
+    /// let input = "_:art <http://xmlns.com/foaf/0.1/name> \"Art Barstow\" .";
+    ///
+    /// let mut reader = NTriplesParser::from_string(input.to_string());
+    ///
+    /// assert!(reader.next_triple().unwrap().is_some());
+    /// assert!(reader.next_triple().unwrap().is_none());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with NTriples standard.
+    /// - Invalid node type for triple segment.
+    ///
+    pub fn next_triple(&mut self) -> Result<Option<Triple>> {
+        self.read_next_triple(false)
+    }
+
+    /// Parses all triples of the input together with the line at which their statement starts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_triples_parser::NTriplesParser;
+    ///
+    /// let input = "# comment
+    ///              _:art <http://xmlns.com/foaf/0.1/name> \"Art Barstow\" .";
+    ///
+    /// let mut reader = NTriplesParser::from_string(input.to_string());
+    /// let triples = reader.parse_with_lines().unwrap();
+    ///
+    /// assert_eq!(triples[0].1, 2);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with NTriples standard.
+    /// - Invalid node type for triple segment.
+    ///
+    pub fn parse_with_lines(&mut self) -> Result<Vec<(Triple, usize)>> {
+        let mut triples = Vec::new();
+
+        while let Some(triple) = self.next_triple()? {
+            triples.push((triple, self.statement_line));
+        }
+
+        Ok(triples)
+    }
+
+    /// Returns an iterator over the results of parsing each statement of the input.
+    ///
+    /// Unlike `next_triple`, parsing continues after an invalid statement: the input is
+    /// skipped until after the next triple delimiter and each invalid statement results
+    /// in exactly one error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_triples_parser::NTriplesParser;
+    ///
+    /// let input = "<http://example.org/s> \"p\" \"o\" .
+    ///              _:art <http://xmlns.com/foaf/0.1/name> \"Art Barstow\" .";
+    ///
+    /// let mut reader = NTriplesParser::from_string(input.to_string());
+    /// let results: Vec<bool> = reader.parse_results().map(|result| result.is_ok()).collect();
+    ///
+    /// assert_eq!(results, vec![false, true]);
+    /// ```
+    pub fn parse_results(&mut self) -> impl Iterator<Item = Result<Triple>> + '_ {
+        let mut done = false;
+
+        std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+
+            let result = self.read_next_triple(true).transpose();
+            done = result.is_none();
+            result
+        })
+    }
+
+    /// Parses the next triple of the input and skips the invalid statement on error
+    /// if `resynchronize` is set.
+    fn read_next_triple(&mut self, resynchronize: bool) -> Result<Option<Triple>> {
+        loop {
+            let token = match self.lexer.peek_next_token() {
+                Ok(token) => token,
+                Err(err) => {
+                    if resynchronize {
+                        self.lexer.skip_statement();
+                    }
+
+                    return Err(err);
+                }
+            };
+
+            match token {
+                Token::Comment(_) => {
+                    // ignore comments
+                    let _ = self.lexer.get_next_token();
+                    continue;
+                }
+                Token::EndOfInput => return Ok(None),
+                Token::TripleDelimiter => {
+                    let _ = self.lexer.get_next_token(); // consume the stray '.'
+
+                    return Err(Error::new(
+                        ErrorType::UnexpectedToken,
+                        "Empty statement: expected NTriples subject instead of '.'",
+                    ));
+                }
+                _ => {}
+            }
+
+            self.statement_line = self.lexer.token_line();
+            self.statement_column = self.lexer.token_column();
+
+            return match self.read_triple() {
+                Ok(None) => continue,
+                Ok(Some(triple)) => {
+                    self.stats.add_triple();
+                    self.options.check_triple_count(self.stats.triples())?;
+                    self.options.check_schemes(&triple)?;
+                    self.options.check_blank_nodes(
+                        &triple,
+                        self.statement_line,
+                        self.statement_column,
+                    )?;
+
+                    if let Node::LiteralNode { literal, .. } = triple.object() {
+                        self.stats.add_literal(literal);
+                    }
+
+                    Ok(Some(triple))
+                }
+                Err(err) => match *err.error_type() {
+                    ErrorType::EndOfInput(_) => Ok(None),
+                    _ => {
+                        if resynchronize {
+                            self.lexer.skip_statement();
+                        }
+
+                        Err(Error::new(
+                            ErrorType::InvalidReaderInput,
+                            format!("Error while parsing NTriples syntax: {}", err),
+                        ))
+                    }
+                },
+            };
         }
     }
 
+    /// Returns an iterator over batches of up to `size` parsed triples.
+    ///
+    /// Only the last batch may contain less than `size` triples. Triples are parsed
+    /// lazily while iterating, hence the input is never loaded into a graph as a whole.
+    /// An error is returned as the last batch, after the triples parsed before it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_triples_parser::NTriplesParser;
+    ///
+    /// let input = "<http://example.org/s> <http://example.org/p> \"1\" .
+    ///              <http://example.org/s> <http://example.org/p> \"2\" .
+    ///              <http://example.org/s> <http://example.org/p> \"3\" .";
+    ///
+    /// let mut reader = NTriplesParser::from_string(input.to_string());
+    /// let sizes: Vec<usize> = reader.batches(2).map(|batch| batch.unwrap().len()).collect();
+    ///
+    /// assert_eq!(sizes, vec![2, 1]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - `size` is 0.
+    ///
+    pub fn batches(&mut self, size: usize) -> impl Iterator<Item = Result<Vec<Triple>>> + '_ {
+        assert!(size > 0, "batch size must be non-zero");
+
+        let mut pending_error = None;
+        let mut done = false;
+
+        std::iter::from_fn(move || {
+            if let Some(err) = pending_error.take() {
+                return Some(Err(err));
+            }
+
+            if done {
+                return None;
+            }
+
+            let mut batch = Vec::with_capacity(size);
+
+            while batch.len() < size {
+                match self.next_triple() {
+                    Ok(Some(triple)) => batch.push(triple),
+                    Ok(None) => {
+                        done = true;
+                        break;
+                    }
+                    Err(err) => {
+                        done = true;
+
+                        if batch.is_empty() {
+                            return Some(Err(err));
+                        }
+
+                        pending_error = Some(err);
+                        break;
+                    }
+                }
+            }
+
+            if batch.is_empty() {
+                None
+            } else {
+                Some(Ok(batch))
+            }
+        })
+    }
+
     /// Creates a triple from the parsed tokens.
-    fn read_triple(&mut self) -> Result<Triple> {
+    fn read_triple(&mut self) -> Result<Option<Triple>> {
         let subject = self.read_subject()?;
         let predicate = self.read_predicate()?;
-        let object = self.read_object()?;
+
+        let object = if self.options.allows_predicate(&predicate) {
+            Some(self.read_object()?)
+        } else {
+            self.skip_object()?;
+            None
+        };
 
         match self.lexer.get_next_token() {
             Ok(Token::TripleDelimiter) => {}
@@ -132,13 +463,25 @@ impl<R: Read> NTriplesParser<R> {
             }
         }
 
-        Ok(Triple::new(&subject, &predicate, &object))
+        Ok(object.map(|object| Triple::new(&subject, &predicate, &object)))
+    }
+
+    /// Get the next token of a triple segment.
+    ///
+    /// A triple delimiter is not consumed, so that it terminates the invalid statement.
+    fn next_segment_token(&mut self) -> Result<Token> {
+        match self.lexer.peek_next_token()? {
+            Token::TripleDelimiter => Ok(Token::TripleDelimiter),
+            _ => self.lexer.get_next_token(),
+        }
     }
 
     /// Get the next token and check if it is a valid subject and create a new subject node.
     fn read_subject(&mut self) -> Result<Node> {
-        match self.lexer.get_next_token() {
-            Ok(Token::BlankNode(id)) => Ok(Node::BlankNode { id }),
+        match self.next_segment_token() {
+            Ok(Token::BlankNode(id)) => Ok(Node::BlankNode {
+                id: self.blank_node_labels.label(id),
+            }),
             Ok(Token::Uri(uri)) => Ok(Node::UriNode { uri: Uri::new(uri) }),
             _ => Err(Error::new(
                 ErrorType::InvalidToken,
@@ -149,7 +492,7 @@ impl<R: Read> NTriplesParser<R> {
 
     /// Get the next token and check if it is a valid predicate and create a new predicate node.
     fn read_predicate(&mut self) -> Result<Node> {
-        match self.lexer.get_next_token() {
+        match self.next_segment_token() {
             Ok(Token::Uri(uri)) => Ok(Node::UriNode { uri: Uri::new(uri) }),
             _ => Err(Error::new(
                 ErrorType::InvalidToken,
@@ -160,8 +503,10 @@ impl<R: Read> NTriplesParser<R> {
 
     /// Get the next token and check if it is a valid object and create a new object node.
     fn read_object(&mut self) -> Result<Node> {
-        match self.lexer.get_next_token()? {
-            Token::BlankNode(id) => Ok(Node::BlankNode { id }),
+        match self.next_segment_token()? {
+            Token::BlankNode(id) => Ok(Node::BlankNode {
+                id: self.blank_node_labels.label(id),
+            }),
             Token::Uri(uri) => Ok(Node::UriNode { uri: Uri::new(uri) }),
             Token::LiteralWithLanguageSpecification(literal, lang) => Ok(Node::LiteralNode {
                 literal,
@@ -169,7 +514,7 @@ impl<R: Read> NTriplesParser<R> {
                 language: Some(lang),
             }),
             Token::LiteralWithUrlDatatype(literal, datatype) => Ok(Node::LiteralNode {
-                literal,
+                literal: self.options.literal_form(literal, &datatype),
                 data_type: Some(Uri::new(datatype)),
                 language: None,
             }),
@@ -184,12 +529,44 @@ impl<R: Read> NTriplesParser<R> {
             )),
         }
     }
+
+    /// Get the next token and check if it is a valid object without creating a node.
+    ///
+    /// Blank node labels are still assigned, so that renumbered labels do not depend on
+    /// the skipped statements.
+    fn skip_object(&mut self) -> Result<()> {
+        match self.next_segment_token()? {
+            Token::BlankNode(id) => {
+                let _ = self.blank_node_labels.label(id);
+                Ok(())
+            }
+            Token::Uri(_)
+            | Token::LiteralWithLanguageSpecification(_, _)
+            | Token::LiteralWithUrlDatatype(_, _)
+            | Token::Literal(_) => Ok(()),
+            _ => Err(Error::new(
+                ErrorType::InvalidToken,
+                "Invalid token for NTriples object.",
+            )),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::error::ErrorType;
+    use crate::graph::Graph;
+    use crate::node::Node;
     use crate::reader::n_triples_parser::NTriplesParser;
+    use crate::reader::parser_builder::ParserBuilder;
+    use crate::reader::parser_options::ParserOptions;
     use crate::reader::rdf_parser::RdfParser;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+    use crate::writer::n_triples_writer::NTriplesWriter;
+    use crate::writer::rdf_writer::RdfWriter;
+    use std::collections::HashSet;
+    use crate::Result;
 
     #[test]
     fn test_read_n_triples_from_string() {
@@ -202,9 +579,553 @@ mod tests {
 
         match reader.decode() {
             Ok(graph) => assert_eq!(graph.count(), 4),
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_next_triple_yields_exact_triples() {
+        let input = "<http://example.org/s> <http://example.org/p> <http://example.org/o> .
+                 _:b <http://example.org/p> \"a\"@en .
+                 <http://example.org/s> <http://example.org/q> \"1\"^^<http://example.org/int> .
+                 _:b <http://example.org/q> _:c .";
+
+        let uri = |uri: &str| Node::UriNode { uri: Uri::new(uri.to_string()) };
+        let blank = |id: &str| Node::BlankNode { id: id.to_string() };
+        let literal = |literal: &str, data_type: Option<Uri>, language: Option<&str>| {
+            Node::LiteralNode {
+                literal: literal.to_string(),
+                data_type,
+                language: language.map(|l| l.to_string()),
+            }
+        };
+
+        let mut reader = NTriplesParser::from_string(input);
+        let mut triples = Vec::new();
+
+        while let Some(triple) = reader.next_triple().unwrap() {
+            triples.push(triple);
+        }
+
+        assert_eq!(
+            triples,
+            vec![
+                Triple::new(
+                    &uri("http://example.org/s"),
+                    &uri("http://example.org/p"),
+                    &uri("http://example.org/o")
+                ),
+                Triple::new(
+                    &blank("b"),
+                    &uri("http://example.org/p"),
+                    &literal("a", None, Some("en"))
+                ),
+                Triple::new(
+                    &uri("http://example.org/s"),
+                    &uri("http://example.org/q"),
+                    &literal("1", Some(Uri::new("http://example.org/int".to_string())), None)
+                ),
+                Triple::new(&blank("b"), &uri("http://example.org/q"), &blank("c")),
+            ]
+        );
+
+        for invalid in &[
+            "\"s\" <http://example.org/p> <http://example.org/o> .",
+            "<http://example.org/s> _:p <http://example.org/o> .",
+        ] {
+            match NTriplesParser::from_string(*invalid).next_triple() {
+                Ok(triple) => panic!("Invalid statement must be rejected: {:?}", triple),
+                Err(e) => assert!(matches!(e.error_type(), ErrorType::InvalidReaderInput)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_triple_with_trailing_comment_from_string() {
+        let input = "<http://example.org/s> <http://example.org/p> <http://example.org/o> . # note
+                 <http://example.org/s> <http://example.org/p> \"o\" . # another note";
+
+        let mut reader = NTriplesParser::from_string(input.to_string());
+
+        match reader.decode() {
+            Ok(graph) => assert_eq!(graph.count(), 2),
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_read_from_multiple_readers() {
+        let first = "<http://example.org/s> <http://example.org/p> _:b .";
+        let second = "_:b <http://example.org/p> \"o\" .";
+
+        let mut reader = NTriplesParser::from_readers(vec![first.as_bytes(), second.as_bytes()]);
+
+        match reader.decode() {
+            Ok(graph) => {
+                let blank = Node::BlankNode {
+                    id: "b".to_string(),
+                };
+
+                assert_eq!(graph.count(), 2);
+                assert_eq!(graph.get_triples_with_subject(&blank).len(), 1);
+                assert_eq!(graph.get_triples_with_object(&blank).len(), 1);
+            }
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_count_duplicate_triples() {
+        let input = "<http://example.org/s> <http://example.org/p> \"o\" .
+                 <http://example.org/s> <http://example.org/p> <http://example.org/o> .
+                 <http://example.org/s> <http://example.org/p> \"o\" .";
+
+        let mut reader = NTriplesParser::from_string(input.to_string());
+
+        match reader.decode() {
+            Ok(_) => assert_eq!(reader.stats().duplicate_triples(), 1),
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_collect_literal_lengths() {
+        let input = "<http://example.org/s> <http://example.org/p> \"a\" .
+                 <http://example.org/s> <http://example.org/p> \"abcdef\"@en .
+                 <http://example.org/s> <http://example.org/p> <http://example.org/o> .
+                 <http://example.org/s> <http://example.org/p> \"abc\"^^<http://www.w3.org/2001/XMLSchema#string> .";
+
+        let mut reader = NTriplesParser::from_string(input.to_string());
+
+        match reader.decode() {
+            Ok(_) => {
+                assert_eq!(reader.stats().max_literal_length(), 6);
+                assert_eq!(reader.stats().literals(), 3);
+                assert_eq!(reader.stats().total_literal_length(), 10);
+            }
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_parse_results_after_invalid_statements() {
+        let input = "<http://example.org/s> <http://example.org/p> \"1\" .
+                 <http://example.org/s> \"p\" \"a.b\" .
+                 <http://example.org/s> <http://example.org/p> \"2\" .
+                 <http://example.org/s> <http://example.org/p> .
+                 <http://example.org/s> <http://example.org/p> \"3\" .
+                 x <http://example.org/p> <http://example.org/o.x> .
+                 <http://example.org/s> <http://example.org/p> \"4\" .";
+
+        let mut reader = NTriplesParser::from_string(input.to_string());
+        let results: Vec<Result<Triple>> = reader.parse_results().collect();
+
+        assert_eq!(results.len(), 7);
+
+        for (index, result) in results.iter().enumerate() {
+            match result {
+                Ok(triple) if index % 2 == 0 => assert_eq!(
+                    triple.object(),
+                    &Node::LiteralNode {
+                        literal: (index / 2 + 1).to_string(),
+                        data_type: None,
+                        language: None,
+                    }
+                ),
+                Err(_) if index % 2 == 1 => {}
+                _ => panic!("Unexpected result at {}: {:?}", index, result.is_ok()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_results_after_invalid_statement_with_dotted_blank_node() {
+        let input = "<http://example.org/s> <http://example.org/p> \"1\" .
+                 \"s\" _:a..b <http://example.org/o> .
+                 <http://example.org/s> <http://example.org/p> \"2\" .";
+
+        let mut reader = NTriplesParser::from_string(input.to_string());
+        let results: Vec<bool> = reader.parse_results().map(|r| r.is_ok()).collect();
+
+        assert_eq!(results, vec![true, false, true]);
+    }
+
+    #[test]
+    fn test_forbid_blank_nodes() {
+        let input = "<http://example.org/s> <http://example.org/p> \"o\" .
+                 _:b <http://example.org/p> \"o\" .";
+
+        let options = ParserOptions {
+            forbid_blank_nodes: true,
+            ..Default::default()
+        };
+
+        let mut reader = NTriplesParser::with_options(input.as_bytes(), options);
+
+        match reader.decode() {
+            Ok(_) => panic!("Blank node must be rejected"),
+            Err(e) => {
+                assert!(matches!(e.error_type(), ErrorType::InvalidReaderInput));
+                assert!(e
+                    .to_string()
+                    .contains("_:b is not allowed as subject of a triple at line 2, column 18."));
+            }
+        }
+    }
+
+    #[test]
+    fn test_reject_empty_statements() {
+        for input in &[
+            "<http://example.org/s> <http://example.org/p> <http://example.org/o> . .",
+            ". <http://example.org/s> <http://example.org/p> <http://example.org/o> .",
+        ] {
+            let mut reader = NTriplesParser::from_string(input.to_string());
+
+            match reader.decode() {
+                Ok(_) => panic!("Empty statement must be rejected: {}", input),
+                Err(e) => assert!(matches!(e.error_type(), ErrorType::UnexpectedToken)),
+            }
+        }
+
+        let input = ". <http://example.org/s> <http://example.org/p> <http://example.org/o> .";
+        let mut reader = NTriplesParser::from_string(input.to_string());
+        let results: Vec<bool> = reader.parse_results().map(|r| r.is_ok()).collect();
+
+        assert_eq!(results, vec![false, true]);
+    }
+
+    #[test]
+    fn test_parse_with_lines() {
+        let input = "<http://example.org/s> <http://example.org/p> \"1\" .\r\n\
+                     \n\
+                     # comment\n\
+                     <http://example.org/s>\n    <http://example.org/p> \"2\" . <http://example.org/s> <http://example.org/p> \"3\" .";
+
+        let mut reader = NTriplesParser::from_string(input.to_string());
+
+        match reader.parse_with_lines() {
+            Ok(triples) => {
+                let lines: Vec<usize> = triples.iter().map(|(_, line)| *line).collect();
+                assert_eq!(lines, vec![1, 4, 5]);
+            }
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_report_position_of_invalid_input() {
+        let input = "<http://example.org/s> <http://example.org/p> \"1\" .\n\
+                     <http://example.org/s> <http://example.org/p> \"2\" .\n\
+                     <http://example.org/s> <http://example.org/p> ? .";
+
+        let mut reader = NTriplesParser::from_string(input.to_string());
+
+        match reader.decode() {
+            Ok(_) => panic!("Invalid input must be rejected"),
+            Err(e) => {
+                assert!(matches!(e.error_type(), ErrorType::InvalidReaderInput));
+                assert_eq!(
+                    e.to_string(),
+                    "Error while parsing NTriples syntax: Invalid NTriples input: ? at line 3, column 47."
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_skip_leading_byte_order_mark() {
+        let input = "\u{feff}<http://example.org/s> <http://example.org/p> <http://example.org/o> .";
+
+        let mut reader = NTriplesParser::from_string(input.to_string());
+
+        match reader.decode() {
+            Ok(graph) => assert_eq!(graph.count(), 1),
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_reject_byte_order_mark_between_triples() {
+        let input = "<http://example.org/s> <http://example.org/p> <http://example.org/o> .
+                 \u{feff}<http://example.org/s> <http://example.org/p> <http://example.org/o> .";
+
+        let mut reader = NTriplesParser::from_string(input.to_string());
+
+        match reader.decode() {
+            Ok(_) => panic!("Byte order mark must be rejected"),
+            Err(e) => assert!(matches!(e.error_type(), ErrorType::InvalidReaderInput)),
+        }
+    }
+
+    #[test]
+    fn test_read_crlf_terminated_n_triples() {
+        let input = "<http://example.org/s> <http://example.org/p> <http://example.org/o> .\r\n\
+                     <http://example.org/s> <http://example.org/p> \"o\"@en .\r\n\
+                     # comment\r\n\
+                     _:b <http://example.org/p> \"o\" .\r\n";
+
+        let mut reader = NTriplesParser::from_string(input.to_string());
+
+        match reader.decode() {
+            Ok(graph) => assert_eq!(graph.count(), 3),
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_canonicalize_numeric_literals() {
+        let input = "<http://example.org/s> <http://example.org/p> \"01\"^^<http://www.w3.org/2001/XMLSchema#integer> .
+                 <http://example.org/s> <http://example.org/p> \"1\"^^<http://www.w3.org/2001/XMLSchema#integer> .
+                 <http://example.org/s> <http://example.org/p> \"01\"^^<http://www.w3.org/2001/XMLSchema#string> .";
+
+        let options = ParserOptions {
+            canonicalize_numeric_literals: true,
+            ..Default::default()
+        };
+
+        let mut reader = NTriplesParser::with_options(input.as_bytes(), options);
+
+        match reader.decode() {
+            Ok(graph) => {
+                let distinct: HashSet<&Triple> = graph.triples_iter().collect();
+
+                // the integers collapse while the string keeps its leading zero
+                assert_eq!(distinct.len(), 2);
+                assert_eq!(reader.stats().duplicate_triples(), 1);
+            }
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_normalize_whitespace_of_token_literals() {
+        let input = "<http://example.org/s> <http://example.org/p> \"  a \\t b  \"^^<http://www.w3.org/2001/XMLSchema#token> .
+                 <http://example.org/s> <http://example.org/p> \" a\\tb \"^^<http://www.w3.org/2001/XMLSchema#normalizedString> .
+                 <http://example.org/s> <http://example.org/p> \" a \"^^<http://www.w3.org/2001/XMLSchema#string> .";
+
+        let literals = |options: ParserOptions| -> Vec<String> {
+            let mut reader = NTriplesParser::with_options(input.as_bytes(), options);
+
+            match reader.decode() {
+                Ok(graph) => graph
+                    .triples_iter()
+                    .map(|triple| match triple.object() {
+                        Node::LiteralNode { literal, .. } => literal.to_string(),
+                        node => panic!("Unexpected node {:?}", node),
+                    })
+                    .collect(),
+                Err(e) => panic!("Err {}", e),
+            }
+        };
+
+        let options = ParserOptions {
+            normalize_whitespace: true,
+            ..Default::default()
+        };
+
+        assert_eq!(literals(options), vec!["a b", " a b ", " a "]);
+        assert_eq!(
+            literals(ParserOptions::default()),
+            vec!["  a \t b  ", " a\tb ", " a "]
+        );
+    }
+
+    #[test]
+    fn test_read_triples_in_batches() {
+        let input = "<http://example.org/s> <http://example.org/p> \"1\" .
+                 <http://example.org/s> <http://example.org/p> \"2\" .
+                 # comment
+                 <http://example.org/s> <http://example.org/p> \"3\" .
+                 <http://example.org/s> <http://example.org/p> \"4\" .
+                 <http://example.org/s> <http://example.org/p> \"5\" .";
+
+        let mut reader = NTriplesParser::from_string(input.to_string());
+
+        let sizes: Vec<usize> = reader
+            .batches(2)
+            .map(|batch| batch.unwrap().len())
+            .collect();
+
+        assert_eq!(sizes, vec![2, 2, 1]);
+    }
+
+    #[test]
+    fn test_read_batches_with_invalid_triple() {
+        let input = "<http://example.org/s> <http://example.org/p> \"1\" .
+                 <http://example.org/s> <http://example.org/p> \"2\" .
+                 <http://example.org/s> \"p\" \"3\" .";
+
+        let mut reader = NTriplesParser::from_string(input.to_string());
+        let batches: Vec<_> = reader.batches(3).collect();
+
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].as_ref().unwrap().len(), 2);
+        assert!(batches[1].is_err());
+    }
+
+    #[test]
+    fn test_renumber_blank_nodes() {
+        let input = "_:x <http://example.org/p> _:y .
+                 <http://example.org/s> <http://example.org/p> _:x .";
+
+        let options = ParserOptions {
+            renumber_blank_nodes: true,
+            ..Default::default()
+        };
+
+        let mut reader = NTriplesParser::with_options(input.as_bytes(), options);
+
+        match reader.decode() {
+            Ok(graph) => {
+                let x = Node::BlankNode {
+                    id: "b0".to_string(),
+                };
+
+                assert_eq!(graph.get_triples_with_subject(&x).len(), 1);
+                assert_eq!(graph.get_triples_with_object(&x).len(), 1);
+                assert_eq!(
+                    graph.get_triples_with_subject(&x)[0].object(),
+                    &Node::BlankNode {
+                        id: "b1".to_string()
+                    }
+                );
+            }
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_round_trip_backspace_and_form_feed() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_blank_node();
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let object = graph.create_literal_node("back\u{8}space form\u{c}feed".to_string());
+        graph.add_triple(&Triple::new(&subject, &predicate, &object));
+
+        let output = NTriplesWriter::new().write_to_string(&graph).unwrap();
+
+        assert!(output.contains("back\\bspace form\\ffeed"));
+
+        let mut reader = NTriplesParser::from_string(output);
+
+        match reader.decode() {
+            Ok(parsed) => assert_eq!(parsed.triples_iter().next().unwrap().object(), &object),
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_allowed_schemes() {
+        let options = ParserOptions {
+            allowed_schemes: Some(vec!["https".to_string()]),
+            ..Default::default()
+        };
+
+        let allowed = "<https://example.org/s> <https://example.org/p> _:o .";
+        let mut reader = NTriplesParser::with_options(allowed.as_bytes(), options.clone());
+
+        match reader.decode() {
+            Ok(graph) => assert_eq!(graph.count(), 1),
+            Err(e) => panic!("Err {}", e),
+        }
+
+        let rejected = "<https://example.org/s> <https://example.org/p> <file:///etc/passwd> .";
+        let mut reader = NTriplesParser::with_options(rejected.as_bytes(), options);
+
+        match reader.decode() {
+            Ok(_) => panic!("File URI must be rejected"),
+            Err(e) => {
+                assert!(matches!(e.error_type(), ErrorType::InvalidReaderInput));
+                assert!(e.to_string().contains("'file'"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_stop_at_blank_line() {
+        let header = "<http://example.org/doc> <http://example.org/version> \"2\" .\n\
+                      # end of header\n\
+                      <http://example.org/doc> <http://example.org/rows> \"1\" .\n";
+        let data = "<http://example.org/s> <http://example.org/p> <http://example.org/o> .\n";
+        let input = format!("{} \t\r\n{}", header, data);
+
+        let mut reader: NTriplesParser<_> = ParserBuilder::new()
+            .stop_at_blank_line(true)
+            .build(input.as_bytes());
+
+        assert_eq!(reader.decode().unwrap().count(), 2);
+        assert!(reader.next_triple().unwrap().is_none());
+        assert_eq!(reader.byte_offset(), header.len() + 4);
+        assert_eq!(&input[reader.byte_offset()..], data);
+
+        let mut reader = NTriplesParser::from_string(input);
+
+        assert_eq!(reader.decode().unwrap().count(), 3);
+    }
+
+    #[test]
+    fn test_predicate_allowlist() {
+        let input = "_:a <http://example.org/b> _:b .
+                     _:c <http://example.org/a> \"01\"^^<http://www.w3.org/2001/XMLSchema#integer> .
+                     _:a <http://example.org/b> \"skipped\"@en .
+                     _:d <http://example.org/a> _:b .";
+
+        let allowlist: HashSet<String> = vec!["http://example.org/a".to_string()]
+            .into_iter()
+            .collect();
+
+        let options = ParserOptions {
+            canonicalize_numeric_literals: true,
+            renumber_blank_nodes: true,
+            ..Default::default()
+        };
+        let filtered_options = ParserOptions {
+            predicate_allowlist: Some(allowlist.clone()),
+            ..options.clone()
+        };
+
+        let full = NTriplesParser::with_options(input.as_bytes(), options).decode().unwrap();
+        let filtered = NTriplesParser::with_options(input.as_bytes(), filtered_options)
+            .decode()
+            .unwrap();
+
+        let expected: Vec<&Triple> = full
+            .triples_iter()
+            .filter(|t| match t.predicate() {
+                Node::UriNode { uri } => allowlist.contains(uri.to_string()),
+                _ => false,
+            })
+            .collect();
+
+        assert_eq!(expected.len(), 2);
+        assert_eq!(filtered.triples_iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_max_triples() {
+        let input = "<http://example.org/s> <http://example.org/p> \"a\" .
+                     <http://example.org/s> <http://example.org/p> \"b\" .
+                     <http://example.org/s> <http://example.org/p> \"c\" .";
+
+        let limit = |max_triples| ParserOptions {
+            max_triples: Some(max_triples),
+            ..Default::default()
+        };
+
+        let mut reader = NTriplesParser::with_options(input.as_bytes(), limit(3));
+
+        match reader.decode() {
+            Ok(graph) => assert_eq!(graph.count(), 3),
+            Err(e) => panic!("Err {}", e),
+        }
+        assert_eq!(reader.stats().triples(), 3);
+
+        match NTriplesParser::with_options(input.as_bytes(), limit(2)).decode() {
+            Ok(_) => panic!("Input exceeding the maximum number of triples must be rejected"),
             Err(e) => {
-                println!("Err {}", e.to_string());
-                assert!(false)
+                assert!(matches!(e.error_type(), ErrorType::InvalidReaderInput));
+                assert!(e.to_string().contains("maximum of 2 triples"));
             }
         }
     }
@@ -0,0 +1,304 @@
+use crate::error::{Error, ErrorType};
+use crate::reader::n_triples_parser::NTriplesParser;
+use crate::writer::n_triples_writer::NTriplesWriter;
+use crate::Result;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::env;
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, BufWriter, Lines, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Number of triples that `sort_ntriples` sorts in memory at a time.
+pub const DEFAULT_RUN_SIZE: usize = 100_000;
+
+/// Number of sorts started by this process, used to name their temporary files.
+static STARTED_SORTS: AtomicUsize = AtomicUsize::new(0);
+
+/// Sorts N-Triples input using bounded memory.
+///
+/// Triples are sorted by their N-Triples serialization, hence by subject first, and
+/// repeated triples are written once. Up to `run_size` triples are sorted in memory at a
+/// time. Larger inputs are split into sorted runs that are stored in temporary files and
+/// merged afterwards, so the output is sorted as expected by `SortedDiff`.
+pub struct NTriplesSorter {
+    run_size: usize,
+    temp_dir: PathBuf,
+}
+
+/// Temporary files of the sorted runs, which are removed when dropped.
+struct SortRuns {
+    paths: Vec<PathBuf>,
+}
+
+impl Drop for SortRuns {
+    fn drop(&mut self) {
+        for path in &self.paths {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+impl NTriplesSorter {
+    /// Constructor of `NTriplesSorter` that sorts up to `run_size` triples in memory and
+    /// stores sorted runs in the temporary directory of the system.
+    pub fn new(run_size: usize) -> NTriplesSorter {
+        NTriplesSorter {
+            run_size: run_size.max(1),
+            temp_dir: env::temp_dir(),
+        }
+    }
+
+    /// Stores the sorted runs in the provided directory.
+    pub fn temp_dir<P: AsRef<Path>>(mut self, dir: P) -> NTriplesSorter {
+        self.temp_dir = dir.as_ref().to_path_buf();
+        self
+    }
+
+    /// Writes the triples of the input sorted as N-Triples to the output and returns the
+    /// number of written triples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_triples_sorter::NTriplesSorter;
+    ///
+    /// let input = "<http://example.org/b> <http://example.org/p> \"2\" .
+    ///              <http://example.org/a> <http://example.org/p> \"1\" .";
+    ///
+    /// let mut output: Vec<u8> = Vec::new();
+    ///
+    /// assert_eq!(NTriplesSorter::new(1).sort(input.as_bytes(), &mut output).unwrap(), 2);
+    /// assert_eq!(
+    ///     String::from_utf8(output).unwrap(),
+    ///     "<http://example.org/a> <http://example.org/p> \"1\" .\n\
+    ///      <http://example.org/b> <http://example.org/p> \"2\" .\n"
+    /// );
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with NTriples standard.
+    /// - Reading or writing the temporary files fails.
+    /// - Writing to the output fails.
+    ///
+    pub fn sort<R: Read, W: Write>(&self, input: R, output: W) -> Result<usize> {
+        let mut parser = NTriplesParser::from_reader(input);
+        let writer = NTriplesWriter::new();
+        let sort_id = STARTED_SORTS.fetch_add(1, Ordering::SeqCst);
+
+        let mut runs = SortRuns { paths: Vec::new() };
+        let mut lines = Vec::new();
+
+        while let Some(triple) = parser.next_triple()? {
+            lines.push(writer.triple_to_n_triples(&triple)?);
+
+            if lines.len() >= self.run_size {
+                let path = self.write_run(&mut lines, sort_id, runs.paths.len())?;
+                runs.paths.push(path);
+            }
+        }
+
+        let mut output = BufWriter::new(output);
+
+        let count = if runs.paths.is_empty() {
+            Self::sort_lines(&mut lines);
+
+            for line in &lines {
+                Self::write_line(&mut output, line)?;
+            }
+
+            lines.len()
+        } else {
+            if !lines.is_empty() {
+                let path = self.write_run(&mut lines, sort_id, runs.paths.len())?;
+                runs.paths.push(path);
+            }
+
+            Self::merge_runs(&runs, &mut output)?
+        };
+
+        output
+            .flush()
+            .map_err(|err| Error::new(ErrorType::InvalidWriterOutput, err.to_string()))?;
+
+        Ok(count)
+    }
+
+    /// Sorts the lines and removes repeated lines.
+    fn sort_lines(lines: &mut Vec<String>) {
+        lines.sort_unstable();
+        lines.dedup();
+    }
+
+    /// Sorts the lines, writes them to a new temporary file and removes them.
+    fn write_run(&self, lines: &mut Vec<String>, sort_id: usize, run: usize) -> Result<PathBuf> {
+        let file_name = format!("rdf-sort-{}-{}-{}.nt", process::id(), sort_id, run);
+        let path = self.temp_dir.join(file_name);
+
+        let file = File::create(&path)
+            .map_err(|err| Error::new(ErrorType::InvalidWriterOutput, err.to_string()))?;
+        let mut file = BufWriter::new(file);
+
+        Self::sort_lines(lines);
+
+        for line in lines.drain(..) {
+            Self::write_line(&mut file, &line)?;
+        }
+
+        file.flush()
+            .map_err(|err| Error::new(ErrorType::InvalidWriterOutput, err.to_string()))?;
+
+        Ok(path)
+    }
+
+    /// Merges the sorted runs into the output and returns the number of written lines.
+    fn merge_runs<W: Write>(runs: &SortRuns, output: &mut W) -> Result<usize> {
+        let mut readers = Vec::new();
+
+        for path in &runs.paths {
+            let file = File::open(path)
+                .map_err(|err| Error::new(ErrorType::InvalidReaderInput, err.to_string()))?;
+            readers.push(BufReader::new(file).lines());
+        }
+
+        // smallest next line of each run
+        let mut heap = BinaryHeap::new();
+
+        for (run, reader) in readers.iter_mut().enumerate() {
+            if let Some(line) = Self::next_line(reader)? {
+                heap.push(Reverse((line, run)));
+            }
+        }
+
+        let mut count = 0;
+        let mut last_line: Option<String> = None;
+
+        while let Some(Reverse((line, run))) = heap.pop() {
+            if let Some(next) = Self::next_line(&mut readers[run])? {
+                heap.push(Reverse((next, run)));
+            }
+
+            if last_line.as_ref() == Some(&line) {
+                continue;
+            }
+
+            Self::write_line(output, &line)?;
+            count += 1;
+            last_line = Some(line);
+        }
+
+        Ok(count)
+    }
+
+    /// Reads the next line of a sorted run.
+    fn next_line(reader: &mut Lines<BufReader<File>>) -> Result<Option<String>> {
+        reader
+            .next()
+            .transpose()
+            .map_err(|err| Error::new(ErrorType::InvalidReaderInput, err.to_string()))
+    }
+
+    /// Writes the line followed by a line break.
+    fn write_line<W: Write>(output: &mut W, line: &str) -> Result<()> {
+        output
+            .write_all(line.as_bytes())
+            .and_then(|_| output.write_all(b"\n"))
+            .map_err(|err| Error::new(ErrorType::InvalidWriterOutput, err.to_string()))
+    }
+}
+
+/// Writes the triples of the input sorted as N-Triples to the output and returns the number of
+/// written triples.
+///
+/// Sorts up to `DEFAULT_RUN_SIZE` triples in memory at a time, see `NTriplesSorter`.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::reader::n_triples_sorter::sort_ntriples;
+///
+/// let input = "<http://example.org/b> <http://example.org/p> \"2\" .
+///              <http://example.org/a> <http://example.org/p> \"1\" .";
+///
+/// let mut output: Vec<u8> = Vec::new();
+///
+/// assert_eq!(sort_ntriples(input.as_bytes(), &mut output).unwrap(), 2);
+/// assert!(String::from_utf8(output).unwrap().starts_with("<http://example.org/a>"));
+/// ```
+///
+/// # Failures
+///
+/// - Invalid input that does not conform with NTriples standard.
+/// - Reading or writing the temporary files fails.
+/// - Writing to the output fails.
+///
+pub fn sort_ntriples<R: Read, W: Write>(reader: R, writer: W) -> Result<usize> {
+    NTriplesSorter::new(DEFAULT_RUN_SIZE).sort(reader, writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::reader::n_triples_sorter::{sort_ntriples, NTriplesSorter};
+    use crate::reader::sorted_diff::SortedDiff;
+    use std::env;
+    use std::fs;
+
+    #[test]
+    fn test_sort_unsorted_input() {
+        let input = "<http://example.org/c> <http://example.org/p> \"3\" .
+                     _:b <http://example.org/p> <http://example.org/a> .
+                     <http://example.org/a> <http://example.org/q> \"1\" .
+                     <http://example.org/c> <http://example.org/p> \"3\" .
+                     <http://example.org/a> <http://example.org/p> \"2\"@en .
+                     <http://example.org/b> <http://example.org/p> \"4\" .";
+
+        let expected = "<http://example.org/a> <http://example.org/p> \"2\"@en .\n\
+                        <http://example.org/a> <http://example.org/q> \"1\" .\n\
+                        <http://example.org/b> <http://example.org/p> \"4\" .\n\
+                        <http://example.org/c> <http://example.org/p> \"3\" .\n\
+                        _:b <http://example.org/p> <http://example.org/a> .\n";
+
+        let temp_dir = env::temp_dir().join(format!("rdf-sort-test-{}", std::process::id()));
+        fs::create_dir_all(&temp_dir).unwrap();
+
+        // in memory, with runs of single triples and with runs of two triples
+        for run_size in [100, 1, 2] {
+            let mut output: Vec<u8> = Vec::new();
+            let sorter = NTriplesSorter::new(run_size).temp_dir(&temp_dir);
+
+            assert_eq!(sorter.sort(input.as_bytes(), &mut output).unwrap(), 5);
+            assert_eq!(String::from_utf8(output).unwrap(), expected);
+        }
+
+        assert_eq!(fs::read_dir(&temp_dir).unwrap().count(), 0);
+        fs::remove_dir(&temp_dir).unwrap();
+
+        let mut output: Vec<u8> = Vec::new();
+        NTriplesSorter::new(2)
+            .sort(input.as_bytes(), &mut output)
+            .unwrap();
+
+        assert_eq!(SortedDiff::new(&output[..], expected.as_bytes()).count(), 0);
+
+        let mut output: Vec<u8> = Vec::new();
+
+        assert_eq!(sort_ntriples(input.as_bytes(), &mut output).unwrap(), 5);
+        assert_eq!(String::from_utf8(output).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_sort_invalid_input() {
+        let input = "<http://example.org/b> <http://example.org/p> \"2\" .
+                     <http://example.org/a> \"p\" \"1\" .";
+
+        let mut output: Vec<u8> = Vec::new();
+
+        assert!(NTriplesSorter::new(1)
+            .sort(input.as_bytes(), &mut output)
+            .is_err());
+        assert!(output.is_empty());
+    }
+}
@@ -0,0 +1,98 @@
+/// Statistics collected while parsing RDF syntax.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ParseStats {
+    /// Number of parsed triples, including duplicate triples.
+    triples: usize,
+
+    /// Number of parsed triples that were already contained in the document.
+    duplicate_triples: usize,
+
+    /// Number of parsed literals.
+    literals: usize,
+
+    /// Sum of the lengths of all parsed literals in characters.
+    total_literal_length: usize,
+
+    /// Length of the longest parsed literal in characters.
+    max_literal_length: usize,
+}
+
+impl ParseStats {
+    /// Constructor of `ParseStats`.
+    pub fn new() -> ParseStats {
+        ParseStats::default()
+    }
+
+    /// Returns the number of parsed triples, including duplicate triples.
+    pub fn triples(&self) -> usize {
+        self.triples
+    }
+
+    /// Returns the number of parsed triples that appeared more than once.
+    ///
+    /// Duplicates are detected when the triples are added to a graph, hence they are
+    /// only counted while decoding and not while streaming triples one by one.
+    pub fn duplicate_triples(&self) -> usize {
+        self.duplicate_triples
+    }
+
+    /// Returns the number of parsed literals, including literals of duplicate triples.
+    pub fn literals(&self) -> usize {
+        self.literals
+    }
+
+    /// Returns the sum of the lengths of all parsed literals in characters.
+    pub fn total_literal_length(&self) -> usize {
+        self.total_literal_length
+    }
+
+    /// Returns the length of the longest parsed literal in characters.
+    pub fn max_literal_length(&self) -> usize {
+        self.max_literal_length
+    }
+
+    /// Returns the average length of the parsed literals in characters.
+    ///
+    /// Returns `None` if no literal was parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_triples_parser::NTriplesParser;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    ///
+    /// let input = "_:art <http://xmlns.com/foaf/0.1/name> \"Art\" .
+    ///              _:art <http://xmlns.com/foaf/0.1/nick> \"A\" .";
+    ///
+    /// let mut reader = NTriplesParser::from_string(input.to_string());
+    /// reader.decode().unwrap();
+    ///
+    /// assert_eq!(reader.stats().average_literal_length(), Some(2.0));
+    /// ```
+    pub fn average_literal_length(&self) -> Option<f64> {
+        if self.literals == 0 {
+            None
+        } else {
+            Some(self.total_literal_length as f64 / self.literals as f64)
+        }
+    }
+
+    /// Increments the number of parsed triples.
+    pub(crate) fn add_triple(&mut self) {
+        self.triples += 1;
+    }
+
+    /// Increments the number of duplicate triples.
+    pub(crate) fn add_duplicate_triple(&mut self) {
+        self.duplicate_triples += 1;
+    }
+
+    /// Records the length of a parsed literal.
+    pub(crate) fn add_literal(&mut self, literal: &str) {
+        let length = literal.chars().count();
+
+        self.literals += 1;
+        self.total_literal_length += length;
+        self.max_literal_length = self.max_literal_length.max(length);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::reader::lexer::rdf_lexer::LexerOptions;
+use crate::reader::parser_options::ParserOptions;
+use std::io::Read;
+
+/// Trait implemented by RDF parsers that can be configured by a `ParserBuilder`.
+pub trait ConfigurableParser<R: Read> {
+    /// Constructor of a parser from input reader using the provided options.
+    fn with_configuration(
+        input: R,
+        lexer_options: LexerOptions,
+        parser_options: ParserOptions,
+    ) -> Self;
+}
+
+/// Collects the options of lexer and parser and creates configured RDF parsers.
+///
+/// All options are disabled by default.
+#[derive(Debug, Clone, Default)]
+pub struct ParserBuilder {
+    lexer_options: LexerOptions,
+    parser_options: ParserOptions,
+}
+
+impl ParserBuilder {
+    /// Constructor of `ParserBuilder` using the default options.
+    pub fn new() -> ParserBuilder {
+        ParserBuilder::default()
+    }
+
+    /// Resolves unknown escape sequences in literals to the escaped character.
+    ///
+    /// See `LexerOptions::lenient_escapes`.
+    pub fn lenient_escapes(mut self, enabled: bool) -> ParserBuilder {
+        self.lexer_options.lenient_escapes = enabled;
+        self
+    }
+
+    /// Stops parsing at the first blank line.
+    ///
+    /// See `LexerOptions::stop_at_blank_line`.
+    pub fn stop_at_blank_line(mut self, enabled: bool) -> ParserBuilder {
+        self.lexer_options.stop_at_blank_line = enabled;
+        self
+    }
+
+    /// Accepts characters in URIs that are not allowed in IRI references.
+    ///
+    /// See `LexerOptions::lenient_uris`.
+    pub fn lenient_uris(mut self, enabled: bool) -> ParserBuilder {
+        self.lexer_options.lenient_uris = enabled;
+        self
+    }
+
+    /// Rewrites numeric literals to their canonical form.
+    ///
+    /// See `ParserOptions::canonicalize_numeric_literals`.
+    pub fn canonicalize_numeric_literals(mut self, enabled: bool) -> ParserBuilder {
+        self.parser_options.canonicalize_numeric_literals = enabled;
+        self
+    }
+
+    /// Normalizes the whitespace of `xsd:normalizedString` and `xsd:token` literals.
+    ///
+    /// See `ParserOptions::normalize_whitespace`.
+    pub fn normalize_whitespace(mut self, enabled: bool) -> ParserBuilder {
+        self.parser_options.normalize_whitespace = enabled;
+        self
+    }
+
+    /// Replaces blank node labels by sequential labels.
+    ///
+    /// See `ParserOptions::renumber_blank_nodes`.
+    pub fn renumber_blank_nodes(mut self, enabled: bool) -> ParserBuilder {
+        self.parser_options.renumber_blank_nodes = enabled;
+        self
+    }
+
+    /// Prefixes the labels of the blank nodes generated by the parser.
+    ///
+    /// See `ParserOptions::generated_blank_node_prefix`.
+    pub fn generated_blank_node_prefix<S: Into<String>>(mut self, prefix: S) -> ParserBuilder {
+        self.parser_options.generated_blank_node_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Restricts the schemes of absolute URIs to the provided ones.
+    ///
+    /// See `ParserOptions::allowed_schemes`.
+    pub fn allowed_schemes<S: Into<String>>(mut self, schemes: Vec<S>) -> ParserBuilder {
+        self.parser_options.allowed_schemes = Some(schemes.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Returns an error for any blank node.
+    ///
+    /// See `ParserOptions::forbid_blank_nodes`.
+    pub fn forbid_blank_nodes(mut self, enabled: bool) -> ParserBuilder {
+        self.parser_options.forbid_blank_nodes = enabled;
+        self
+    }
+
+    /// Keeps only triples whose predicate is one of the provided URIs.
+    ///
+    /// See `ParserOptions::predicate_allowlist`.
+    pub fn predicate_allowlist<S: Into<String>>(mut self, predicates: Vec<S>) -> ParserBuilder {
+        self.parser_options.predicate_allowlist =
+            Some(predicates.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Returns an error once the input contains more than the provided number of triples.
+    ///
+    /// See `ParserOptions::max_triples`.
+    pub fn max_triples(mut self, max_triples: usize) -> ParserBuilder {
+        self.parser_options.max_triples = Some(max_triples);
+        self
+    }
+
+    /// Returns the collected lexer options.
+    pub fn lexer_options(&self) -> &LexerOptions {
+        &self.lexer_options
+    }
+
+    /// Returns the collected parser options.
+    pub fn parser_options(&self) -> &ParserOptions {
+        &self.parser_options
+    }
+
+    /// Creates a parser for the provided input using the collected options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::parser_builder::ParserBuilder;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    ///
+    /// let input = "_:art <http://xmlns.com/foaf/0.1/name> \"Art\\/Barstow\" .";
+    ///
+    /// let mut reader: TurtleParser<_> = ParserBuilder::new()
+    ///     .lenient_escapes(true)
+    ///     .renumber_blank_nodes(true)
+    ///     .build(input.as_bytes());
+    ///
+    /// assert_eq!(reader.decode().unwrap().count(), 1);
+    /// ```
+    pub fn build<R: Read, P: ConfigurableParser<R>>(&self, input: R) -> P {
+        P::with_configuration(
+            input,
+            self.lexer_options.clone(),
+            self.parser_options.clone(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::Node;
+    use crate::reader::n_triples_parser::NTriplesParser;
+    use crate::reader::parser_builder::ParserBuilder;
+    use crate::reader::rdf_parser::RdfParser;
+    use crate::reader::turtle_parser::TurtleParser;
+
+    #[test]
+    fn test_default_options() {
+        let builder = ParserBuilder::new();
+
+        assert!(!builder.lexer_options().lenient_escapes);
+        assert!(!builder.lexer_options().stop_at_blank_line);
+        assert!(!builder.lexer_options().lenient_uris);
+        assert!(!builder.parser_options().canonicalize_numeric_literals);
+        assert!(!builder.parser_options().normalize_whitespace);
+        assert!(!builder.parser_options().renumber_blank_nodes);
+        assert!(builder
+            .parser_options()
+            .generated_blank_node_prefix
+            .is_none());
+        assert!(builder.parser_options().allowed_schemes.is_none());
+        assert!(!builder.parser_options().forbid_blank_nodes);
+        assert!(builder.parser_options().predicate_allowlist.is_none());
+        assert!(builder.parser_options().max_triples.is_none());
+    }
+
+    #[test]
+    fn test_build_configured_parsers() {
+        let input = "_:x <http://example.org/p> \"a\\/b\" .
+                 _:x <http://example.org/p> \"007\"^^<http://www.w3.org/2001/XMLSchema#integer> .";
+
+        let builder = ParserBuilder::new()
+            .lenient_escapes(true)
+            .canonicalize_numeric_literals(true);
+
+        let mut n_triples_reader: NTriplesParser<_> = builder.build(input.as_bytes());
+        let mut turtle_reader: TurtleParser<_> = builder.build(input.as_bytes());
+
+        for graph in [
+            n_triples_reader.decode().unwrap(),
+            turtle_reader.decode().unwrap(),
+        ] {
+            let objects: Vec<&Node> = graph.triples_iter().map(|t| t.object()).collect();
+
+            assert_eq!(objects[0], &graph.create_literal_node("a/b".to_string()));
+            assert!(matches!(objects[1], Node::LiteralNode { literal, .. } if literal == "7"));
+        }
+    }
+
+    #[test]
+    fn test_build_strict_parser() {
+        let input = "_:x <http://example.org/p> \"a\\/b\" .";
+
+        let mut reader: NTriplesParser<_> = ParserBuilder::new().build(input.as_bytes());
+
+        assert!(reader.decode().is_err());
+    }
+}
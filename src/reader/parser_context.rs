@@ -0,0 +1,239 @@
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::reader::turtle_parser::TurtleParser;
+use crate::triple::Triple;
+use crate::uri::Uri;
+use crate::Result;
+use std::collections::HashMap;
+
+/// Scanner state while searching for the end of a Turtle statement.
+#[derive(Clone, Copy, PartialEq)]
+enum ScanState {
+    Default,
+    Uri,
+    Comment,
+    Literal(u8),
+    LongLiteral(u8),
+}
+
+/// Parser context for Turtle input that arrives in arbitrary chunks of bytes.
+///
+/// Bytes are buffered until a statement is complete, hence chunks may end anywhere,
+/// even in the middle of a token. Namespaces, the base URI and the counter for
+/// generated blank nodes are kept across chunks, so a blank node label denotes the
+/// same node in all chunks.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::reader::parser_context::ParserContext;
+///
+/// let mut context = ParserContext::new();
+///
+/// assert!(context.feed_chunk(b"@prefix ex: <http://exa").unwrap().is_empty());
+/// assert!(context.feed_chunk(b"mple.org/> .\nex:a ex:b ").unwrap().is_empty());
+/// assert_eq!(context.feed_chunk(b"ex:c .\n").unwrap().len(), 1);
+/// assert!(context.finish().unwrap().is_empty());
+/// ```
+pub struct ParserContext {
+    graph: Graph,
+    buffer: Vec<u8>,
+}
+
+impl Default for ParserContext {
+    fn default() -> Self {
+        ParserContext::new()
+    }
+}
+
+impl ParserContext {
+    /// Constructor of `ParserContext`.
+    pub fn new() -> ParserContext {
+        ParserContext {
+            graph: Graph::new(None),
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Returns the namespaces defined so far.
+    pub fn namespaces(&self) -> &HashMap<String, Uri> {
+        self.graph.namespaces()
+    }
+
+    /// Returns the base URI defined so far.
+    pub fn base_uri(&self) -> &Option<Uri> {
+        self.graph.base_uri()
+    }
+
+    /// Adds the chunk to the input and returns the triples of all completed statements.
+    ///
+    /// # Failures
+    ///
+    /// - A completed statement is invalid Turtle syntax.
+    ///
+    pub fn feed_chunk(&mut self, chunk: &[u8]) -> Result<Vec<Triple>> {
+        self.buffer.extend_from_slice(chunk);
+
+        match self.complete_statements_end() {
+            Some(end) => {
+                let statements: Vec<u8> = self.buffer.drain(..end).collect();
+                self.parse(statements)
+            }
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Parses all remaining buffered input and returns the resulting triples.
+    ///
+    /// # Failures
+    ///
+    /// - The remaining input is incomplete or invalid Turtle syntax.
+    ///
+    pub fn finish(&mut self) -> Result<Vec<Triple>> {
+        let statements: Vec<u8> = self.buffer.drain(..).collect();
+        self.parse(statements)
+    }
+
+    /// Parses complete statements and returns the created triples.
+    fn parse(&mut self, statements: Vec<u8>) -> Result<Vec<Triple>> {
+        let input = String::from_utf8(statements).map_err(|_| {
+            Error::new(
+                ErrorType::InvalidByteEncoding,
+                "Turtle input is not valid UTF-8.",
+            )
+        })?;
+
+        TurtleParser::from_string(input).decode_into(&mut self.graph)?;
+
+        Ok(self.graph.take_triples())
+    }
+
+    /// Returns the position after the last complete statement in the buffer.
+    ///
+    /// A statement is complete if its terminating '.' is followed by whitespace
+    /// or a comment and is not part of an URI, literal or comment.
+    fn complete_statements_end(&self) -> Option<usize> {
+        let buffer = &self.buffer;
+        let mut state = ScanState::Default;
+        let mut end = None;
+        let mut i = 0;
+
+        while i < buffer.len() {
+            let c = buffer[i];
+
+            match state {
+                ScanState::Default => match c {
+                    b'<' => state = ScanState::Uri,
+                    b'#' => state = ScanState::Comment,
+                    b'"' | b'\'' => {
+                        if i + 2 >= buffer.len() {
+                            // not possible to decide if long literal yet
+                            break;
+                        }
+
+                        if buffer[i + 1] == c && buffer[i + 2] == c {
+                            state = ScanState::LongLiteral(c);
+                            i += 2;
+                        } else {
+                            state = ScanState::Literal(c);
+                        }
+                    }
+                    b'.' => match buffer.get(i + 1) {
+                        Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r') | Some(b'#') => {
+                            end = Some(i + 1)
+                        }
+                        Some(_) => {}
+                        None => break,
+                    },
+                    _ => {}
+                },
+                ScanState::Uri => {
+                    if c == b'>' {
+                        state = ScanState::Default;
+                    }
+                }
+                ScanState::Comment => {
+                    if c == b'\n' || c == b'\r' {
+                        state = ScanState::Default;
+                    }
+                }
+                ScanState::Literal(quote) => {
+                    if c == b'\\' {
+                        i += 1; // skip escaped character
+                    } else if c == quote {
+                        state = ScanState::Default;
+                    }
+                }
+                ScanState::LongLiteral(quote) => {
+                    if c == b'\\' {
+                        i += 1; // skip escaped character
+                    } else if c == quote
+                        && buffer.get(i + 1) == Some(&quote)
+                        && buffer.get(i + 2) == Some(&quote)
+                    {
+                        state = ScanState::Default;
+                        i += 2;
+                    }
+                }
+            }
+
+            i += 1;
+        }
+
+        end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::Node;
+    use crate::reader::parser_context::ParserContext;
+    use crate::uri::Uri;
+
+    #[test]
+    fn test_feed_chunks_split_mid_token() {
+        let input = "@prefix ex: <http://example.org/> .
+                 ex:s ex:p \"a literal. with dot\" ;
+                     ex:q <http://example.org/o.x> .
+                 _:b ex:p \"\"\"multi
+                 line . \"\"\" . # comment . with dot
+                 ex:s ex:p _:b .
+                 ";
+
+        // split into chunks of three bytes to cut every token
+        let mut context = ParserContext::new();
+        let mut triples = Vec::new();
+
+        for chunk in input.as_bytes().chunks(3) {
+            triples.append(&mut context.feed_chunk(chunk).unwrap());
+        }
+
+        triples.append(&mut context.finish().unwrap());
+
+        assert_eq!(triples.len(), 4);
+        assert_eq!(
+            triples[1].object(),
+            &Node::UriNode {
+                uri: Uri::new("http://example.org/o.x".to_string())
+            }
+        );
+        assert_eq!(triples[2].subject(), triples[3].object());
+        assert_eq!(context.namespaces().len(), 1);
+    }
+
+    #[test]
+    fn test_feed_chunks_returns_completed_triples() {
+        let mut context = ParserContext::new();
+
+        assert!(context
+            .feed_chunk(b"<http://example.org/s> <http://exam")
+            .unwrap()
+            .is_empty());
+        assert!(context
+            .feed_chunk(b"ple.org/p> <http://example.org/o> .")
+            .unwrap()
+            .is_empty());
+        assert_eq!(context.feed_chunk(b"\n").unwrap().len(), 1);
+        assert!(context.finish().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,293 @@
+use crate::error::{Error, ErrorType};
+use crate::node::Node;
+use crate::specs::xml_specs::XmlDataTypes;
+use crate::triple::Triple;
+use crate::uri::Uri;
+use crate::Result;
+use std::collections::{HashMap, HashSet};
+
+/// Options to configure how RDF parsers build nodes from the parsed tokens.
+#[derive(Debug, Clone, Default)]
+pub struct ParserOptions {
+    /// Rewrite the lexical form of numeric literals to their canonical form, e.g.
+    /// `"01"^^xsd:integer` becomes `"1"^^xsd:integer`, so that equal values result in equal nodes.
+    ///
+    /// Literals of non-numeric data types are not changed.
+    pub canonicalize_numeric_literals: bool,
+
+    /// Normalize the whitespace of `xsd:normalizedString` and `xsd:token` literals, e.g.
+    /// `" a  b "^^xsd:token` becomes `"a b"^^xsd:token`.
+    ///
+    /// See `XmlDataTypes::normalized_whitespace_form`.
+    pub normalize_whitespace: bool,
+
+    /// Replace the labels of blank nodes by sequential labels `b0`, `b1`, ... in the
+    /// order of their first occurrence. Blank nodes sharing a label in the input
+    /// still share a label in the output.
+    pub renumber_blank_nodes: bool,
+
+    /// Prefix of the labels of the blank nodes that the Turtle parser generates for `[]` and
+    /// collections, e.g. `g_` results in `g_auto0`, `g_auto1`, ...
+    ///
+    /// The generated labels cannot be told apart from labels of the input otherwise.
+    pub generated_blank_node_prefix: Option<String>,
+
+    /// Schemes that absolute URIs may use, e.g. `http` and `https`, all schemes if `None`.
+    ///
+    /// Schemes are compared case-insensitively. Relative URIs as well as data types of
+    /// literals and URIs generated by the parser, e.g. for the keyword `a`, are always allowed.
+    pub allowed_schemes: Option<Vec<String>>,
+
+    /// Return an error for any blank node, including unlabeled blank nodes and collections
+    /// of Turtle, e.g. to validate data for stores that do not support blank nodes.
+    pub forbid_blank_nodes: bool,
+
+    /// URIs of the predicates whose triples are kept, all triples if `None`.
+    ///
+    /// Other statements are still parsed to find their end, but no triples are created
+    /// for them, hence the remaining options are not checked for these statements.
+    pub predicate_allowlist: Option<HashSet<String>>,
+
+    /// Maximum number of triples of the input, unlimited if `None`.
+    ///
+    /// Parsing fails as soon as the input contains more triples, e.g. to protect against
+    /// huge untrusted input. Duplicate triples are counted, triples that are not kept due
+    /// to `predicate_allowlist` are not.
+    pub max_triples: Option<usize>,
+}
+
+impl ParserOptions {
+    /// Returns the lexical form of a literal with the provided data type according to the options.
+    pub(crate) fn literal_form(&self, literal: String, data_type: &str) -> String {
+        let literal = if self.normalize_whitespace {
+            XmlDataTypes::normalized_whitespace_form(data_type, &literal).unwrap_or(literal)
+        } else {
+            literal
+        };
+
+        if !self.canonicalize_numeric_literals {
+            return literal;
+        }
+
+        XmlDataTypes::canonical_numeric_form(data_type, &literal).unwrap_or(literal)
+    }
+
+    /// Checks that the provided number of parsed triples does not exceed the maximum.
+    pub(crate) fn check_triple_count(&self, triples: usize) -> Result<()> {
+        match self.max_triples {
+            Some(max_triples) if triples > max_triples => Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                format!(
+                    "Input contains more than the maximum of {} triples.",
+                    max_triples
+                ),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns `true` if triples with the provided predicate are kept.
+    pub(crate) fn allows_predicate(&self, predicate: &Node) -> bool {
+        match (&self.predicate_allowlist, predicate) {
+            (None, _) => true,
+            (Some(allowlist), Node::UriNode { uri }) => allowlist.contains(uri.to_string()),
+            _ => false,
+        }
+    }
+
+    /// Checks that the triple does not contain blank nodes if they are forbidden.
+    ///
+    /// The error message contains the line and column of the statement of the triple.
+    pub(crate) fn check_blank_nodes(
+        &self,
+        triple: &Triple,
+        line: usize,
+        column: usize,
+    ) -> Result<()> {
+        if !self.forbid_blank_nodes {
+            return Ok(());
+        }
+
+        let segments = [
+            ("subject", triple.subject()),
+            ("predicate", triple.predicate()),
+            ("object", triple.object()),
+        ];
+
+        for (segment, node) in &segments {
+            if let Node::BlankNode { ref id } = **node {
+                return Err(Error::new(
+                    ErrorType::InvalidReaderInput,
+                    format!(
+                        "Blank node _:{} is not allowed as {} of a triple at line {}, column {}.",
+                        id, segment, line, column
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the URIs of the triple use one of the allowed schemes.
+    pub(crate) fn check_schemes(&self, triple: &Triple) -> Result<()> {
+        for node in &[triple.subject(), triple.predicate(), triple.object()] {
+            self.check_node_scheme(node)?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the node uses one of the allowed schemes if it is a URI node.
+    pub(crate) fn check_node_scheme(&self, node: &Node) -> Result<()> {
+        match *node {
+            Node::UriNode { ref uri } => self.check_scheme(uri),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks that the URI uses one of the allowed schemes.
+    pub(crate) fn check_scheme(&self, uri: &Uri) -> Result<()> {
+        let (allowed_schemes, scheme) = match (&self.allowed_schemes, uri.scheme()) {
+            (Some(allowed_schemes), Some(scheme)) => (allowed_schemes, scheme),
+            _ => return Ok(()),
+        };
+
+        if allowed_schemes
+            .iter()
+            .any(|s| s.eq_ignore_ascii_case(scheme))
+        {
+            Ok(())
+        } else {
+            Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                format!(
+                    "URI scheme '{}' is not allowed: {}",
+                    scheme,
+                    uri.to_string()
+                ),
+            ))
+        }
+    }
+}
+
+/// Maps the blank node labels of a document to the labels used for the parsed nodes.
+#[derive(Debug, Default)]
+pub(crate) struct BlankNodeLabels {
+    renumber: bool,
+    labels: HashMap<String, String>,
+}
+
+impl BlankNodeLabels {
+    /// Constructor of `BlankNodeLabels` that renumbers labels if required by the options.
+    pub(crate) fn new(options: &ParserOptions) -> BlankNodeLabels {
+        BlankNodeLabels {
+            renumber: options.renumber_blank_nodes,
+            labels: HashMap::new(),
+        }
+    }
+
+    /// Returns the label for the blank node with the label of the input.
+    pub(crate) fn label(&mut self, id: String) -> String {
+        if !self.renumber {
+            return id;
+        }
+
+        let next_label = format!("b{}", self.labels.len());
+        self.labels.entry(id).or_insert(next_label).clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::reader::parser_options::{BlankNodeLabels, ParserOptions};
+    use crate::specs::xml_specs::XmlDataTypes;
+    use crate::uri::Uri;
+
+    #[test]
+    fn test_canonicalize_numeric_literals() {
+        let options = ParserOptions {
+            canonicalize_numeric_literals: true,
+            ..Default::default()
+        };
+
+        let integer = XmlDataTypes::Integer.to_string();
+        let decimal = XmlDataTypes::Decimal.to_string();
+        let double = XmlDataTypes::Double.to_string();
+
+        assert_eq!(options.literal_form("-0012".to_string(), &integer), "-12");
+        assert_eq!(options.literal_form("-0".to_string(), &integer), "0");
+        assert_eq!(options.literal_form("+01.50".to_string(), &decimal), "1.5");
+        assert_eq!(options.literal_form(".0".to_string(), &decimal), "0.0");
+        assert_eq!(
+            options.literal_form("0.00120".to_string(), &double),
+            "1.2E-3"
+        );
+        assert_eq!(
+            options.literal_form("-5e10".to_string(), &double),
+            "-5.0E10"
+        );
+        assert_eq!(
+            options.literal_form("12.5E-1".to_string(), &double),
+            "1.25E0"
+        );
+    }
+
+    #[test]
+    fn test_keep_non_numeric_and_invalid_literals() {
+        let options = ParserOptions {
+            canonicalize_numeric_literals: true,
+            ..Default::default()
+        };
+
+        let string = XmlDataTypes::String.to_string();
+        let integer = XmlDataTypes::Integer.to_string();
+
+        assert_eq!(options.literal_form("01".to_string(), &string), "01");
+        assert_eq!(options.literal_form("0x1".to_string(), &integer), "0x1");
+        assert_eq!(
+            ParserOptions::default().literal_form("01".to_string(), &integer),
+            "01"
+        );
+    }
+
+    #[test]
+    fn test_renumber_blank_node_labels() {
+        let options = ParserOptions {
+            renumber_blank_nodes: true,
+            ..Default::default()
+        };
+
+        let mut renumbered = BlankNodeLabels::new(&options);
+        let mut kept = BlankNodeLabels::new(&ParserOptions::default());
+
+        assert_eq!(renumbered.label("x".to_string()), "b0");
+        assert_eq!(renumbered.label("y".to_string()), "b1");
+        assert_eq!(renumbered.label("x".to_string()), "b0");
+        assert_eq!(kept.label("x".to_string()), "x");
+    }
+
+    #[test]
+    fn test_check_allowed_schemes() {
+        let options = ParserOptions {
+            allowed_schemes: Some(vec!["http".to_string(), "https".to_string()]),
+            ..Default::default()
+        };
+
+        assert!(options
+            .check_scheme(&Uri::new("HTTPS://example.org/".to_string()))
+            .is_ok());
+        assert!(options
+            .check_scheme(&Uri::new("#relative".to_string()))
+            .is_ok());
+        assert!(options
+            .check_scheme(&Uri::new("file:///etc/passwd".to_string()))
+            .is_err());
+        assert!(options
+            .check_scheme(&Uri::new("javascript:alert(1)".to_string()))
+            .is_err());
+        assert!(ParserOptions::default()
+            .check_scheme(&Uri::new("file:///etc/passwd".to_string()))
+            .is_ok());
+    }
+}
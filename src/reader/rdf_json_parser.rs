@@ -0,0 +1,625 @@
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::reader::rdf_parser::RdfParser;
+use crate::triple::Triple;
+use crate::uri::Uri;
+use crate::Result;
+use std::io::Cursor;
+use std::io::Read;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Minimal representation of JSON values required for RDF/JSON.
+#[derive(Debug)]
+enum JsonValue {
+    Object(Vec<(String, JsonValue)>),
+    Array(Vec<JsonValue>),
+    String(String),
+    Other,
+}
+
+/// RDF parser to generate an RDF graph from RDF/JSON syntax.
+///
+/// The expected structure is `{"subject": {"predicate": [{"type": ..., "value": ...}]}}`
+/// where the type of an object is `uri`, `bnode` or `literal`. Literals can
+/// optionally specify a `datatype` or a `lang`.
+///
+/// The crate has no dependencies, hence the JSON input is read by a small built-in reader
+/// instead of the `serde_json` crate.
+pub struct RdfJsonParser<R: Read> {
+    input: R,
+}
+
+impl<R: Read> RdfParser for RdfJsonParser<R> {
+    /// Generates an RDF graph from a string containing RDF/JSON syntax.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::rdf_json_parser::RdfJsonParser;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    ///
+    /// let input = "{\"_:art\": {\"http://xmlns.com/foaf/0.1/name\": [{\"type\": \"literal\", \"value\": \"Art Barstow\"}]}}";
+    ///
+    /// let mut reader = RdfJsonParser::from_string(input.to_string());
+    ///
+    /// match reader.decode() {
+    ///   Ok(graph) => assert_eq!(graph.count(), 1),
+    ///   Err(_) => assert!(false)
+    /// }
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid JSON input.
+    /// - Input that does not conform with the RDF/JSON structure.
+    ///
+    fn decode(&mut self) -> Result<Graph> {
+        let mut input = String::new();
+
+        if self.input.read_to_string(&mut input).is_err() {
+            return Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Invalid input while reading RDF/JSON.",
+            ));
+        }
+
+        let mut chars = input.chars().peekable();
+        let value = Self::read_value(&mut chars)?;
+        Self::skip_whitespace(&mut chars);
+
+        if chars.peek().is_some() {
+            return Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Unexpected content after RDF/JSON object.",
+            ));
+        }
+
+        let mut graph = Graph::new(None);
+
+        match value {
+            JsonValue::Object(subjects) => {
+                for (subject, predicates) in subjects {
+                    let subject = Self::key_to_node(subject);
+
+                    for triple in Self::read_predicates(&subject, predicates)? {
+                        graph.add_triple(&triple);
+                    }
+                }
+            }
+            _ => {
+                return Err(Error::new(
+                    ErrorType::InvalidReaderInput,
+                    "RDF/JSON input is not an object.",
+                ))
+            }
+        }
+
+        Ok(graph)
+    }
+}
+
+impl RdfJsonParser<Cursor<Vec<u8>>> {
+    /// Constructor of `RdfJsonParser` from input string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::rdf_json_parser::RdfJsonParser;
+    ///
+    /// let reader = RdfJsonParser::from_string("{}".to_string());
+    /// ```
+    pub fn from_string<S>(input: S) -> RdfJsonParser<Cursor<Vec<u8>>>
+    where
+        S: Into<String>,
+    {
+        RdfJsonParser::from_reader(Cursor::new(input.into().into_bytes()))
+    }
+}
+
+impl<R: Read> RdfJsonParser<R> {
+    /// Constructor of `RdfJsonParser` from input reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::rdf_json_parser::RdfJsonParser;
+    ///
+    /// let reader = RdfJsonParser::from_reader("{}".as_bytes());
+    /// ```
+    pub fn from_reader(input: R) -> RdfJsonParser<R> {
+        RdfJsonParser { input }
+    }
+
+    /// Creates a node from a subject key which is either a blank node label or an URI.
+    fn key_to_node(key: String) -> Node {
+        if let Some(id) = key.strip_prefix("_:") {
+            Node::BlankNode { id: id.to_string() }
+        } else {
+            Node::UriNode { uri: Uri::new(key) }
+        }
+    }
+
+    /// Creates the triples for all predicates and objects of a subject.
+    fn read_predicates(subject: &Node, predicates: JsonValue) -> Result<Vec<Triple>> {
+        let mut triples = Vec::new();
+
+        let predicates = match predicates {
+            JsonValue::Object(predicates) => predicates,
+            _ => {
+                return Err(Error::new(
+                    ErrorType::InvalidReaderInput,
+                    "RDF/JSON predicates are not an object.",
+                ))
+            }
+        };
+
+        for (predicate, objects) in predicates {
+            let predicate = Node::UriNode {
+                uri: Uri::new(predicate),
+            };
+
+            match objects {
+                JsonValue::Array(objects) => {
+                    for object in objects {
+                        let object = Self::read_object(object)?;
+                        triples.push(Triple::new(subject, &predicate, &object));
+                    }
+                }
+                _ => {
+                    return Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        "RDF/JSON objects are not an array.",
+                    ))
+                }
+            }
+        }
+
+        Ok(triples)
+    }
+
+    /// Creates a node from an RDF/JSON term object.
+    fn read_object(object: JsonValue) -> Result<Node> {
+        let mut term_type = None;
+        let mut value = None;
+        let mut data_type = None;
+        let mut language = None;
+
+        match object {
+            JsonValue::Object(entries) => {
+                for (key, entry) in entries {
+                    let entry = match (key.as_ref(), entry) {
+                        ("type" | "value" | "datatype" | "lang", JsonValue::String(entry)) => entry,
+                        ("type" | "value" | "datatype" | "lang", _) => {
+                            return Err(Error::new(
+                                ErrorType::InvalidReaderInput,
+                                "RDF/JSON term values must be strings.",
+                            ))
+                        }
+                        _ => continue, // ignore unknown keys
+                    };
+
+                    match key.as_ref() {
+                        "type" => term_type = Some(entry),
+                        "value" => value = Some(entry),
+                        "datatype" => data_type = Some(Uri::new(entry)),
+                        _ => language = Some(entry),
+                    }
+                }
+            }
+            _ => {
+                return Err(Error::new(
+                    ErrorType::InvalidReaderInput,
+                    "RDF/JSON term is not an object.",
+                ))
+            }
+        }
+
+        let value = match value {
+            Some(value) => value,
+            None => {
+                return Err(Error::new(
+                    ErrorType::InvalidReaderInput,
+                    "RDF/JSON term without value.",
+                ))
+            }
+        };
+
+        match term_type.as_ref().map(|t| t.as_ref()) {
+            Some("uri") => Ok(Node::UriNode {
+                uri: Uri::new(value),
+            }),
+            Some("bnode") => Ok(Node::BlankNode {
+                id: value.trim_start_matches("_:").to_string(),
+            }),
+            Some("literal") => Ok(Node::LiteralNode {
+                literal: value,
+                data_type,
+                language,
+            }),
+            _ => Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Invalid RDF/JSON term type.",
+            )),
+        }
+    }
+
+    /// Skips all whitespace characters.
+    fn skip_whitespace(chars: &mut Peekable<Chars>) {
+        while let Some(c) = chars.peek() {
+            if !c.is_whitespace() {
+                break;
+            }
+
+            chars.next();
+        }
+    }
+
+    /// Consumes the next non-whitespace character which is expected to be `expected`.
+    fn expect_char(chars: &mut Peekable<Chars>, expected: char) -> Result<()> {
+        Self::skip_whitespace(chars);
+
+        match chars.next() {
+            Some(c) if c == expected => Ok(()),
+            _ => Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                format!("Expected '{}' in JSON input.", expected),
+            )),
+        }
+    }
+
+    /// Reads a JSON value.
+    fn read_value(chars: &mut Peekable<Chars>) -> Result<JsonValue> {
+        Self::skip_whitespace(chars);
+
+        match chars.peek() {
+            Some('{') => Self::read_json_object(chars),
+            Some('[') => Self::read_json_array(chars),
+            Some('"') => Ok(JsonValue::String(Self::read_json_string(chars)?)),
+            Some(_) => {
+                // numbers, booleans and null are not part of RDF/JSON terms
+                let mut word = String::new();
+
+                while let Some(&c) = chars.peek() {
+                    if c == ',' || c == '}' || c == ']' || c.is_whitespace() {
+                        break;
+                    }
+
+                    word.push(c);
+                    chars.next();
+                }
+
+                if word == "true" || word == "false" || word == "null" || Self::is_number(&word) {
+                    Ok(JsonValue::Other)
+                } else {
+                    Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        "Invalid JSON value: ".to_string() + &word,
+                    ))
+                }
+            }
+            None => Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Unexpected end of JSON input.",
+            )),
+        }
+    }
+
+    /// Returns `true` if the word is a JSON number, e.g. `-1`, `0.5` or `2e10`.
+    fn is_number(word: &str) -> bool {
+        let digits = |s: &str| s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+
+        let rest = word.strip_prefix('-').unwrap_or(word);
+        let integer_digits = digits(rest);
+
+        if integer_digits == 0 || (integer_digits > 1 && rest.starts_with('0')) {
+            return false;
+        }
+
+        let mut rest = &rest[integer_digits..];
+
+        if let Some(fraction) = rest.strip_prefix('.') {
+            let fraction_digits = digits(fraction);
+
+            if fraction_digits == 0 {
+                return false;
+            }
+
+            rest = &fraction[fraction_digits..];
+        }
+
+        if let Some(exponent) = rest.strip_prefix(|c| c == 'e' || c == 'E') {
+            let exponent = exponent
+                .strip_prefix(|c| c == '+' || c == '-')
+                .unwrap_or(exponent);
+            let exponent_digits = digits(exponent);
+
+            if exponent_digits == 0 {
+                return false;
+            }
+
+            rest = &exponent[exponent_digits..];
+        }
+
+        rest.is_empty()
+    }
+
+    /// Reads a JSON object.
+    fn read_json_object(chars: &mut Peekable<Chars>) -> Result<JsonValue> {
+        let mut entries = Vec::new();
+
+        Self::expect_char(chars, '{')?;
+        Self::skip_whitespace(chars);
+
+        if chars.peek() == Some(&'}') {
+            chars.next();
+            return Ok(JsonValue::Object(entries));
+        }
+
+        loop {
+            Self::skip_whitespace(chars);
+            let key = Self::read_json_string(chars)?;
+            Self::expect_char(chars, ':')?;
+            let value = Self::read_value(chars)?;
+            entries.push((key, value));
+
+            Self::skip_whitespace(chars);
+
+            match chars.next() {
+                Some(',') => continue,
+                Some('}') => return Ok(JsonValue::Object(entries)),
+                _ => {
+                    return Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        "Invalid JSON object.",
+                    ))
+                }
+            }
+        }
+    }
+
+    /// Reads a JSON array.
+    fn read_json_array(chars: &mut Peekable<Chars>) -> Result<JsonValue> {
+        let mut values = Vec::new();
+
+        Self::expect_char(chars, '[')?;
+        Self::skip_whitespace(chars);
+
+        if chars.peek() == Some(&']') {
+            chars.next();
+            return Ok(JsonValue::Array(values));
+        }
+
+        loop {
+            values.push(Self::read_value(chars)?);
+
+            Self::skip_whitespace(chars);
+
+            match chars.next() {
+                Some(',') => continue,
+                Some(']') => return Ok(JsonValue::Array(values)),
+                _ => {
+                    return Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        "Invalid JSON array.",
+                    ))
+                }
+            }
+        }
+    }
+
+    /// Reads a JSON string and resolves its escape sequences.
+    fn read_json_string(chars: &mut Peekable<Chars>) -> Result<String> {
+        let mut output_string = String::new();
+
+        Self::expect_char(chars, '"')?;
+
+        loop {
+            match chars.next() {
+                Some('"') => return Ok(output_string),
+                Some('\\') => match chars.next() {
+                    Some('"') => output_string.push('"'),
+                    Some('\\') => output_string.push('\\'),
+                    Some('/') => output_string.push('/'),
+                    Some('b') => output_string.push('\u{8}'),
+                    Some('f') => output_string.push('\u{c}'),
+                    Some('n') => output_string.push('\n'),
+                    Some('r') => output_string.push('\r'),
+                    Some('t') => output_string.push('\t'),
+                    Some('u') => output_string.push(Self::read_unicode_escape(chars)?),
+                    _ => {
+                        return Err(Error::new(
+                            ErrorType::InvalidReaderInput,
+                            "Invalid escape sequence in JSON string.",
+                        ))
+                    }
+                },
+                Some(c) => output_string.push(c),
+                None => {
+                    return Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        "Unterminated JSON string.",
+                    ))
+                }
+            }
+        }
+    }
+
+    /// Reads the hexadecimal digits of a `\u` escape sequence, including surrogate pairs.
+    fn read_unicode_escape(chars: &mut Peekable<Chars>) -> Result<char> {
+        let high = Self::read_hex_code(chars)?;
+
+        let code = if (0xD800..0xDC00).contains(&high) {
+            // surrogate pair: expect a second `\u` escape
+            if chars.next() != Some('\\') || chars.next() != Some('u') {
+                return Err(Error::new(
+                    ErrorType::InvalidReaderInput,
+                    "Invalid unicode surrogate pair in JSON string.",
+                ));
+            }
+
+            let low = Self::read_hex_code(chars)?;
+
+            if !(0xDC00..=0xDFFF).contains(&low) {
+                return Err(Error::new(
+                    ErrorType::InvalidReaderInput,
+                    "Invalid unicode surrogate pair in JSON string.",
+                ));
+            }
+
+            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+        } else {
+            high
+        };
+
+        std::char::from_u32(code).ok_or_else(|| {
+            Error::new(
+                ErrorType::InvalidReaderInput,
+                "Invalid unicode escape in JSON string.",
+            )
+        })
+    }
+
+    /// Reads four hexadecimal digits.
+    fn read_hex_code(chars: &mut Peekable<Chars>) -> Result<u32> {
+        let hex: String = chars.take(4).collect();
+
+        match u32::from_str_radix(&hex, 16) {
+            Ok(code) if hex.len() == 4 => Ok(code),
+            _ => Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Invalid unicode escape in JSON string.",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::Graph;
+    use crate::node::Node;
+    use crate::reader::rdf_json_parser::RdfJsonParser;
+    use crate::reader::rdf_parser::RdfParser;
+    use crate::specs::xml_specs::XmlDataTypes;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+    use crate::writer::rdf_json_writer::RdfJsonWriter;
+    use crate::writer::rdf_writer::RdfWriter;
+
+    #[test]
+    fn test_read_rdf_json_term_types() {
+        let input = "{
+            \"http://example.org/s\": {
+                \"http://example.org/p\": [
+                    { \"type\": \"uri\", \"value\": \"http://example.org/o\" },
+                    { \"type\": \"bnode\", \"value\": \"_:b0\" },
+                    { \"type\": \"literal\", \"value\": \"chat\", \"lang\": \"fr\" },
+                    { \"type\": \"literal\", \"value\": \"1\", \"datatype\": \"http://www.w3.org/2001/XMLSchema#integer\" }
+                ]
+            },
+            \"_:b0\": {
+                \"http://example.org/p\": [ { \"type\": \"literal\", \"value\": \"line\\nbreak \\u00e9\" } ]
+            }
+        }";
+
+        let mut reader = RdfJsonParser::from_string(input.to_string());
+
+        match reader.decode() {
+            Ok(graph) => {
+                assert_eq!(graph.count(), 5);
+
+                let blank = graph.get_triples_with_subject(&Node::BlankNode {
+                    id: "b0".to_string(),
+                });
+                assert_eq!(blank.len(), 1);
+                assert_eq!(
+                    blank[0].object(),
+                    &graph.create_literal_node("line\nbreak é".to_string())
+                );
+            }
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_read_invalid_rdf_json() {
+        let input = "{\"s\": {\"p\": [{\"type\": \"unknown\", \"value\": \"x\"}]}}";
+        let mut reader = RdfJsonParser::from_string(input);
+        assert!(reader.decode().is_err());
+
+        let mut reader = RdfJsonParser::from_string("{\"s\": ");
+        assert!(reader.decode().is_err());
+
+        let mut reader = RdfJsonParser::from_string("{\"a\": xyz}");
+        assert!(reader.decode().is_err());
+
+        let input = "{\"s\": {\"p\": [{\"type\": \"literal\", \"value\": \"\\uD800\\u0041\"}]}}";
+        let mut reader = RdfJsonParser::from_string(input);
+        assert!(reader.decode().is_err());
+    }
+
+    #[test]
+    fn test_skip_json_values_other_than_terms() {
+        for value in ["true", "false", "null", "0", "-12.5e+3", "1E5"] {
+            let input = format!(
+                "{{\"http://example.org/s\": {{\"http://example.org/p\": [
+                    {{ \"type\": \"literal\", \"value\": \"\\uD83D\\uDE00\", \"extra\": {} }}
+                ]}}}}",
+                value
+            );
+
+            let mut reader = RdfJsonParser::from_string(input);
+
+            match reader.decode() {
+                Ok(graph) => assert_eq!(
+                    graph.triples_iter().next().unwrap().object(),
+                    &graph.create_literal_node("\u{1F600}".to_string())
+                ),
+                Err(e) => panic!("Err for {}: {}", value, e),
+            }
+        }
+
+        for value in ["01", "1.", "-", "1e", "nul", "+1"] {
+            let input = format!(
+                "{{\"s\": {{\"p\": [{{\"type\": \"uri\", \"value\": \"o\", \"x\": {}}}]}}}}",
+                value
+            );
+            assert!(
+                RdfJsonParser::from_string(input).decode().is_err(),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_rdf_json_round_trip() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_uri_node(&Uri::new("http://example.org/s".to_string()));
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let blank = graph.create_blank_node();
+        let literal = graph
+            .create_literal_node_with_language("a \"quoted\" text".to_string(), "en".to_string());
+        let number = graph
+            .create_literal_node_with_data_type("1".to_string(), &XmlDataTypes::Integer.to_uri());
+
+        graph.add_triple(&Triple::new(&subject, &predicate, &blank));
+        graph.add_triple(&Triple::new(&subject, &predicate, &literal));
+        graph.add_triple(&Triple::new(&blank, &predicate, &number));
+
+        let writer = RdfJsonWriter::new();
+        let json = writer.write_to_string(&graph).unwrap();
+
+        let mut reader = RdfJsonParser::from_string(json);
+        let parsed = reader.decode().unwrap();
+
+        let mut expected: Vec<&Triple> = graph.triples_iter().collect();
+        let mut actual: Vec<&Triple> = parsed.triples_iter().collect();
+        expected.sort();
+        actual.sort();
+
+        assert_eq!(expected, actual);
+    }
+}
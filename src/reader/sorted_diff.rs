@@ -0,0 +1,218 @@
+use crate::error::{Error, ErrorType};
+use crate::reader::n_triples_parser::NTriplesParser;
+use crate::triple::Triple;
+use crate::writer::n_triples_writer::NTriplesWriter;
+use crate::Result;
+use std::cmp::Ordering;
+use std::io::Read;
+
+/// Difference of a triple between two N-Triples inputs.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiffEntry {
+    /// Triple of the new input that is not contained in the old input.
+    Added(Triple),
+
+    /// Triple of the old input that is not contained in the new input.
+    Removed(Triple),
+}
+
+/// Iterator over the differences of two N-Triples inputs whose lines are sorted.
+///
+/// Both inputs are read triple by triple and merged, hence neither is loaded as a whole.
+/// Triples are compared by their N-Triples serialization, so the inputs must be sorted
+/// by this serialization, e.g. canonical dumps sorted with `sort`. Repeated triples of
+/// an input are treated as one.
+pub struct SortedDiff<R1: Read, R2: Read> {
+    old: NTriplesParser<R1>,
+    new: NTriplesParser<R2>,
+    writer: NTriplesWriter,
+    next_old: Option<(String, Triple)>,
+    next_new: Option<(String, Triple)>,
+    started: bool,
+    finished: bool,
+}
+
+impl<R1: Read, R2: Read> SortedDiff<R1, R2> {
+    /// Constructor of `SortedDiff` comparing the old input with the new input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::sorted_diff::{DiffEntry, SortedDiff};
+    ///
+    /// let old = "<http://example.org/a> <http://example.org/p> \"1\" .
+    ///            <http://example.org/b> <http://example.org/p> \"2\" .";
+    /// let new = "<http://example.org/b> <http://example.org/p> \"2\" .";
+    ///
+    /// let entries: Vec<DiffEntry> = SortedDiff::new(old.as_bytes(), new.as_bytes())
+    ///     .map(|entry| entry.unwrap())
+    ///     .collect();
+    ///
+    /// assert_eq!(entries.len(), 1);
+    /// assert!(matches!(entries[0], DiffEntry::Removed(_)));
+    /// ```
+    pub fn new(old: R1, new: R2) -> SortedDiff<R1, R2> {
+        SortedDiff {
+            old: NTriplesParser::from_reader(old),
+            new: NTriplesParser::from_reader(new),
+            writer: NTriplesWriter::new(),
+            next_old: None,
+            next_new: None,
+            started: false,
+            finished: false,
+        }
+    }
+
+    /// Reads the next triple of the input that differs from the previous one.
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with NTriples standard.
+    /// - The triple is sorted before the previous triple.
+    ///
+    fn read_next<R: Read>(
+        parser: &mut NTriplesParser<R>,
+        writer: &NTriplesWriter,
+        previous: &Option<(String, Triple)>,
+    ) -> Result<Option<(String, Triple)>> {
+        while let Some(triple) = parser.next_triple()? {
+            let line = writer.triple_to_n_triples(&triple)?;
+
+            match previous {
+                Some((ref previous_line, _)) if line == *previous_line => continue,
+                Some((ref previous_line, _)) if line < *previous_line => {
+                    return Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        format!("Input is not sorted: {} follows {}", line, previous_line),
+                    ))
+                }
+                _ => return Ok(Some((line, triple))),
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Determines the next difference of both inputs, if any.
+    fn next_entry(&mut self) -> Result<Option<DiffEntry>> {
+        if !self.started {
+            self.started = true;
+            self.next_old = Self::read_next(&mut self.old, &self.writer, &None)?;
+            self.next_new = Self::read_next(&mut self.new, &self.writer, &None)?;
+        }
+
+        loop {
+            let ordering = match (&self.next_old, &self.next_new) {
+                (None, None) => return Ok(None),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (Some((old_line, _)), Some((new_line, _))) => old_line.cmp(new_line),
+            };
+
+            match ordering {
+                Ordering::Less => {
+                    let next = Self::read_next(&mut self.old, &self.writer, &self.next_old)?;
+                    let (_, triple) = std::mem::replace(&mut self.next_old, next).unwrap();
+
+                    return Ok(Some(DiffEntry::Removed(triple)));
+                }
+                Ordering::Greater => {
+                    let next = Self::read_next(&mut self.new, &self.writer, &self.next_new)?;
+                    let (_, triple) = std::mem::replace(&mut self.next_new, next).unwrap();
+
+                    return Ok(Some(DiffEntry::Added(triple)));
+                }
+                Ordering::Equal => {
+                    self.next_old = Self::read_next(&mut self.old, &self.writer, &self.next_old)?;
+                    self.next_new = Self::read_next(&mut self.new, &self.writer, &self.next_new)?;
+                }
+            }
+        }
+    }
+}
+
+impl<R1: Read, R2: Read> Iterator for SortedDiff<R1, R2> {
+    type Item = Result<DiffEntry>;
+
+    /// Returns the next difference of both inputs. After an error no further entries are returned.
+    fn next(&mut self) -> Option<Result<DiffEntry>> {
+        if self.finished {
+            return None;
+        }
+
+        let entry = self.next_entry().transpose();
+
+        if !matches!(entry, Some(Ok(_))) {
+            self.finished = true;
+        }
+
+        entry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ErrorType;
+    use crate::node::Node;
+    use crate::reader::sorted_diff::{DiffEntry, SortedDiff};
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+
+    fn triple(subject: &str, object: &str) -> Triple {
+        Triple::new(
+            &Node::UriNode {
+                uri: Uri::new(format!("http://example.org/{}", subject)),
+            },
+            &Node::UriNode {
+                uri: Uri::new("http://example.org/p".to_string()),
+            },
+            &Node::LiteralNode {
+                literal: object.to_string(),
+                data_type: None,
+                language: None,
+            },
+        )
+    }
+
+    #[test]
+    fn test_diff_sorted_inputs() {
+        let old = "<http://example.org/a> <http://example.org/p> \"1\" .
+                   <http://example.org/b> <http://example.org/p> \"2\" .
+                   <http://example.org/b> <http://example.org/p> \"2\" .
+                   <http://example.org/d> <http://example.org/p> \"4\" .";
+        let new = "# added and removed triples
+                   <http://example.org/b> <http://example.org/p> \"2\" .
+                   <http://example.org/c> <http://example.org/p> \"3\" .
+                   <http://example.org/e> <http://example.org/p> \"5\" .";
+
+        let entries: Vec<DiffEntry> = SortedDiff::new(old.as_bytes(), new.as_bytes())
+            .map(|entry| entry.unwrap())
+            .collect();
+
+        assert_eq!(
+            entries,
+            vec![
+                DiffEntry::Removed(triple("a", "1")),
+                DiffEntry::Added(triple("c", "3")),
+                DiffEntry::Removed(triple("d", "4")),
+                DiffEntry::Added(triple("e", "5")),
+            ]
+        );
+        assert_eq!(SortedDiff::new(old.as_bytes(), old.as_bytes()).count(), 0);
+    }
+
+    #[test]
+    fn test_diff_unsorted_input() {
+        let old = "<http://example.org/b> <http://example.org/p> \"2\" .
+                   <http://example.org/a> <http://example.org/p> \"1\" .";
+        let new = "<http://example.org/c> <http://example.org/p> \"3\" .";
+
+        let entries: Vec<_> = SortedDiff::new(old.as_bytes(), new.as_bytes()).collect();
+
+        assert_eq!(entries.len(), 1);
+        assert!(matches!(
+            entries[0].as_ref().unwrap_err().error_type(),
+            ErrorType::InvalidReaderInput
+        ));
+    }
+}
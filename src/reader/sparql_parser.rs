@@ -6,6 +6,7 @@ use crate::reader::lexer::token::Token;
 use crate::sparql::pattern::{GroupPattern, NodePattern, TriplePattern};
 use crate::sparql::query::{SparqlQuery, SparqlQueryType};
 use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+use crate::specs::xml_specs::XmlDataTypes;
 use std::io::Cursor;
 use std::io::Read;
 use crate::uri::Uri;
@@ -152,12 +153,9 @@ impl<R: Read> SparqlParser<R> {
         let mut query = SparqlQuery::new(query_type);
 
         // parse WHERE clause
-        match self.lexer.peek_next_token()? {
-            Token::Where => {
-                // WHERE keyword is optional but always followed by a group
-                let _ = self.lexer.get_next_token();
-            }
-            _ => {}
+        if self.lexer.peek_next_token()? == Token::Where {
+            // WHERE keyword is optional but always followed by a group
+            let _ = self.lexer.get_next_token();
         }
 
         // parse group
@@ -230,11 +228,11 @@ impl<R: Read> SparqlParser<R> {
     /// Get the next token and check if it is a valid subject pattern.
     fn read_subject_pattern(&mut self, query: &mut SparqlQuery) -> Result<NodePattern> {
         match self.lexer.get_next_token()? {
-            Token::BlankNode(id) => Ok(NodePattern::FixedNode(Node::BlankNode { id: id })),
+            Token::BlankNode(id) => Ok(NodePattern::FixedNode(Node::BlankNode { id })),
             Token::QName(prefix, path) => {
                 let mut uri = query.get_namespace_uri_by_prefix(prefix)?.to_owned();
                 uri.append_resource_path(&path.replace(":", "/")); // adjust the QName path to URI path
-                Ok(NodePattern::FixedNode(Node::UriNode { uri: uri }))
+                Ok(NodePattern::FixedNode(Node::UriNode { uri }))
             }
             Token::Uri(uri) => Ok(NodePattern::FixedNode(Node::UriNode { uri: Uri::new(uri) })),
             Token::SparqlVariable(variable_name) => Ok(NodePattern::VariableNode(variable_name)),
@@ -299,9 +297,9 @@ impl<R: Read> SparqlParser<R> {
             Token::QName(prefix, path) => {
                 let mut uri = query.get_namespace_uri_by_prefix(prefix)?.to_owned();
                 uri.append_resource_path(&path.replace(":", "/")); // adjust the QName path to URI path
-                NodePattern::FixedNode(Node::UriNode { uri: uri })
+                NodePattern::FixedNode(Node::UriNode { uri })
             }
-            Token::BlankNode(id) => NodePattern::FixedNode(Node::BlankNode { id: id }),
+            Token::BlankNode(id) => NodePattern::FixedNode(Node::BlankNode { id }),
             Token::SparqlVariable(variable_name) => NodePattern::VariableNode(variable_name),
             _ => {
                 return Err(Error::new(
@@ -320,33 +318,48 @@ impl<R: Read> SparqlParser<R> {
     /// Get the next token and check if it is a valid object and create a new object node pattern.
     fn read_object_pattern(&mut self, query: &mut SparqlQuery) -> Result<NodePattern> {
         match self.lexer.get_next_token()? {
-            Token::BlankNode(id) => Ok(NodePattern::FixedNode(Node::BlankNode { id: id })),
+            Token::BlankNode(id) => Ok(NodePattern::FixedNode(Node::BlankNode { id })),
             Token::Uri(uri) => Ok(NodePattern::FixedNode(Node::UriNode { uri: Uri::new(uri) })),
             Token::QName(prefix, path) => {
                 let mut uri = query.get_namespace_uri_by_prefix(prefix)?.to_owned();
                 uri.append_resource_path(&path.replace(":", "/")); // adjust the QName path to URI path
-                Ok(NodePattern::FixedNode(Node::UriNode { uri: uri }))
+                Ok(NodePattern::FixedNode(Node::UriNode { uri }))
             }
             Token::SparqlVariable(variable_name) => Ok(NodePattern::VariableNode(variable_name)),
             Token::LiteralWithLanguageSpecification(literal, lang) => {
                 Ok(NodePattern::FixedNode(Node::LiteralNode {
-                    literal: literal,
+                    literal,
                     data_type: None,
                     language: Some(lang),
                 }))
             }
             Token::LiteralWithUrlDatatype(literal, datatype) => {
                 Ok(NodePattern::FixedNode(Node::LiteralNode {
-                    literal: literal,
+                    literal,
                     data_type: Some(Uri::new(datatype)),
                     language: None,
                 }))
             }
             Token::Literal(literal) => Ok(NodePattern::FixedNode(Node::LiteralNode {
-                literal: literal,
+                literal,
                 data_type: None,
                 language: None,
             })),
+            Token::IntegerLiteral(literal) => Ok(NodePattern::FixedNode(Node::LiteralNode {
+                literal,
+                data_type: Some(XmlDataTypes::Integer.to_uri()),
+                language: None,
+            })),
+            Token::DecimalLiteral(literal) => Ok(NodePattern::FixedNode(Node::LiteralNode {
+                literal,
+                data_type: Some(XmlDataTypes::Decimal.to_uri()),
+                language: None,
+            })),
+            Token::DoubleLiteral(literal) => Ok(NodePattern::FixedNode(Node::LiteralNode {
+                literal,
+                data_type: Some(XmlDataTypes::Double.to_uri()),
+                language: None,
+            })),
             _ => Err(Error::new(
                 ErrorType::InvalidToken,
                 "Invalid token for SPARQL object pattern.",
@@ -366,14 +379,11 @@ mod tests {
         let mut reader = SparqlParser::from_string(input.to_string());
 
         match reader.decode() {
-            Ok(sparql_query) => match sparql_query.get_query_type() {
-                &SparqlQueryType::Select => assert!(true),
-                _ => assert!(false),
-            },
-            Err(e) => {
-                println!("Err {}", e.to_string());
-                assert!(false)
-            }
+            Ok(sparql_query) => assert!(matches!(
+                sparql_query.get_query_type(),
+                SparqlQueryType::Select
+            )),
+            Err(e) => panic!("Err {}", e),
         }
     }
 
@@ -393,10 +403,7 @@ mod tests {
                 // todo
                 //        let expected_triple
             }
-            Err(e) => {
-                println!("Err {}", e.to_string());
-                assert!(false)
-            }
+            Err(e) => panic!("Err {}", e),
         }
     }
 
@@ -0,0 +1,138 @@
+use crate::reader::n_triples_parser::NTriplesParser;
+use crate::triple::Triple;
+use crate::writer::n_triples_writer::NTriplesWriter;
+use crate::Result;
+use std::io::{Read, Write};
+
+/// Parser that passes each parsed triple to a callback and writes it as N-Triples to an output.
+///
+/// Triples are processed one at a time while parsing, hence the input is never loaded
+/// into a graph as a whole.
+pub struct TeeParser<R: Read, W: Write, F: FnMut(&Triple)> {
+    parser: NTriplesParser<R>,
+    writer: NTriplesWriter,
+    output: W,
+    callback: F,
+}
+
+impl<R: Read, W: Write, F: FnMut(&Triple)> TeeParser<R, W, F> {
+    /// Constructor of `TeeParser` that reads the triples of the provided parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_triples_parser::NTriplesParser;
+    /// use rdf::reader::tee_parser::TeeParser;
+    ///
+    /// let input = "_:art <http://xmlns.com/foaf/0.1/name> \"Art Barstow\" .";
+    ///
+    /// let mut count = 0;
+    /// let mut output: Vec<u8> = Vec::new();
+    ///
+    /// let mut tee = TeeParser::new(NTriplesParser::from_string(input), &mut output, |_| count += 1);
+    /// tee.run().unwrap();
+    /// drop(tee);
+    ///
+    /// assert_eq!(count, 1);
+    /// assert_eq!(String::from_utf8(output).unwrap(), format!("{}\n", input));
+    /// ```
+    pub fn new(parser: NTriplesParser<R>, output: W, callback: F) -> TeeParser<R, W, F> {
+        TeeParser {
+            parser,
+            writer: NTriplesWriter::new(),
+            output,
+            callback,
+        }
+    }
+
+    /// Parses the next triple, passes it to the callback and writes it to the output.
+    ///
+    /// Returns `None` once the end of the input is reached.
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with NTriples standard.
+    /// - Writing to the output fails.
+    ///
+    pub fn next_triple(&mut self) -> Result<Option<Triple>> {
+        let triple = match self.parser.next_triple()? {
+            Some(triple) => triple,
+            None => return Ok(None),
+        };
+
+        (self.callback)(&triple);
+
+        self.writer.write_triple(&triple, &mut self.output)?;
+
+        Ok(Some(triple))
+    }
+
+    /// Processes all remaining triples of the input and returns their number.
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with NTriples standard.
+    /// - Writing to the output fails.
+    ///
+    pub fn run(&mut self) -> Result<usize> {
+        let mut count = 0;
+
+        while self.next_triple()?.is_some() {
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
+    /// Returns the output the triples are written to.
+    pub fn into_output(self) -> W {
+        self.output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::reader::n_triples_parser::NTriplesParser;
+    use crate::reader::tee_parser::TeeParser;
+
+    #[test]
+    fn test_tee_triples_to_callback_and_output() {
+        let input = "<http://example.org/s> <http://example.org/p> <http://example.org/o> .
+                 # comment
+                 <http://example.org/s> <http://example.org/p> \"o\"@en .
+                 _:b <http://example.org/p> \"o\" .";
+
+        let mut subjects = Vec::new();
+
+        let mut tee = TeeParser::new(NTriplesParser::from_string(input), Vec::new(), |triple| {
+            subjects.push(triple.subject().clone())
+        });
+
+        assert_eq!(tee.run().unwrap(), 3);
+
+        let output = String::from_utf8(tee.into_output()).unwrap();
+
+        assert_eq!(subjects.len(), 3);
+        assert_eq!(
+            output,
+            "<http://example.org/s> <http://example.org/p> <http://example.org/o> .\n\
+             <http://example.org/s> <http://example.org/p> \"o\"@en .\n\
+             _:b <http://example.org/p> \"o\" .\n"
+        );
+    }
+
+    #[test]
+    fn test_tee_stops_at_invalid_triple() {
+        let input = "<http://example.org/s> <http://example.org/p> \"o\" .
+                 <http://example.org/s> \"p\" \"o\" .";
+
+        let mut count = 0;
+        let mut tee = TeeParser::new(NTriplesParser::from_string(input), Vec::new(), |_| {
+            count += 1
+        });
+
+        assert!(tee.run().is_err());
+        assert_eq!(tee.into_output().iter().filter(|&&b| b == b'\n').count(), 1);
+        assert_eq!(count, 1);
+    }
+}
@@ -2,11 +2,16 @@ use crate::error::{Error, ErrorType};
 use crate::graph::Graph;
 use crate::namespace::Namespace;
 use crate::node::Node;
-use crate::reader::lexer::rdf_lexer::RdfLexer;
+use crate::reader::input_reader::ChainReader;
+use crate::reader::lexer::rdf_lexer::{LexerOptions, RdfLexer};
 use crate::reader::lexer::token::Token;
+use crate::reader::parse_stats::ParseStats;
+use crate::reader::parser_builder::ConfigurableParser;
+use crate::reader::parser_options::{BlankNodeLabels, ParserOptions};
 use crate::reader::lexer::turtle_lexer::TurtleLexer;
 use crate::reader::rdf_parser::RdfParser;
 use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+use crate::specs::xml_specs::XmlDataTypes;
 use std::io::Cursor;
 use std::io::Read;
 use crate::triple::Triple;
@@ -16,6 +21,18 @@ use crate::Result;
 /// RDF parser to generate an RDF graph from Turtle syntax.
 pub struct TurtleParser<R: Read> {
     lexer: TurtleLexer<R>,
+    options: ParserOptions,
+    blank_node_labels: BlankNodeLabels,
+    stats: ParseStats,
+
+    /// Line at which the statement that is parsed starts.
+    statement_line: usize,
+
+    /// Column at which the statement that is parsed starts.
+    statement_column: usize,
+
+    /// Inserted triples together with the line of their statement, if they are collected.
+    triple_lines: Option<Vec<(Triple, usize)>>,
 }
 
 impl<R: Read> RdfParser for TurtleParser<R> {
@@ -47,46 +64,26 @@ impl<R: Read> RdfParser for TurtleParser<R> {
     ///
     fn decode(&mut self) -> Result<Graph> {
         let mut graph = Graph::new(None);
+        self.decode_into(&mut graph)?;
+        Ok(graph)
+    }
+}
 
-        loop {
-            match self.lexer.peek_next_token() {
-                Ok(Token::Comment(_)) => {
-                    let _ = self.lexer.get_next_token();
-                    continue;
-                }
-                Ok(Token::EndOfInput) => return Ok(graph),
-                Ok(Token::BaseDirective(_)) => {
-                    let base_uri = self.read_base_directive()?;
-                    graph.set_base_uri(&base_uri);
-                }
-                Ok(Token::PrefixDirective(_, _)) => {
-                    let namespace = self.read_prefix_directive()?;
-                    graph.add_namespace(&namespace);
-                }
-                Ok(Token::Uri(_))
-                | Ok(Token::BlankNode(_))
-                | Ok(Token::QName(_, _))
-                | Ok(Token::CollectionStart)
-                | Ok(Token::UnlabeledBlankNodeStart) => {
-                    let triples = self.read_triples(&mut graph)?;
-                    graph.add_triples(&triples);
-                }
-                Err(err) => match err.error_type() {
-                    &ErrorType::EndOfInput(_) => return Ok(graph),
-                    _ => {
-                        return Err(Error::new(
-                            ErrorType::InvalidReaderInput,
-                            "Error while parsing Turtle syntax.",
-                        ))
-                    }
-                },
-                Ok(_) => {
-                    return Err(Error::new(
-                        ErrorType::InvalidToken,
-                        "Invalid token while parsing Turtle syntax.",
-                    ))
-                }
-            }
+impl<R: Read> ConfigurableParser<R> for TurtleParser<R> {
+    /// Constructor of `TurtleParser` from input reader using the provided lexer and parser options.
+    fn with_configuration(
+        input: R,
+        lexer_options: LexerOptions,
+        parser_options: ParserOptions,
+    ) -> TurtleParser<R> {
+        TurtleParser {
+            lexer: TurtleLexer::with_options(input, lexer_options),
+            blank_node_labels: BlankNodeLabels::new(&parser_options),
+            options: parser_options,
+            stats: ParseStats::new(),
+            statement_line: 1,
+            statement_column: 1,
+            triple_lines: None,
         }
     }
 }
@@ -113,6 +110,33 @@ impl TurtleParser<Cursor<Vec<u8>>> {
     }
 }
 
+impl<R: Read> TurtleParser<ChainReader<R>> {
+    /// Constructor of `TurtleParser` that parses several readers as one document.
+    ///
+    /// The inputs are concatenated, hence blank node labels are shared across
+    /// all readers instead of being kept apart like when merging graphs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    ///
+    /// let first = "_:art <http://xmlns.com/foaf/0.1/name> \"Art Barstow\" .";
+    /// let second = "<http://www.w3.org/2001/sw/RDFCore/ntriples/> <http://xmlns.com/foaf/0.1/maker> _:art .";
+    ///
+    /// let mut reader = TurtleParser::from_readers(vec![first.as_bytes(), second.as_bytes()]);
+    ///
+    /// assert_eq!(reader.decode().unwrap().count(), 2);
+    /// ```
+    pub fn from_readers<I>(inputs: I) -> TurtleParser<ChainReader<R>>
+    where
+        I: IntoIterator<Item = R>,
+    {
+        TurtleParser::from_reader(ChainReader::new(inputs))
+    }
+}
+
 impl<R: Read> TurtleParser<R> {
     /// Constructor of `TurtleParser` from input reader.
     ///
@@ -128,8 +152,184 @@ impl<R: Read> TurtleParser<R> {
     /// let reader = TurtleParser::from_reader(input.as_bytes());
     /// ```
     pub fn from_reader(input: R) -> TurtleParser<R> {
-        TurtleParser {
-            lexer: TurtleLexer::new(input),
+        TurtleParser::with_options(input, ParserOptions::default())
+    }
+
+    /// Constructor of `TurtleParser` from input reader using the provided options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    /// use rdf::reader::parser_options::ParserOptions;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    ///
+    /// let input = "<http://example.org/s> <http://example.org/p> \"01\"^^<http://www.w3.org/2001/XMLSchema#integer> .";
+    ///
+    /// let options = ParserOptions {
+    ///     canonicalize_numeric_literals: true,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut reader = TurtleParser::with_options(input.as_bytes(), options);
+    /// let graph = reader.decode().unwrap();
+    ///
+    /// let one = graph.create_literal_node_with_data_type("1".to_string(), &XmlDataTypes::Integer.to_uri());
+    ///
+    /// assert_eq!(graph.triples_iter().next().unwrap().object(), &one);
+    /// ```
+    pub fn with_options(input: R, options: ParserOptions) -> TurtleParser<R> {
+        TurtleParser::with_configuration(input, LexerOptions::default(), options)
+    }
+
+    /// Returns the statistics collected while parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    ///
+    /// let input = "_:art <http://xmlns.com/foaf/0.1/name> \"Art Barstow\" .
+    ///              _:art <http://xmlns.com/foaf/0.1/name> \"Art Barstow\" .";
+    ///
+    /// let mut reader = TurtleParser::from_string(input.to_string());
+    /// reader.decode().unwrap();
+    ///
+    /// assert_eq!(reader.stats().duplicate_triples(), 1);
+    /// ```
+    pub fn stats(&self) -> &ParseStats {
+        &self.stats
+    }
+
+    /// Parses all triples of the input together with the line at which their statement starts.
+    ///
+    /// Triples of the same statement, e.g. separated by `;` or `,`, share the line of the statement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    ///
+    /// let input = "@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+    ///              _:art foaf:name \"Art Barstow\" ; foaf:nick \"Art\" .";
+    ///
+    /// let mut reader = TurtleParser::from_string(input.to_string());
+    /// let triples = reader.parse_with_lines().unwrap();
+    ///
+    /// assert_eq!(triples.len(), 2);
+    /// assert!(triples.iter().all(|(_, line)| *line == 2));
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with the Turtle standard.
+    ///
+    pub fn parse_with_lines(&mut self) -> Result<Vec<(Triple, usize)>> {
+        let mut graph = Graph::new(None);
+
+        self.triple_lines = Some(Vec::new());
+        let result = self.decode_into(&mut graph);
+        let triple_lines = self.triple_lines.take().unwrap_or_default();
+
+        result.map(|_| triple_lines)
+    }
+
+    /// Adds the triple to the graph and records its literal and whether it was parsed before.
+    ///
+    /// Triples whose predicate is not allowed by the options are dropped. Returns an error
+    /// if the maximum number of triples is exceeded or the triple contains blank nodes that
+    /// are forbidden by the options.
+    fn insert_triple(&mut self, graph: &mut Graph, triple: &Triple) -> Result<()> {
+        if !self.options.allows_predicate(triple.predicate()) {
+            return Ok(());
+        }
+
+        self.stats.add_triple();
+        self.options.check_triple_count(self.stats.triples())?;
+        self.options
+            .check_blank_nodes(triple, self.statement_line, self.statement_column)?;
+
+        if let Some(ref mut triple_lines) = self.triple_lines {
+            triple_lines.push((triple.clone(), self.statement_line));
+        }
+
+        if let Node::LiteralNode { literal, .. } = triple.object() {
+            self.stats.add_literal(literal);
+        }
+
+        if !graph.add_triple(triple) {
+            self.stats.add_duplicate_triple();
+        }
+
+        Ok(())
+    }
+
+    /// Returns the URI node if its scheme is allowed by the parser options.
+    fn checked_uri_node(&self, node: Node) -> Result<Node> {
+        self.options.check_node_scheme(&node)?;
+
+        Ok(node)
+    }
+
+    /// Parses the Turtle input and adds base URI, namespaces and triples to the provided graph.
+    ///
+    /// Namespaces and the base URI already defined in the graph can be used by the input.
+    pub(crate) fn decode_into(&mut self, graph: &mut Graph) -> Result<()> {
+        loop {
+            match self.lexer.peek_next_token() {
+                Ok(Token::Comment(_)) => {
+                    let _ = self.lexer.get_next_token();
+                    continue;
+                }
+                Ok(Token::EndOfInput) => return Ok(()),
+                Ok(Token::BaseDirective(_)) => {
+                    let base_uri = self.read_base_directive()?;
+                    self.options.check_scheme(&base_uri)?;
+                    graph.set_base_uri(&base_uri);
+                }
+                Ok(Token::PrefixDirective(_, _)) => {
+                    let namespace = self.read_prefix_directive()?;
+                    self.options.check_scheme(namespace.uri())?;
+                    graph.add_namespace(&namespace);
+                }
+                Ok(Token::Uri(_))
+                | Ok(Token::BlankNode(_))
+                | Ok(Token::QName(_, _))
+                | Ok(Token::CollectionStart)
+                | Ok(Token::BlankNodePropertyListStart) => {
+                    self.statement_line = self.lexer.token_line();
+                    self.statement_column = self.lexer.token_column();
+
+                    let triples = self.read_triples(graph)?;
+
+                    for triple in &triples {
+                        self.insert_triple(graph, triple)?;
+                    }
+                }
+                Ok(Token::TripleDelimiter) => {
+                    return Err(Error::new(
+                        ErrorType::UnexpectedToken,
+                        "Empty statement: expected Turtle subject instead of '.'",
+                    ))
+                }
+                Err(err) => match err.error_type() {
+                    &ErrorType::EndOfInput(_) => return Ok(()),
+                    _ => {
+                        return Err(Error::new(
+                            ErrorType::InvalidReaderInput,
+                            format!("Error while parsing Turtle syntax: {}", err),
+                        ))
+                    }
+                },
+                Ok(_) => {
+                    return Err(Error::new(
+                        ErrorType::InvalidToken,
+                        "Invalid token while parsing Turtle syntax.",
+                    ))
+                }
+            }
         }
     }
 
@@ -157,7 +357,10 @@ impl<R: Read> TurtleParser<R> {
                 Token::TripleDelimiter => Ok(Namespace::new(prefix, Uri::new(uri))),
                 _ => Err(Error::new(
                     ErrorType::InvalidReaderInput,
-                    "Turtle prefix directive does not end with '.'",
+                    format!(
+                        "Turtle prefix directive '@prefix {} <{}>' does not end with '.'",
+                        prefix, uri
+                    ),
                 )),
             },
             _ => Err(Error::new(
@@ -177,15 +380,17 @@ impl<R: Read> TurtleParser<R> {
     /// Get the next token and check if it is a valid subject and create a new subject node.
     fn read_subject(&mut self, graph: &mut Graph) -> Result<Node> {
         match self.lexer.get_next_token()? {
-            Token::BlankNode(id) => Ok(Node::BlankNode { id }),
+            Token::BlankNode(id) => Ok(Node::BlankNode {
+                id: self.blank_node_labels.label(id),
+            }),
             Token::QName(prefix, path) => {
                 let mut uri = graph.get_namespace_uri_by_prefix(&prefix)?.to_owned();
                 uri.append_resource_path(&path.replace(":", "/")); // adjust the QName path to URI path
-                Ok(Node::UriNode { uri })
+                self.checked_uri_node(Node::UriNode { uri })
             }
-            Token::Uri(uri) => Ok(graph.create_uri_node_str(&uri)),
+            Token::Uri(uri) => self.checked_uri_node(graph.create_uri_node_str(&uri)),
             Token::CollectionStart => self.read_collection(graph),
-            Token::UnlabeledBlankNodeStart => self.read_unlabeled_blank_node(graph),
+            Token::BlankNodePropertyListStart => self.read_unlabeled_blank_node(graph),
             _ => Err(Error::new(
                 ErrorType::InvalidToken,
                 "Invalid token for Turtle subject.",
@@ -207,7 +412,7 @@ impl<R: Read> TurtleParser<R> {
         loop {
             match self.lexer.get_next_token()? {
                 Token::TripleDelimiter => break,
-                Token::UnlabeledBlankNodeEnd => break,
+                Token::BlankNodePropertyListEnd => break,
                 Token::PredicateListDelimiter => {
                     let (predicate, object) = self.read_predicate_with_object(graph)?;
                     triples.push(Triple::new(subject, &predicate, &object));
@@ -232,16 +437,18 @@ impl<R: Read> TurtleParser<R> {
     fn read_predicate_with_object(&mut self, graph: &mut Graph) -> Result<(Node, Node)> {
         // read the predicate
         let predicate = match self.lexer.get_next_token()? {
-            Token::Uri(uri) => graph.create_uri_node_str(&uri),
+            Token::Uri(uri) => self.checked_uri_node(graph.create_uri_node_str(&uri))?,
             Token::KeywordA => Node::UriNode {
                 uri: RdfSyntaxDataTypes::A.to_uri(),
             },
             Token::QName(prefix, path) => {
                 let mut uri = graph.get_namespace_uri_by_prefix(&prefix)?.to_owned();
                 uri.append_resource_path(&path.replace(":", "/")); // adjust the QName path to URI path
-                Node::UriNode { uri }
+                self.checked_uri_node(Node::UriNode { uri })?
             }
-            Token::BlankNode(id) => Node::BlankNode { id },
+            Token::BlankNode(id) => Node::BlankNode {
+                id: self.blank_node_labels.label(id),
+            },
             _ => {
                 return Err(Error::new(
                     ErrorType::InvalidToken,
@@ -259,12 +466,14 @@ impl<R: Read> TurtleParser<R> {
     /// Get the next token and check if it is a valid object and create a new object node.
     fn read_object(&mut self, graph: &mut Graph) -> Result<Node> {
         match self.lexer.get_next_token()? {
-            Token::BlankNode(id) => Ok(Node::BlankNode { id }),
-            Token::Uri(uri) => Ok(graph.create_uri_node_str(&uri)),
+            Token::BlankNode(id) => Ok(Node::BlankNode {
+                id: self.blank_node_labels.label(id),
+            }),
+            Token::Uri(uri) => self.checked_uri_node(graph.create_uri_node_str(&uri)),
             Token::QName(prefix, path) => {
                 let mut uri = graph.get_namespace_uri_by_prefix(&prefix)?.to_owned();
                 uri.append_resource_path(&path.replace(":", "/")); // adjust the QName path to URI path
-                Ok(Node::UriNode { uri })
+                self.checked_uri_node(Node::UriNode { uri })
             }
             Token::LiteralWithLanguageSpecification(literal, lang) => Ok(Node::LiteralNode {
                 literal,
@@ -272,17 +481,36 @@ impl<R: Read> TurtleParser<R> {
                 language: Some(lang),
             }),
             Token::LiteralWithUrlDatatype(literal, datatype) => Ok(Node::LiteralNode {
-                literal,
+                literal: self.options.literal_form(literal, &datatype),
                 data_type: Some(Uri::new(datatype)),
                 language: None,
             }),
+            Token::LiteralWithQNameDatatype(literal, prefix, path) => {
+                let mut datatype = graph.get_namespace_uri_by_prefix(&prefix)?.to_owned();
+                datatype.append_resource_path(&path.replace(":", "/")); // adjust the QName path to URI path
+
+                Ok(Node::LiteralNode {
+                    literal: self.options.literal_form(literal, datatype.to_string()),
+                    data_type: Some(datatype),
+                    language: None,
+                })
+            }
             Token::Literal(literal) => Ok(Node::LiteralNode {
                 literal,
                 data_type: None,
                 language: None,
             }),
+            Token::IntegerLiteral(literal) => {
+                Ok(self.numeric_literal(literal, XmlDataTypes::Integer))
+            }
+            Token::DecimalLiteral(literal) => {
+                Ok(self.numeric_literal(literal, XmlDataTypes::Decimal))
+            }
+            Token::DoubleLiteral(literal) => {
+                Ok(self.numeric_literal(literal, XmlDataTypes::Double))
+            }
             Token::CollectionStart => self.read_collection(graph),
-            Token::UnlabeledBlankNodeStart => self.read_unlabeled_blank_node(graph),
+            Token::BlankNodePropertyListStart => self.read_unlabeled_blank_node(graph),
             _ => Err(Error::new(
                 ErrorType::InvalidToken,
                 "Invalid token for Turtle object.",
@@ -294,18 +522,42 @@ impl<R: Read> TurtleParser<R> {
     ///
     /// Returns the subject node and add all other nested nodes to the graph.
     fn read_unlabeled_blank_node(&mut self, graph: &mut Graph) -> Result<Node> {
-        let subject = graph.create_blank_node();
+        let subject = self.generate_blank_node(graph);
 
-        if self.lexer.peek_next_token()? == Token::UnlabeledBlankNodeEnd {
+        if self.lexer.peek_next_token()? == Token::BlankNodePropertyListEnd {
             let _ = self.lexer.get_next_token()?; // consume the token indicating the node end ']'
         } else {
             let triples = self.read_predicate_object_list(&subject, graph)?;
-            graph.add_triples(&triples);
+
+            for triple in &triples {
+                self.insert_triple(graph, triple)?;
+            }
         }
 
         Ok(subject)
     }
 
+    /// Creates the node of a numeric literal with the provided data type.
+    fn numeric_literal(&self, literal: String, data_type: XmlDataTypes) -> Node {
+        let data_type = data_type.to_uri();
+
+        Node::LiteralNode {
+            literal: self.options.literal_form(literal, data_type.to_string()),
+            data_type: Some(data_type),
+            language: None,
+        }
+    }
+
+    /// Creates a blank node for `[]` or a collection, prefixed as required by the options.
+    fn generate_blank_node(&self, graph: &mut Graph) -> Node {
+        match (&self.options.generated_blank_node_prefix, graph.create_blank_node()) {
+            (Some(prefix), Node::BlankNode { id }) => Node::BlankNode {
+                id: prefix.to_string() + &id,
+            },
+            (_, node) => node,
+        }
+    }
+
     /// Reads a collection and returns the collection start as node.
     ///
     /// The remaining elements are implicitly added to the graph.
@@ -320,28 +572,28 @@ impl<R: Read> TurtleParser<R> {
         }
 
         // for non-empty list generate blank node
-        let subject = graph.create_blank_node();
+        let subject = self.generate_blank_node(graph);
 
         let mut next_subject = subject.to_owned();
 
         loop {
-            let rest = graph.create_blank_node();
+            let rest = self.generate_blank_node(graph);
             let object = self.read_object(graph)?;
 
-            graph.add_triple(&Triple::new(
+            self.insert_triple(graph, &Triple::new(
                 &next_subject,
                 &Node::UriNode {
                     uri: RdfSyntaxDataTypes::ListFirst.to_uri(),
                 },
                 &object,
-            ));
+            ))?;
 
             // check if the rest of the list is nil
             if self.lexer.peek_next_token()? == Token::CollectionEnd {
                 let _ = self.lexer.get_next_token()?; // consume the token indicating the collection end ')'
 
                 // create list:nil node
-                graph.add_triple(&Triple::new(
+                self.insert_triple(graph, &Triple::new(
                     &next_subject,
                     &Node::UriNode {
                         uri: RdfSyntaxDataTypes::ListRest.to_uri(),
@@ -349,17 +601,17 @@ impl<R: Read> TurtleParser<R> {
                     &Node::UriNode {
                         uri: RdfSyntaxDataTypes::ListNil.to_uri(),
                     },
-                ));
+                ))?;
                 break; // stop further list evaluation
             } else {
                 // create node referring to the non-empty rest of the list
-                graph.add_triple(&Triple::new(
+                self.insert_triple(graph, &Triple::new(
                     &next_subject,
                     &Node::UriNode {
                         uri: RdfSyntaxDataTypes::ListRest.to_uri(),
                     },
                     &rest,
-                ));
+                ))?;
             }
 
             next_subject = rest;
@@ -371,9 +623,18 @@ impl<R: Read> TurtleParser<R> {
 
 #[cfg(test)]
 mod tests {
+    use crate::error::ErrorType;
+    use crate::graph::Graph;
+    use crate::node::Node;
+    use crate::reader::parser_options::ParserOptions;
     use crate::reader::rdf_parser::RdfParser;
     use crate::reader::turtle_parser::TurtleParser;
+    use crate::specs::xml_specs::XmlDataTypes;
+    use crate::triple::Triple;
     use crate::uri::Uri;
+    use crate::writer::rdf_writer::RdfWriter;
+    use crate::writer::turtle_writer::TurtleWriter;
+    use std::collections::HashSet;
 
     #[test]
     fn test_read_n_triples_as_turtle_from_string() {
@@ -386,10 +647,7 @@ mod tests {
 
         match reader.decode() {
             Ok(graph) => assert_eq!(graph.count(), 4),
-            Err(e) => {
-                println!("Err {}", e.to_string());
-                assert!(false)
-            }
+            Err(e) => panic!("Err {}", e),
         }
     }
 
@@ -415,10 +673,7 @@ mod tests {
                     &Some(Uri::new("http://example.org/".to_string()))
                 )
             }
-            Err(e) => {
-                println!("Err {}", e.to_string());
-                assert!(false)
-            }
+            Err(e) => panic!("Err {}", e),
         }
     }
 
@@ -447,10 +702,7 @@ mod tests {
                     &Some(Uri::new("http://example.org/".to_string()))
                 )
             }
-            Err(e) => {
-                println!("Err {}", e.to_string());
-                assert!(false)
-            }
+            Err(e) => panic!("Err {}", e),
         }
     }
 
@@ -464,10 +716,7 @@ mod tests {
                 graph.base_uri(),
                 &Some(Uri::new("http://example/".to_string()))
             ),
-            Err(e) => {
-                println!("Err {}", e.to_string());
-                assert!(false)
-            }
+            Err(e) => panic!("Err {}", e),
         }
     }
 
@@ -481,10 +730,7 @@ mod tests {
                 graph.base_uri(),
                 &Some(Uri::new("http://example/".to_string()))
             ),
-            Err(e) => {
-                println!("Err {}", e.to_string());
-                assert!(false)
-            }
+            Err(e) => panic!("Err {}", e),
         }
     }
 
@@ -495,10 +741,7 @@ mod tests {
 
         match reader.decode() {
             Ok(graph) => assert_eq!(graph.namespaces().len(), 1),
-            Err(e) => {
-                println!("Err {}", e.to_string());
-                assert!(false)
-            }
+            Err(e) => panic!("Err {}", e),
         }
     }
 
@@ -509,10 +752,7 @@ mod tests {
 
         match reader.decode() {
             Ok(graph) => assert_eq!(graph.namespaces().len(), 1),
-            Err(e) => {
-                println!("Err {}", e.to_string());
-                assert!(false)
-            }
+            Err(e) => panic!("Err {}", e),
         }
     }
 
@@ -525,10 +765,7 @@ mod tests {
 
         match reader.decode() {
             Ok(graph) => assert_eq!(graph.count(), 1),
-            Err(e) => {
-                println!("Err {}", e.to_string());
-                assert!(false)
-            }
+            Err(e) => panic!("Err {}", e),
         }
     }
 
@@ -540,10 +777,7 @@ mod tests {
 
         match reader.decode() {
             Ok(graph) => assert_eq!(graph.count(), 5),
-            Err(e) => {
-                println!("Err {}", e.to_string());
-                assert!(false)
-            }
+            Err(e) => panic!("Err {}", e),
         }
     }
 
@@ -555,10 +789,7 @@ mod tests {
 
         match reader.decode() {
             Ok(graph) => assert_eq!(graph.count(), 1),
-            Err(e) => {
-                println!("Err {}", e.to_string());
-                assert!(false)
-            }
+            Err(e) => panic!("Err {}", e),
         }
     }
 
@@ -570,10 +801,7 @@ mod tests {
 
         match reader.decode() {
             Ok(graph) => assert_eq!(graph.count(), 17),
-            Err(e) => {
-                println!("Err {}", e.to_string());
-                assert!(false)
-            }
+            Err(e) => panic!("Err {}", e),
         }
     }
 
@@ -585,10 +813,7 @@ mod tests {
 
         match reader.decode() {
             Ok(graph) => assert_eq!(graph.count(), 1),
-            Err(e) => {
-                println!("Err {}", e.to_string());
-                assert!(false)
-            }
+            Err(e) => panic!("Err {}", e),
         }
     }
 
@@ -604,10 +829,7 @@ mod tests {
 
         match reader.decode() {
             Ok(graph) => assert_eq!(graph.count(), 7),
-            Err(e) => {
-                println!("Err {}", e.to_string());
-                assert!(false)
-            }
+            Err(e) => panic!("Err {}", e),
         }
     }
     #[test]
@@ -627,11 +849,512 @@ mod tests {
                     Some(t) => assert_eq!(
                         &graph.create_uri_node(&Uri::new("https://example.com/alice/card#this".to_string()))
                         ,t.subject()),
-                    None => assert!(false)
+                    None => panic!("No triple found")
                 }
 
                 }    ,
-            Err(e) => panic!("Err {}", e.to_string())
+            Err(e) => panic!("Err {}", e)
         }
     }
+
+    #[test]
+    fn test_read_triple_with_trailing_comment_from_string() {
+        let input = "<http://example.org/s> <http://example.org/p> <http://example.org/o> . # note
+                 <http://example.org/s> <http://example.org/p> \"o\" . # another note";
+
+        let mut reader = TurtleParser::from_string(input.to_string());
+
+        match reader.decode() {
+            Ok(graph) => assert_eq!(graph.count(), 2),
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_read_from_multiple_readers() {
+        let first = "<http://example.org/s> <http://example.org/p> _:b .";
+        let second = "_:b <http://example.org/p> \"o\" .";
+
+        let mut reader = TurtleParser::from_readers(vec![first.as_bytes(), second.as_bytes()]);
+
+        match reader.decode() {
+            Ok(graph) => {
+                let blank = Node::BlankNode {
+                    id: "b".to_string(),
+                };
+
+                assert_eq!(graph.count(), 2);
+                assert_eq!(graph.get_triples_with_subject(&blank).len(), 1);
+                assert_eq!(graph.get_triples_with_object(&blank).len(), 1);
+            }
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_count_duplicate_triples() {
+        let input = "<http://example.org/s> <http://example.org/p> \"o\" .
+                 <http://example.org/s> <http://example.org/p> <http://example.org/o> .
+                 <http://example.org/s> <http://example.org/p> \"o\" .";
+
+        let mut reader = TurtleParser::from_string(input.to_string());
+
+        match reader.decode() {
+            Ok(_) => assert_eq!(reader.stats().duplicate_triples(), 1),
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_canonicalize_numeric_literals() {
+        let input = "<http://example.org/s> <http://example.org/p> 01 .
+                 <http://example.org/s> <http://example.org/p> \"1\"^^<http://www.w3.org/2001/XMLSchema#integer> .
+                 <http://example.org/s> <http://example.org/p> \"01\"^^<http://www.w3.org/2001/XMLSchema#string> .";
+
+        let options = ParserOptions {
+            canonicalize_numeric_literals: true,
+            ..Default::default()
+        };
+
+        let mut reader = TurtleParser::with_options(input.as_bytes(), options);
+
+        match reader.decode() {
+            Ok(graph) => {
+                let distinct: HashSet<&Triple> = graph.triples_iter().collect();
+
+                // the integers collapse while the string keeps its leading zero
+                assert_eq!(distinct.len(), 2);
+                assert_eq!(reader.stats().duplicate_triples(), 1);
+            }
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_collect_literal_lengths() {
+        let input = "@prefix ex: <http://example.org/> .
+                 ex:s ex:p \"a\" , \"abcdefgh\"@en ;
+                      ex:q ex:o , 42 .";
+
+        let mut reader = TurtleParser::from_string(input.to_string());
+
+        match reader.decode() {
+            Ok(_) => {
+                assert_eq!(reader.stats().max_literal_length(), 8);
+                assert_eq!(reader.stats().literals(), 3);
+                assert_eq!(reader.stats().average_literal_length(), Some(11.0 / 3.0));
+            }
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_reject_prefix_directive_without_delimiter() {
+        let input = "@prefix ex: <http://ex/> <s> <p> <o> .";
+
+        let mut reader = TurtleParser::from_string(input.to_string());
+
+        match reader.decode() {
+            Ok(_) => panic!("Prefix directive without '.' must be rejected"),
+            Err(e) => {
+                assert!(matches!(e.error_type(), ErrorType::InvalidReaderInput));
+                assert!(e.to_string().contains("@prefix ex: <http://ex/>"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_uri_with_query_and_fragment() {
+        let input = "@prefix ex: <http://ex/path?x=1&y=2#> .
+                 <http://ex/path?x=1&y=2#frag> ex:p <http://ex/?q#> . # comment";
+
+        let mut reader = TurtleParser::from_string(input.to_string());
+
+        match reader.decode() {
+            Ok(graph) => {
+                let subject =
+                    graph.create_uri_node(&Uri::new("http://ex/path?x=1&y=2#frag".to_string()));
+
+                assert_eq!(graph.count(), 1);
+                assert_eq!(graph.get_triples_with_subject(&subject).len(), 1);
+            }
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_renumber_blank_nodes() {
+        let input = "_:x <http://example.org/p> _:y .
+                 <http://example.org/s> <http://example.org/p> _:x .";
+
+        let options = ParserOptions {
+            renumber_blank_nodes: true,
+            ..Default::default()
+        };
+
+        let mut reader = TurtleParser::with_options(input.as_bytes(), options);
+
+        match reader.decode() {
+            Ok(graph) => {
+                let x = Node::BlankNode {
+                    id: "b0".to_string(),
+                };
+
+                assert_eq!(graph.get_triples_with_subject(&x).len(), 1);
+                assert_eq!(graph.get_triples_with_object(&x).len(), 1);
+                assert_eq!(
+                    graph.get_triples_with_subject(&x)[0].object(),
+                    &Node::BlankNode {
+                        id: "b1".to_string()
+                    }
+                );
+            }
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_round_trip_backspace_and_form_feed() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_blank_node();
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let object = graph.create_literal_node("back\u{8}space form\u{c}feed".to_string());
+        graph.add_triple(&Triple::new(&subject, &predicate, &object));
+
+        let output = TurtleWriter::new(graph.namespaces()).write_to_string(&graph).unwrap();
+
+        assert!(output.contains("back\\bspace form\\ffeed"));
+
+        let mut reader = TurtleParser::from_string(output);
+
+        match reader.decode() {
+            Ok(parsed) => assert_eq!(parsed.triples_iter().next().unwrap().object(), &object),
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_expand_qname_datatype() {
+        let input = "@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+                 <http://example.org/s> <http://example.org/p> \"42\"^^xsd:integer .";
+
+        let mut reader = TurtleParser::from_string(input.to_string());
+
+        match reader.decode() {
+            Ok(graph) => assert_eq!(
+                graph.triples_iter().next().unwrap().object(),
+                &Node::LiteralNode {
+                    literal: "42".to_string(),
+                    data_type: Some(XmlDataTypes::Integer.to_uri()),
+                    language: None,
+                }
+            ),
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_reject_qname_datatype_with_unknown_prefix() {
+        let input = "<http://example.org/s> <http://example.org/p> \"42\"^^xsd:integer .";
+
+        let mut reader = TurtleParser::from_string(input.to_string());
+
+        match reader.decode() {
+            Ok(_) => panic!("Unknown datatype prefix must be rejected"),
+            Err(e) => assert!(matches!(e.error_type(), ErrorType::InvalidNamespace)),
+        }
+    }
+
+    #[test]
+    fn test_forbid_blank_nodes() {
+        let options = ParserOptions {
+            forbid_blank_nodes: true,
+            ..Default::default()
+        };
+
+        let valid = "<http://example.org/s> <http://example.org/p> ( ) .";
+        let mut reader = TurtleParser::with_options(valid.as_bytes(), options.clone());
+        assert!(reader.decode().is_ok());
+
+        for input in &[
+            "_:b <http://example.org/p> \"o\" .",
+            "<http://example.org/s> <http://example.org/p> [ ] .",
+            "<http://example.org/s> <http://example.org/p> ( 1 ) .",
+            "<http://example.org/s> <http://example.org/p> \"o\" .\n  _:b <http://example.org/p> \"o\" .",
+        ] {
+            let mut reader = TurtleParser::with_options(input.as_bytes(), options.clone());
+
+            match reader.decode() {
+                Ok(_) => panic!("Blank node must be rejected: {}", input),
+                Err(e) => {
+                    assert!(matches!(e.error_type(), ErrorType::InvalidReaderInput));
+
+                    let location = if input.contains('\n') {
+                        "at line 2, column 3."
+                    } else {
+                        "at line 1, column 1."
+                    };
+
+                    assert!(e.to_string().ends_with(location), "{}", e);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_reject_empty_statements() {
+        for input in &[
+            "<http://example.org/s> <http://example.org/p> <http://example.org/o> . .",
+            ". <http://example.org/s> <http://example.org/p> <http://example.org/o> .",
+        ] {
+            let mut reader = TurtleParser::from_string(input.to_string());
+
+            match reader.decode() {
+                Ok(_) => panic!("Empty statement must be rejected: {}", input),
+                Err(e) => assert!(matches!(e.error_type(), ErrorType::UnexpectedToken)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_with_lines() {
+        let input = "@prefix ex: <http://example.org/> .
+                 ex:s ex:p \"1\" , \"2\" ; ex:q [ ex:r \"3\" ] .
+                 ex:t ex:p
+                     \"4\" .";
+
+        let mut reader = TurtleParser::from_string(input.to_string());
+
+        match reader.parse_with_lines() {
+            Ok(triples) => {
+                let lines: Vec<usize> = triples.iter().map(|(_, line)| *line).collect();
+                assert_eq!(lines, vec![2, 2, 2, 2, 3]);
+            }
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_round_trip_single_quoted_literal() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_blank_node();
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let object = graph.create_literal_node("say \"hi\"".to_string());
+        graph.add_triple(&Triple::new(&subject, &predicate, &object));
+
+        let output = TurtleWriter::new(graph.namespaces()).write_to_string(&graph).unwrap();
+
+        assert!(output.contains("'say \"hi\"'"));
+
+        let mut reader = TurtleParser::from_string(output);
+
+        match reader.decode() {
+            Ok(parsed) => assert_eq!(parsed.triples_iter().next().unwrap().object(), &object),
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_read_literals_with_escaped_quotes() {
+        let input = "<http://example.org/s> <http://example.org/p> \"say \\\"hi\\\"\" , 'it\\'s' .";
+
+        let mut reader = TurtleParser::from_string(input.to_string());
+
+        match reader.decode() {
+            Ok(graph) => {
+                let objects: Vec<&Node> = graph.triples_iter().map(|t| t.object()).collect();
+
+                assert_eq!(objects[0], &graph.create_literal_node("say \"hi\"".to_string()));
+                assert_eq!(objects[1], &graph.create_literal_node("it's".to_string()));
+            }
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_prefix_generated_blank_nodes() {
+        let input = "_:auto0 <http://example.org/p> [ <http://example.org/q> ( 1 ) ] .";
+
+        let options = ParserOptions {
+            generated_blank_node_prefix: Some("g_".to_string()),
+            ..Default::default()
+        };
+
+        let mut reader = TurtleParser::with_options(input.as_bytes(), options);
+
+        match reader.decode() {
+            Ok(graph) => {
+                let blank_node = |id: &str| Node::BlankNode { id: id.to_string() };
+
+                assert_eq!(graph.get_triples_with_subject(&blank_node("auto0")).len(), 1);
+                assert_eq!(graph.get_triples_with_subject(&blank_node("g_auto0")).len(), 1);
+                assert_eq!(graph.get_triples_with_subject(&blank_node("g_auto1")).len(), 2);
+            }
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_allowed_schemes() {
+        let options = ParserOptions {
+            allowed_schemes: Some(vec!["https".to_string(), "urn".to_string()]),
+            ..Default::default()
+        };
+
+        let allowed = "<https://example.org/s> a ( <urn:isbn:0451450523> 42 ) .";
+        let mut reader = TurtleParser::with_options(allowed.as_bytes(), options.clone());
+
+        match reader.decode() {
+            Ok(graph) => assert_eq!(graph.count(), 5),
+            Err(e) => panic!("Err {}", e),
+        }
+
+        for rejected in &[
+            "<https://example.org/s> <https://example.org/p> <file:///etc/passwd> .",
+            "<https://example.org/s> <https://example.org/p> [ <https://example.org/p> <file:///a> ] .",
+            "@prefix ex: <file:///etc/> . ex:passwd <https://example.org/p> \"o\" .",
+            "<javascript:alert(1)> <https://example.org/p> \"o\" .",
+        ] {
+            let mut reader = TurtleParser::with_options(rejected.as_bytes(), options.clone());
+
+            match reader.decode() {
+                Ok(_) => panic!("URI scheme must be rejected: {}", rejected),
+                Err(e) => assert!(matches!(e.error_type(), ErrorType::InvalidReaderInput)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_input_without_final_newline() {
+        for input in &[
+            "<http://example.org/s> <http://example.org/p> <http://example.org/o> .",
+            "<http://example.org/s> <http://example.org/p> <http://example.org/o> . # comment",
+        ] {
+            let mut reader = TurtleParser::from_string(input.to_string());
+
+            match reader.decode() {
+                Ok(graph) => assert_eq!(graph.count(), 1),
+                Err(e) => panic!("Err {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_keyword_a_and_boolean_literals() {
+        let input = "@prefix a: <http://example.org/> .
+                     a:s a a:Type ; a:flag true , false .";
+
+        let mut reader = TurtleParser::from_string(input.to_string());
+
+        match reader.decode() {
+            Ok(graph) => {
+                let boolean = XmlDataTypes::Boolean.to_uri();
+                let objects: Vec<&Node> = graph.triples_iter().map(|t| t.object()).collect();
+
+                assert_eq!(graph.count(), 3);
+                assert_eq!(
+                    objects[1],
+                    &graph.create_literal_node_with_data_type("true".to_string(), &boolean)
+                );
+                assert_eq!(
+                    objects[2],
+                    &graph.create_literal_node_with_data_type("false".to_string(), &boolean)
+                );
+            }
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_predicate_allowlist() {
+        let input = "@prefix ex: <http://example.org/> .
+                     ex:s ex:a ex:o ; ex:b [ ex:a \"nested\" ; ex:c ( 1 2 ) ] .
+                     _:x ex:c \"skipped\" , ex:o ; ex:a _:y .";
+
+        let allowlist: HashSet<String> = ["http://example.org/a", "http://example.org/c"]
+            .iter()
+            .map(|p| p.to_string())
+            .collect();
+
+        let options = ParserOptions {
+            renumber_blank_nodes: true,
+            ..Default::default()
+        };
+        let filtered_options = ParserOptions {
+            predicate_allowlist: Some(allowlist.clone()),
+            ..options.clone()
+        };
+
+        let full = TurtleParser::with_options(input.as_bytes(), options).decode().unwrap();
+        let filtered = TurtleParser::with_options(input.as_bytes(), filtered_options)
+            .decode()
+            .unwrap();
+
+        let expected: Vec<&Triple> = full
+            .triples_iter()
+            .filter(|t| match t.predicate() {
+                Node::UriNode { uri } => allowlist.contains(uri.to_string()),
+                _ => false,
+            })
+            .collect();
+
+        assert_eq!(expected.len(), 6);
+        assert_eq!(filtered.triples_iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_max_triples() {
+        let input = "<http://example.org/s> <http://example.org/p> \"a\" .
+                     <http://example.org/s> <http://example.org/p> \"b\" .
+                     <http://example.org/s> <http://example.org/p> \"c\" .";
+
+        let limit = |max_triples| ParserOptions {
+            max_triples: Some(max_triples),
+            ..Default::default()
+        };
+
+        let mut reader = TurtleParser::with_options(input.as_bytes(), limit(3));
+
+        match reader.decode() {
+            Ok(graph) => assert_eq!(graph.count(), 3),
+            Err(e) => panic!("Err {}", e),
+        }
+        assert_eq!(reader.stats().triples(), 3);
+
+        match TurtleParser::with_options(input.as_bytes(), limit(2)).decode() {
+            Ok(_) => panic!("Input exceeding the maximum number of triples must be rejected"),
+            Err(e) => {
+                assert!(matches!(e.error_type(), ErrorType::InvalidReaderInput));
+                assert!(e.to_string().contains("maximum of 2 triples"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_statement_directly_after_directive() {
+        let input = "@prefix ex: <http://example.org/> .ex:s ex:p ex:o .
+                     PREFIX b: <http://b.example/> .b:s a \"x\" .
+                     @base <http://base.example/> .<s> ex:p 1 .";
+
+        let mut reader = TurtleParser::from_string(input.to_string());
+
+        let graph = match reader.decode() {
+            Ok(graph) => graph,
+            Err(e) => panic!("Err {}", e),
+        };
+
+        let subjects: Vec<String> = graph
+            .triples_iter()
+            .map(|triple| match triple.subject() {
+                Node::UriNode { uri } => uri.to_string().to_string(),
+                node => panic!("Unexpected subject {:?}", node),
+            })
+            .collect();
+
+        assert_eq!(graph.namespaces().len(), 2);
+        assert_eq!(graph.base_uri(), &Some(Uri::new("http://base.example/".to_string())));
+        assert_eq!(subjects, vec!["http://example.org/s", "http://b.example/s", "s"]);
+    }
 }
@@ -4,6 +4,7 @@ use crate::node::Node;
 pub trait Pattern {}
 
 /// Describes a group of triples the SPARQL `WHERE` clause should match.
+#[derive(Default)]
 pub struct GroupPattern {
     patterns: Vec<Box<dyn Pattern>>,
     is_union: bool,
@@ -87,6 +88,31 @@ impl TriplePattern {
             is_union: false,
         }
     }
+
+    /// Returns the subject pattern.
+    pub fn subject(&self) -> &NodePattern {
+        &self.subject
+    }
+
+    /// Returns the predicate pattern.
+    pub fn predicate(&self) -> &NodePattern {
+        &self.predicate
+    }
+
+    /// Returns the object pattern.
+    pub fn object(&self) -> &NodePattern {
+        &self.object
+    }
+
+    /// Returns `true` if the triple pattern is preceded by `UNION`.
+    pub fn is_union(&self) -> bool {
+        self.is_union
+    }
+
+    /// Returns `true` if the triple pattern is preceded by `OPTIONAL`.
+    pub fn is_optional(&self) -> bool {
+        self.is_optional
+    }
 }
 
 /// Describes nodes in a `TriplePattern` which can either be variables or nodes with specific values.
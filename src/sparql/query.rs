@@ -40,7 +40,7 @@ impl SparqlQuery {
     /// Constructor of `SparqlQuery`.
     pub fn new(query_type: SparqlQueryType) -> SparqlQuery {
         SparqlQuery {
-            query_type: query_type,
+            query_type,
             base_uri: None,
             variables: Vec::new(),
             patterns: Vec::new(),
@@ -75,14 +75,19 @@ impl SparqlQuery {
         &self.variables
     }
 
-    /// Get the query patterns in the `WHERE` expression.
-    ///
-    /// todo
-    ///
+    // Get the query patterns in the `WHERE` expression.
+    //
+    // todo
+    //
     //  pub fn get_query_patterns(&self) -> &Vec<Pattern> {
     //    &self.patterns
     //  }
 
+    /// Returns the base URI denoted in the query.
+    pub fn get_base_uri(&self) -> &Option<Uri> {
+        &self.base_uri
+    }
+
     /// Returns the URI of a namespace with the provided prefix.
     ///
     /// # Examples
@@ -1,4 +1,5 @@
 use crate::uri::Uri;
+use std::fmt;
 
 /// RDF Schema data types and vocabulary.
 pub enum RdfSyntaxDataTypes {
@@ -13,16 +14,19 @@ impl RdfSyntaxDataTypes {
     pub fn to_uri(&self) -> Uri {
         Uri::new(self.to_string())
     }
+}
 
-    /// Returns a specific data type as string.
-    pub fn to_string(&self) -> String {
+impl fmt::Display for RdfSyntaxDataTypes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let schema_name = "http://www.w3.org/1999/02/22-rdf-syntax-ns#".to_string();
 
-        match *self {
+        let data_type = match *self {
             RdfSyntaxDataTypes::A => schema_name + "type",
             RdfSyntaxDataTypes::ListFirst => schema_name + "first",
             RdfSyntaxDataTypes::ListRest => schema_name + "rest",
             RdfSyntaxDataTypes::ListNil => schema_name + "nil",
-        }
+        };
+
+        f.write_str(&data_type)
     }
 }
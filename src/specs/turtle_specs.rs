@@ -1,5 +1,7 @@
+use crate::error::{Error, ErrorType};
 use crate::specs::xml_specs::XmlDataTypes;
 use crate::uri::Uri;
+use crate::Result;
 
 /// Contains specifications for validating turtle syntax.
 pub struct TurtleSpecs {}
@@ -54,10 +56,7 @@ impl TurtleSpecs {
     /// assert_eq!(TurtleSpecs::is_double_literal(&"a".to_string()), false);
     /// ```
     pub fn is_double_literal(literal: &str) -> bool {
-        match literal.parse::<f64>() {
-            Ok(_) => true,
-            Err(_) => false,
-        }
+        literal.parse::<f64>().is_ok()
     }
 
     /// Checks if the provided literal is an integer.
@@ -71,9 +70,53 @@ impl TurtleSpecs {
     /// assert_eq!(TurtleSpecs::is_integer_literal(&"3.0".to_string()), false);
     /// ```
     pub fn is_integer_literal(literal: &str) -> bool {
-        match literal.parse::<i64>() {
-            Ok(_) => true,
-            Err(_) => false,
+        literal.parse::<i64>().is_ok()
+    }
+
+    /// Checks if the provided literal is an integer, decimal or double according to the
+    /// `INTEGER`, `DECIMAL` and `DOUBLE` productions of the Turtle grammar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::turtle_specs::TurtleSpecs;
+    ///
+    /// assert!(TurtleSpecs::is_numeric_literal("-42"));
+    /// assert!(TurtleSpecs::is_numeric_literal(".5"));
+    /// assert!(TurtleSpecs::is_numeric_literal("6.022e+23"));
+    /// assert!(!TurtleSpecs::is_numeric_literal("5."));
+    /// assert!(!TurtleSpecs::is_numeric_literal("1e"));
+    /// assert!(!TurtleSpecs::is_numeric_literal("NaN"));
+    /// ```
+    pub fn is_numeric_literal(literal: &str) -> bool {
+        let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+        let unsigned = literal.strip_prefix(['+', '-']).unwrap_or(literal);
+
+        let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+            Some(e) => (&unsigned[..e], Some(&unsigned[e + 1..])),
+            None => (unsigned, None),
+        };
+
+        let (integer, fraction) = match mantissa.find('.') {
+            Some(point) => (&mantissa[..point], &mantissa[point + 1..]),
+            None => (mantissa, ""),
+        };
+
+        if !is_digits(integer) || !is_digits(fraction) {
+            return false;
+        }
+
+        match exponent {
+            // decimals require digits after the point, integers at least one digit
+            None if mantissa.contains('.') => !fraction.is_empty(),
+            None => !integer.is_empty(),
+            Some(exponent) => {
+                let exponent = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+
+                (!integer.is_empty() || !fraction.is_empty())
+                    && !exponent.is_empty()
+                    && is_digits(exponent)
+            }
         }
     }
 
@@ -89,9 +132,205 @@ impl TurtleSpecs {
     /// assert_eq!(TurtleSpecs::is_boolean_literal(&"1".to_string()), false);
     /// ```
     pub fn is_boolean_literal(literal: &str) -> bool {
-        match literal.parse::<bool>() {
-            Ok(_) => true,
-            Err(_) => false,
+        literal.parse::<bool>().is_ok()
+    }
+
+    /// Checks if the provided string is a prefixed name `prefix:local` and splits it into
+    /// prefix and local name.
+    ///
+    /// The prefix must conform with `PN_PREFIX` and the local name with `PN_LOCAL` of the
+    /// Turtle grammar. Both may be empty. The prefix is not expanded, escape sequences of
+    /// the local name are kept as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::turtle_specs::TurtleSpecs;
+    ///
+    /// assert_eq!(
+    ///     TurtleSpecs::validate_prefixed_name("foaf:name").unwrap(),
+    ///     ("foaf".to_string(), "name".to_string())
+    /// );
+    /// assert!(TurtleSpecs::validate_prefixed_name("foaf:na^me").is_err());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The string does not contain `:`.
+    /// - The prefix or the local name contains characters that are not allowed.
+    ///
+    pub fn validate_prefixed_name(name: &str) -> Result<(String, String)> {
+        let (prefix, local) = match name.find(':') {
+            Some(index) => (&name[..index], &name[index + 1..]),
+            None => {
+                return Err(Error::new(
+                    ErrorType::InvalidToken,
+                    "Prefixed name does not contain ':': ".to_string() + name,
+                ))
+            }
+        };
+
+        if !TurtleSpecs::is_prefix(prefix) {
+            return Err(Error::new(
+                ErrorType::InvalidToken,
+                "Invalid prefix of prefixed name: ".to_string() + name,
+            ));
+        }
+
+        if !TurtleSpecs::is_local_name(local) {
+            return Err(Error::new(
+                ErrorType::InvalidToken,
+                "Invalid local name of prefixed name: ".to_string() + name,
+            ));
+        }
+
+        Ok((prefix.to_string(), local.to_string()))
+    }
+
+    /// Checks if the provided string conforms with `PN_PREFIX`, or is empty.
+    fn is_prefix(prefix: &str) -> bool {
+        let chars: Vec<char> = prefix.chars().collect();
+
+        match (chars.first(), chars.last()) {
+            (None, _) => true,
+            (Some(&first), Some(&last)) => {
+                TurtleSpecs::is_pn_chars_base(first)
+                    && TurtleSpecs::is_pn_chars(last)
+                    && chars[1..]
+                        .iter()
+                        .all(|&c| TurtleSpecs::is_pn_chars(c) || c == '.')
+            }
+            _ => false,
+        }
+    }
+
+    /// Checks if the provided string conforms with `PN_LOCAL`, or is empty.
+    fn is_local_name(local: &str) -> bool {
+        let chars: Vec<char> = local.chars().collect();
+        let mut index = 0;
+
+        while index < chars.len() {
+            let c = chars[index];
+            let is_first = index == 0;
+            let is_last = index == chars.len() - 1;
+
+            match c {
+                '%' => {
+                    let is_hex = |i: usize| chars.get(i).is_some_and(|c| c.is_ascii_hexdigit());
+
+                    if !is_hex(index + 1) || !is_hex(index + 2) {
+                        return false;
+                    }
+
+                    index += 3;
+                    continue;
+                }
+                '\\' => {
+                    match chars.get(index + 1) {
+                        Some(&escaped) if "_~.-!$&'()*+,;=/?#@%".contains(escaped) => {}
+                        _ => return false,
+                    }
+
+                    index += 2;
+                    continue;
+                }
+                ':' => {}
+                '.' if !is_first && !is_last => {}
+                c if is_first && (TurtleSpecs::is_pn_chars_u(c) || c.is_ascii_digit()) => {}
+                c if !is_first && TurtleSpecs::is_pn_chars(c) => {}
+                _ => return false,
+            }
+
+            index += 1;
+        }
+
+        true
+    }
+
+    /// Checks if the provided character conforms with `PN_CHARS_BASE`.
+    fn is_pn_chars_base(c: char) -> bool {
+        matches!(c,
+            'A'..='Z'
+            | 'a'..='z'
+            | '\u{C0}'..='\u{D6}'
+            | '\u{D8}'..='\u{F6}'
+            | '\u{F8}'..='\u{2FF}'
+            | '\u{370}'..='\u{37D}'
+            | '\u{37F}'..='\u{1FFF}'
+            | '\u{200C}'..='\u{200D}'
+            | '\u{2070}'..='\u{218F}'
+            | '\u{2C00}'..='\u{2FEF}'
+            | '\u{3001}'..='\u{D7FF}'
+            | '\u{F900}'..='\u{FDCF}'
+            | '\u{FDF0}'..='\u{FFFD}'
+            | '\u{10000}'..='\u{EFFFF}'
+        )
+    }
+
+    /// Checks if the provided character conforms with `PN_CHARS_U`.
+    fn is_pn_chars_u(c: char) -> bool {
+        TurtleSpecs::is_pn_chars_base(c) || c == '_'
+    }
+
+    /// Checks if the provided character conforms with `PN_CHARS`.
+    fn is_pn_chars(c: char) -> bool {
+        TurtleSpecs::is_pn_chars_u(c)
+            || matches!(c,
+                '-' | '0'..='9' | '\u{B7}' | '\u{300}'..='\u{36F}' | '\u{203F}'..='\u{2040}'
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ErrorType;
+    use crate::specs::turtle_specs::TurtleSpecs;
+
+    #[test]
+    fn test_validate_prefixed_name() {
+        for (name, prefix, local) in &[
+            ("foaf:name", "foaf", "name"),
+            ("ex.v1:a.b:c", "ex.v1", "a.b:c"),
+            ("ex:0_%2F\\~", "ex", "0_%2F\\~"),
+            ("ex:", "ex", ""),
+            ("café:société", "café", "société"),
+        ] {
+            assert_eq!(
+                TurtleSpecs::validate_prefixed_name(name).unwrap(),
+                (prefix.to_string(), local.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_prefixed_name_with_empty_prefix() {
+        assert_eq!(
+            TurtleSpecs::validate_prefixed_name(":name").unwrap(),
+            ("".to_string(), "name".to_string())
+        );
+        assert_eq!(
+            TurtleSpecs::validate_prefixed_name(":").unwrap(),
+            ("".to_string(), "".to_string())
+        );
+    }
+
+    #[test]
+    fn test_reject_invalid_prefixed_name() {
+        for name in &[
+            "name",
+            "ex:na^me",
+            "ex:name.",
+            "ex:-name",
+            "ex:%2",
+            "ex:\\a",
+            "1ex:name",
+            "ex.:name",
+            "e x:name",
+        ] {
+            match TurtleSpecs::validate_prefixed_name(name) {
+                Ok(parts) => panic!("Prefixed name must be rejected: {} {:?}", name, parts),
+                Err(e) => assert!(matches!(e.error_type(), ErrorType::InvalidToken)),
+            }
         }
     }
 }
@@ -1,4 +1,6 @@
 use crate::uri::Uri;
+use std::convert::TryFrom;
+use std::fmt;
 
 /// XML schema data types.
 pub enum XmlDataTypes {
@@ -7,10 +9,14 @@ pub enum XmlDataTypes {
     Double,
     Boolean,
     Date,
+    Time,
     Long,
     UnsignedLong,
     Int,
     Integer,
+    AnyUri,
+    NormalizedString,
+    Token,
 }
 
 impl XmlDataTypes {
@@ -19,20 +25,394 @@ impl XmlDataTypes {
         Uri::new(self.to_string())
     }
 
-    /// Returns a specific data type as string.
-    pub fn to_string(&self) -> String {
+    /// Returns the canonical lexical form of a numeric literal with the provided data type URI.
+    ///
+    /// Integers lose their leading zeros and `+` sign, decimals are written with exactly one
+    /// digit on each side of the point if there are no other digits and doubles and floats
+    /// use a mantissa with a single leading digit followed by an exponent.
+    ///
+    /// Returns `None` if the data type is not numeric or the lexical form is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    ///
+    /// let integer = XmlDataTypes::Integer.to_string();
+    /// let double = XmlDataTypes::Double.to_string();
+    ///
+    /// assert_eq!(XmlDataTypes::canonical_numeric_form(&integer, "+007"), Some("7".to_string()));
+    /// assert_eq!(XmlDataTypes::canonical_numeric_form(&double, "120"), Some("1.2E2".to_string()));
+    /// assert_eq!(XmlDataTypes::canonical_numeric_form(&XmlDataTypes::String.to_string(), "01"), None);
+    /// ```
+    pub fn canonical_numeric_form(data_type: &str, literal: &str) -> Option<String> {
+        let local_name = data_type.strip_prefix("http://www.w3.org/2001/XMLSchema#")?;
+        let literal = literal.trim();
+
+        match local_name {
+            "integer" | "nonPositiveInteger" | "negativeInteger" | "long" | "int" | "short"
+            | "byte" | "nonNegativeInteger" | "unsignedLong" | "unsignedInt" | "unsignedShort"
+            | "unsignedByte" | "positiveInteger" => canonical_integer(literal),
+            "decimal" => canonical_decimal(literal),
+            "double" | "float" => canonical_double(literal),
+            _ => None,
+        }
+    }
+
+    /// Returns the whitespace normalized form of a literal with the provided data type URI.
+    ///
+    /// Tabs, line feeds and carriage returns of `xsd:normalizedString` literals are replaced by
+    /// spaces. `xsd:token` literals are additionally trimmed and runs of spaces are collapsed
+    /// to a single space.
+    ///
+    /// Returns `None` if the data type is neither `xsd:normalizedString` nor `xsd:token`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    ///
+    /// let normalized_string = XmlDataTypes::NormalizedString.to_string();
+    /// let token = XmlDataTypes::Token.to_string();
+    ///
+    /// assert_eq!(XmlDataTypes::normalized_whitespace_form(&normalized_string, " a\tb "), Some(" a b ".to_string()));
+    /// assert_eq!(XmlDataTypes::normalized_whitespace_form(&token, "  a \n b "), Some("a b".to_string()));
+    /// assert_eq!(XmlDataTypes::normalized_whitespace_form(&XmlDataTypes::String.to_string(), " a "), None);
+    /// ```
+    pub fn normalized_whitespace_form(data_type: &str, literal: &str) -> Option<String> {
+        let replaced = || literal.replace(['\t', '\n', '\r'], " ");
+
+        if data_type == XmlDataTypes::NormalizedString.to_string() {
+            Some(replaced())
+        } else if data_type == XmlDataTypes::Token.to_string() {
+            let replaced = replaced();
+            let parts: Vec<&str> = replaced.split(' ').filter(|part| !part.is_empty()).collect();
+
+            Some(parts.join(" "))
+        } else {
+            None
+        }
+    }
+}
+
+/// Value of an `xsd:date` literal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct XmlDate {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+
+    /// Timezone offset in minutes, `None` if no timezone is specified.
+    pub timezone: Option<i16>,
+}
+
+impl XmlDate {
+    /// Parses the lexical form of an `xsd:date`, e.g. `2023-06-15` or `2023-06-15+02:00`.
+    ///
+    /// Returns `None` if the lexical form is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::xml_specs::XmlDate;
+    ///
+    /// let date = XmlDate::parse("2023-06-15Z").unwrap();
+    ///
+    /// assert_eq!((date.year, date.month, date.day, date.timezone), (2023, 6, 15, Some(0)));
+    /// assert!(XmlDate::parse("2023-02-30").is_none());
+    /// assert!(XmlDate::parse("2147483648-01-01").is_none());
+    /// ```
+    pub fn parse(lexical: &str) -> Option<XmlDate> {
+        if !lexical.is_ascii() {
+            return None;
+        }
+
+        // the year can be negative and have more than four digits
+        let (sign, unsigned) = match lexical.strip_prefix('-') {
+            Some(rest) => (-1, rest),
+            None => (1, lexical),
+        };
+
+        let year_end = unsigned.find('-')?;
+        let year_digits = &unsigned[..year_end];
+
+        if year_digits.len() < 4 || (year_digits.len() > 4 && year_digits.starts_with('0')) {
+            return None;
+        }
+
+        let year = sign * i32::try_from(parse_digits(year_digits)?).ok()?;
+        let rest = &unsigned[year_end..];
+
+        if rest.len() < 6 || &rest[0..1] != "-" || &rest[3..4] != "-" {
+            return None;
+        }
+
+        let month = parse_digits(&rest[1..3])? as u8;
+        let day = parse_digits(&rest[4..6])? as u8;
+        let timezone = parse_timezone(&rest[6..])?;
+
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+            return None;
+        }
+
+        Some(XmlDate {
+            year,
+            month,
+            day,
+            timezone,
+        })
+    }
+}
+
+/// Value of an `xsd:time` literal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct XmlTime {
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub nanosecond: u32,
+
+    /// Timezone offset in minutes, `None` if no timezone is specified.
+    pub timezone: Option<i16>,
+}
+
+impl XmlTime {
+    /// Parses the lexical form of an `xsd:time`, e.g. `13:45:00`, `13:45:00.5Z`.
+    ///
+    /// Returns `None` if the lexical form is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::xml_specs::XmlTime;
+    ///
+    /// let time = XmlTime::parse("13:45:00Z").unwrap();
+    ///
+    /// assert_eq!((time.hour, time.minute, time.second, time.timezone), (13, 45, 0, Some(0)));
+    /// assert!(XmlTime::parse("25:00:00").is_none());
+    /// ```
+    pub fn parse(lexical: &str) -> Option<XmlTime> {
+        if !lexical.is_ascii() || lexical.len() < 8 || &lexical[2..3] != ":" || &lexical[5..6] != ":" {
+            return None;
+        }
+
+        let hour = parse_digits(&lexical[0..2])? as u8;
+        let minute = parse_digits(&lexical[3..5])? as u8;
+        let second = parse_digits(&lexical[6..8])? as u8;
+        let mut rest = &lexical[8..];
+        let mut nanosecond = 0;
+
+        if let Some(fraction) = rest.strip_prefix('.') {
+            let digits = fraction.find(|c: char| !c.is_ascii_digit()).unwrap_or(fraction.len());
+
+            if digits == 0 {
+                return None;
+            }
+
+            // only nanosecond precision is kept
+            for (i, c) in fraction[..digits].chars().take(9).enumerate() {
+                nanosecond += c.to_digit(10)? * 10u32.pow(8 - i as u32);
+            }
+
+            rest = &fraction[digits..];
+        }
+
+        let timezone = parse_timezone(rest)?;
+
+        // 24:00:00 is allowed to denote the end of a day
+        let is_end_of_day = hour == 24 && minute == 0 && second == 0 && nanosecond == 0;
+
+        if (hour > 23 && !is_end_of_day) || minute > 59 || second > 59 {
+            return None;
+        }
+
+        Some(XmlTime {
+            hour,
+            minute,
+            second,
+            nanosecond,
+            timezone,
+        })
+    }
+}
+
+/// Splits the sign from a numeric lexical form and returns whether it is negative.
+fn split_sign(literal: &str) -> (bool, &str) {
+    if let Some(rest) = literal.strip_prefix('-') {
+        (true, rest)
+    } else {
+        (false, literal.strip_prefix('+').unwrap_or(literal))
+    }
+}
+
+/// Splits a lexical form like `12.50` into its integer and fractional digits.
+fn split_decimal(unsigned: &str) -> Option<(&str, &str)> {
+    let (integer, fraction) = match unsigned.find('.') {
+        Some(point) => (&unsigned[..point], &unsigned[point + 1..]),
+        None => (unsigned, ""),
+    };
+
+    let is_digits = |digits: &str| digits.chars().all(|c| c.is_ascii_digit());
+
+    if (integer.is_empty() && fraction.is_empty()) || !is_digits(integer) || !is_digits(fraction) {
+        return None;
+    }
+
+    Some((integer, fraction))
+}
+
+/// Returns the canonical form of an `xsd:integer`, e.g. `-0012` becomes `-12`.
+fn canonical_integer(literal: &str) -> Option<String> {
+    let (negative, unsigned) = split_sign(literal);
+
+    if unsigned.is_empty() || !unsigned.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    match unsigned.trim_start_matches('0') {
+        "" => Some("0".to_string()),
+        digits if negative => Some(format!("-{}", digits)),
+        digits => Some(digits.to_string()),
+    }
+}
+
+/// Returns the canonical form of an `xsd:decimal`, e.g. `+01.50` becomes `1.5`.
+fn canonical_decimal(literal: &str) -> Option<String> {
+    let (negative, unsigned) = split_sign(literal);
+    let (integer, fraction) = split_decimal(unsigned)?;
+
+    let integer = match integer.trim_start_matches('0') {
+        "" => "0",
+        digits => digits,
+    };
+
+    let fraction = match fraction.trim_end_matches('0') {
+        "" => "0",
+        digits => digits,
+    };
+
+    if negative && (integer, fraction) != ("0", "0") {
+        Some(format!("-{}.{}", integer, fraction))
+    } else {
+        Some(format!("{}.{}", integer, fraction))
+    }
+}
+
+/// Returns the canonical form of an `xsd:double`, e.g. `0120` becomes `1.2E2`.
+fn canonical_double(literal: &str) -> Option<String> {
+    match literal {
+        "INF" | "+INF" => return Some("INF".to_string()),
+        "-INF" => return Some("-INF".to_string()),
+        "NaN" => return Some("NaN".to_string()),
+        _ => {}
+    }
+
+    let (mantissa, exponent) = match literal.find(['e', 'E']) {
+        Some(e) => (&literal[..e], &literal[e + 1..]),
+        None => (literal, "0"),
+    };
+
+    let (negative, unsigned) = split_sign(mantissa);
+    let (integer, fraction) = split_decimal(unsigned)?;
+
+    let (exponent_negative, exponent_digits) = split_sign(exponent);
+
+    if exponent_digits.is_empty() || !exponent_digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let exponent: i64 = exponent_digits.parse().ok()?;
+    let exponent = if exponent_negative { -exponent } else { exponent };
+
+    // shift the point so that exactly one non-zero digit precedes it
+    let digits = format!("{}{}", integer, fraction);
+    let sign = if negative { "-" } else { "" };
+
+    let leading_zeros = match digits.find(|c| c != '0') {
+        Some(position) => position,
+        None => return Some(format!("{}0.0E0", sign)),
+    };
+
+    let significant = digits[leading_zeros..].trim_end_matches('0');
+    let exponent = exponent.checked_add(integer.len() as i64 - leading_zeros as i64 - 1)?;
+
+    let fraction = match &significant[1..] {
+        "" => "0",
+        digits => digits,
+    };
+
+    Some(format!("{}{}.{}E{}", sign, &significant[..1], fraction, exponent))
+}
+
+impl fmt::Display for XmlDataTypes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let schema_name = "http://www.w3.org/2001/XMLSchema#".to_string();
 
-        match *self {
+        let data_type = match *self {
             XmlDataTypes::Boolean => schema_name + "boolean",
             XmlDataTypes::Integer => schema_name + "integer",
             XmlDataTypes::Decimal => schema_name + "decimal",
             XmlDataTypes::Double => schema_name + "double",
             XmlDataTypes::Date => schema_name + "date",
+            XmlDataTypes::Time => schema_name + "time",
             XmlDataTypes::Long => schema_name + "long",
             XmlDataTypes::UnsignedLong => schema_name + "unsignedLong",
             XmlDataTypes::Int => schema_name + "int",
             XmlDataTypes::String => schema_name + "string",
+            XmlDataTypes::AnyUri => schema_name + "anyURI",
+            XmlDataTypes::NormalizedString => schema_name + "normalizedString",
+            XmlDataTypes::Token => schema_name + "token",
+        };
+
+        f.write_str(&data_type)
+    }
+}
+
+/// Parses a non-empty sequence of ASCII digits.
+fn parse_digits(digits: &str) -> Option<u32> {
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    digits.parse().ok()
+}
+
+/// Parses an optional timezone (`Z`, `+hh:mm` or `-hh:mm`) and returns the offset in minutes.
+///
+/// Returns `None` if the timezone is invalid and `Some(None)` if there is none.
+fn parse_timezone(timezone: &str) -> Option<Option<i16>> {
+    match timezone {
+        "" => Some(None),
+        "Z" => Some(Some(0)),
+        _ => {
+            let sign = match &timezone[0..1] {
+                "+" => 1,
+                "-" => -1,
+                _ => return None,
+            };
+
+            if timezone.len() != 6 || &timezone[3..4] != ":" {
+                return None;
+            }
+
+            let hours = parse_digits(&timezone[1..3])? as i16;
+            let minutes = parse_digits(&timezone[4..6])? as i16;
+
+            if minutes > 59 || hours > 14 || (hours == 14 && minutes > 0) {
+                return None;
+            }
+
+            Some(Some(sign * (hours * 60 + minutes)))
         }
     }
 }
+
+/// Returns the number of days of a month.
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
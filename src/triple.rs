@@ -1,5 +1,11 @@
+use crate::graph::Graph;
 use crate::node::Node;
+use crate::writer::rdf_writer::RdfWriter;
+use crate::Result;
 use std::cmp::PartialEq;
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash, Hasher};
 use std::slice::Iter;
 use std::vec::IntoIter;
 
@@ -71,10 +77,25 @@ impl PartialEq for Triple {
 
 impl Eq for Triple {}
 
+impl Hash for Triple {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.subject().hash(state);
+        self.predicate().hash(state);
+        self.object().hash(state);
+    }
+}
+
 /// Storage for triples.
+///
+/// Each triple is stored once, in the order it was added first. Removing a triple moves the
+/// last triple to its position.
 #[derive(Debug, Default)]
 pub struct TripleStore {
     triples: Vec<Triple>,
+
+    /// Positions of the stored triples by the hash of the triple.
+    positions: HashMap<u64, Vec<usize>>,
+    hash_builder: RandomState,
 }
 
 impl TripleStore {
@@ -82,9 +103,25 @@ impl TripleStore {
     pub fn new() -> TripleStore {
         TripleStore {
             triples: Vec::new(),
+            positions: HashMap::new(),
+            hash_builder: RandomState::new(),
         }
     }
 
+    /// Returns the hash of the triple that its position is stored with.
+    fn hash_triple(&self, triple: &Triple) -> u64 {
+        self.hash_builder.hash_one(triple)
+    }
+
+    /// Returns the position of the triple, if it is stored.
+    fn position(&self, triple: &Triple) -> Option<usize> {
+        self.positions
+            .get(&self.hash_triple(triple))?
+            .iter()
+            .copied()
+            .find(|&position| &self.triples[position] == triple)
+    }
+
     /// Returns the number of triples that are stored.
     pub fn count(&self) -> usize {
         self.triples.len()
@@ -95,14 +132,54 @@ impl TripleStore {
         self.count() == 0
     }
 
-    /// Adds a new triple to the store.
-    pub fn add_triple(&mut self, triple: &Triple) {
+    /// Checks if the triple is stored.
+    pub fn contains(&self, triple: &Triple) -> bool {
+        self.position(triple).is_some()
+    }
+
+    /// Adds a new triple to the store and returns `false` if it was already stored.
+    pub fn add_triple(&mut self, triple: &Triple) -> bool {
+        if self.contains(triple) {
+            return false;
+        }
+
+        let hash = self.hash_triple(triple);
+        self.positions.entry(hash).or_default().push(self.triples.len());
         self.triples.push(triple.clone());
+        true
+    }
+
+    /// Deletes the triple from the store and returns `false` if it was not stored.
+    pub fn remove_triple(&mut self, triple: &Triple) -> bool {
+        let position = match self.position(triple) {
+            Some(position) => position,
+            None => return false,
+        };
+
+        self.remove_position(self.hash_triple(triple), position);
+
+        let last = self.triples.len() - 1;
+
+        if position != last {
+            // the last triple takes the place of the removed one
+            let hash = self.hash_triple(&self.triples[last]);
+            self.remove_position(hash, last);
+            self.positions.entry(hash).or_default().push(position);
+        }
+
+        self.triples.swap_remove(position);
+        true
     }
 
-    /// Deletes the triple from the store.
-    pub fn remove_triple(&mut self, triple: &Triple) {
-        self.triples.retain(|t| t != triple);
+    /// Deletes the position of a triple with the provided hash.
+    fn remove_position(&mut self, hash: u64, position: usize) {
+        if let Some(positions) = self.positions.get_mut(&hash) {
+            positions.retain(|&p| p != position);
+
+            if positions.is_empty() {
+                self.positions.remove(&hash);
+            }
+        }
     }
 
     /// Returns all triples where the subject node matches the provided node.
@@ -200,7 +277,7 @@ impl TripleStore {
     }
 
     /// Returns an iterator over the stored triples.
-    pub fn iter(&self) -> Iter<Triple> {
+    pub fn iter(&self) -> Iter<'_, Triple> {
         self.triples.iter()
     }
 }
@@ -214,10 +291,89 @@ impl IntoIterator for TripleStore {
     }
 }
 
+/// Builder for triples sharing the same subject.
+///
+/// Mirrors the `;` notation of Turtle: predicate-object pairs are pushed
+/// one after another and each pair results in a triple with the scoped subject.
+#[derive(Debug)]
+pub struct SubjectScope {
+    subject: Node,
+    triples: Vec<Triple>,
+}
+
+impl SubjectScope {
+    /// Constructor of `SubjectScope`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::triple::SubjectScope;
+    /// use rdf::node::Node;
+    /// use rdf::uri::Uri;
+    ///
+    /// let subject = Node::UriNode { uri: Uri::new("http://example.org/s".to_string()) };
+    /// let predicate = Node::UriNode { uri: Uri::new("http://example.org/p".to_string()) };
+    /// let object = Node::BlankNode { id: "b".to_string() };
+    ///
+    /// let mut scope = SubjectScope::new(&subject);
+    /// scope.push(&predicate, &object);
+    ///
+    /// assert_eq!(scope.triples().len(), 1);
+    /// assert_eq!(scope.triples()[0].subject(), &subject);
+    /// ```
+    pub fn new(subject: &Node) -> SubjectScope {
+        SubjectScope {
+            subject: subject.clone(),
+            triples: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to the scoped subject node.
+    pub fn subject(&self) -> &Node {
+        &self.subject
+    }
+
+    /// Adds a triple with the scoped subject and the provided predicate and object.
+    pub fn push(&mut self, predicate: &Node, object: &Node) -> &mut SubjectScope {
+        self.triples.push(Triple::new(&self.subject, predicate, object));
+        self
+    }
+
+    /// Returns the triples created so far.
+    pub fn triples(&self) -> &Vec<Triple> {
+        &self.triples
+    }
+
+    /// Returns the created triples as vector.
+    pub fn into_vec(self) -> Vec<Triple> {
+        self.triples
+    }
+
+    /// Adds all created triples to the provided graph.
+    pub fn add_to_graph(&self, graph: &mut Graph) {
+        graph.add_triples(&self.triples);
+    }
+
+    /// Writes all created triples using the provided writer.
+    ///
+    /// # Failures
+    ///
+    /// - The writer fails generating the output.
+    ///
+    pub fn write_to_string<W: RdfWriter>(&self, writer: &W) -> Result<String> {
+        let mut graph = Graph::new(None);
+        self.add_to_graph(&mut graph);
+        writer.write_to_string(&graph)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::graph::Graph;
     use crate::node::*;
     use crate::triple::*;
+    use crate::uri::Uri;
+    use crate::writer::n_triples_writer::NTriplesWriter;
 
     #[test]
     fn empty_triple_store() {
@@ -254,4 +410,107 @@ mod tests {
 
         assert_eq!(store.count(), 1);
     }
+
+    #[test]
+    fn remove_triples_from_triple_store() {
+        let mut store = TripleStore::new();
+
+        let triples = (0..4)
+            .map(|i| {
+                Triple::new(
+                    &Node::BlankNode { id: format!("s{}", i) },
+                    &Node::UriNode { uri: Uri::new("http://example.org/p".to_string()) },
+                    &Node::BlankNode { id: "o".to_string() },
+                )
+            })
+            .collect::<Vec<_>>();
+
+        for triple in &triples {
+            assert!(store.add_triple(triple));
+        }
+
+        assert!(store.remove_triple(&triples[1]));
+        assert!(!store.remove_triple(&triples[1]));
+        assert!(!store.contains(&triples[1]));
+        assert_eq!(store.count(), 3);
+
+        let stored = store.iter().cloned().collect::<Vec<_>>();
+        assert_eq!(stored, vec![triples[0].clone(), triples[3].clone(), triples[2].clone()]);
+
+        assert!(store.remove_triple(&triples[3]));
+        assert!(store.remove_triple(&triples[2]));
+        assert!(store.contains(&triples[0]));
+        assert!(!store.add_triple(&triples[0]));
+        assert!(store.add_triple(&triples[1]));
+        assert_eq!(store.count(), 2);
+    }
+
+    #[test]
+    fn identical_triples_are_equal_and_hash_the_same() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashSet;
+
+        let create_triple = || {
+            Triple::new(
+                &Node::BlankNode { id: "a".to_string() },
+                &Node::UriNode { uri: Uri::new("http://example.org/p".to_string()) },
+                &Node::LiteralNode {
+                    literal: "o".to_string(),
+                    data_type: None,
+                    language: Some("en".to_string()),
+                },
+            )
+        };
+
+        let hash = |triple: &Triple| {
+            let mut hasher = DefaultHasher::new();
+            triple.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        let (first, second) = (create_triple(), create_triple());
+
+        assert_eq!(first, second);
+        assert_eq!(hash(&first), hash(&second));
+
+        let distinct: HashSet<Triple> = vec![first, second].into_iter().collect();
+        assert_eq!(distinct.len(), 1);
+    }
+
+    #[test]
+    fn subject_scope_creates_triples_for_subject() {
+        let subject = Node::UriNode {
+            uri: Uri::new("http://example.org/s".to_string()),
+        };
+
+        let mut scope = SubjectScope::new(&subject);
+
+        for (predicate, object) in &[("p1", "o1"), ("p2", "o2"), ("p3", "o3")] {
+            let predicate = Node::UriNode {
+                uri: Uri::new("http://example.org/".to_string() + predicate),
+            };
+            let object = Node::LiteralNode {
+                literal: object.to_string(),
+                data_type: None,
+                language: None,
+            };
+
+            scope.push(&predicate, &object);
+        }
+
+        assert_eq!(scope.triples().len(), 3);
+        assert!(scope.triples().iter().all(|t| t.subject() == &subject));
+
+        let mut graph = Graph::new(None);
+        scope.add_to_graph(&mut graph);
+        assert_eq!(graph.count(), 3);
+
+        let output = scope.write_to_string(&NTriplesWriter::new()).unwrap();
+        assert_eq!(
+            output,
+            "<http://example.org/s> <http://example.org/p1> \"o1\" .\n\
+             <http://example.org/s> <http://example.org/p2> \"o2\" .\n\
+             <http://example.org/s> <http://example.org/p3> \"o3\" .\n"
+        );
+    }
 }
@@ -1,6 +1,6 @@
 // todo: implement
 
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct Uri {
     uri: String,
 }
@@ -16,9 +16,275 @@ impl Uri {
         &self.uri
     }
 
+    /// Returns `true` if the URI is an absolute IRI, i.e. it starts with a scheme.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::uri::Uri;
+    ///
+    /// assert!(Uri::new("http://example.org/".to_string()).is_absolute_iri());
+    /// assert!(Uri::new("urn:isbn:0451450523".to_string()).is_absolute_iri());
+    /// assert!(!Uri::new("#foo".to_string()).is_absolute_iri());
+    /// assert!(!Uri::new("example.org/a".to_string()).is_absolute_iri());
+    /// ```
+    pub fn is_absolute_iri(&self) -> bool {
+        self.scheme().is_some()
+    }
+
+    /// Returns the scheme of the URI, `None` if the URI is relative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::uri::Uri;
+    ///
+    /// assert_eq!(Uri::new("https://example.org/".to_string()).scheme(), Some("https"));
+    /// assert_eq!(Uri::new("#foo".to_string()).scheme(), None);
+    /// ```
+    pub fn scheme(&self) -> Option<&str> {
+        parse_scheme(&self.uri)
+    }
+
+    /// Resolves a relative reference against the URI as base, as defined by RFC 3986.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::uri::Uri;
+    ///
+    /// let base = Uri::new("http://example.org/a/b/c?q".to_string());
+    ///
+    /// assert_eq!(base.resolve("d").to_string(), "http://example.org/a/b/d");
+    /// assert_eq!(base.resolve("../x").to_string(), "http://example.org/a/x");
+    /// assert_eq!(base.resolve("#f").to_string(), "http://example.org/a/b/c?q#f");
+    /// assert_eq!(base.resolve("//example.com").to_string(), "http://example.com");
+    /// assert_eq!(base.resolve("urn:a").to_string(), "urn:a");
+    /// ```
+    pub fn resolve(&self, reference: &str) -> Uri {
+        let base = UriParts::parse(&self.uri);
+        let reference = UriParts::parse(reference);
+
+        let (scheme, authority, path, query) = if reference.scheme.is_some() {
+            (
+                reference.scheme,
+                reference.authority,
+                remove_dot_segments(reference.path),
+                reference.query,
+            )
+        } else if reference.authority.is_some() {
+            (
+                base.scheme,
+                reference.authority,
+                remove_dot_segments(reference.path),
+                reference.query,
+            )
+        } else if reference.path.is_empty() {
+            (
+                base.scheme,
+                base.authority,
+                base.path.to_string(),
+                reference.query.or(base.query),
+            )
+        } else if reference.path.starts_with('/') {
+            (
+                base.scheme,
+                base.authority,
+                remove_dot_segments(reference.path),
+                reference.query,
+            )
+        } else {
+            (
+                base.scheme,
+                base.authority,
+                remove_dot_segments(&base.merge(reference.path)),
+                reference.query,
+            )
+        };
+
+        let mut uri = String::new();
+
+        if let Some(scheme) = scheme {
+            uri.push_str(scheme);
+            uri.push(':');
+        }
+
+        if let Some(authority) = authority {
+            uri.push_str("//");
+            uri.push_str(authority);
+        }
+
+        uri.push_str(&path);
+
+        if let Some(query) = query {
+            uri.push('?');
+            uri.push_str(query);
+        }
+
+        if let Some(fragment) = reference.fragment {
+            uri.push('#');
+            uri.push_str(fragment);
+        }
+
+        Uri::new(uri)
+    }
+
     /// todo
     pub fn append_resource_path(&mut self, path: &str) {
         // todo: check if URI ends with '/', if not add '/'
-        self.uri.push_str(&path.to_string());
+        self.uri.push_str(path);
+    }
+}
+
+/// Components of a URI reference, see RFC 3986, appendix B.
+struct UriParts<'a> {
+    scheme: Option<&'a str>,
+    authority: Option<&'a str>,
+    path: &'a str,
+    query: Option<&'a str>,
+    fragment: Option<&'a str>,
+}
+
+impl<'a> UriParts<'a> {
+    /// Splits a URI reference into its components.
+    fn parse(uri: &'a str) -> UriParts<'a> {
+        let (rest, fragment) = match uri.find('#') {
+            Some(index) => (&uri[..index], Some(&uri[index + 1..])),
+            None => (uri, None),
+        };
+
+        let (rest, query) = match rest.find('?') {
+            Some(index) => (&rest[..index], Some(&rest[index + 1..])),
+            None => (rest, None),
+        };
+
+        let scheme = parse_scheme(rest);
+        let rest = match scheme {
+            Some(scheme) => &rest[scheme.len() + 1..],
+            None => rest,
+        };
+
+        let (authority, path) = match rest.strip_prefix("//") {
+            Some(rest) => {
+                let end = rest.find('/').unwrap_or(rest.len());
+                (Some(&rest[..end]), &rest[end..])
+            }
+            None => (None, rest),
+        };
+
+        UriParts {
+            scheme,
+            authority,
+            path,
+            query,
+            fragment,
+        }
+    }
+
+    /// Merges a relative path with the path of this base, see RFC 3986, section 5.2.3.
+    fn merge(&self, path: &str) -> String {
+        if self.authority.is_some() && self.path.is_empty() {
+            return "/".to_string() + path;
+        }
+
+        match self.path.rfind('/') {
+            Some(index) => self.path[..=index].to_string() + path,
+            None => path.to_string(),
+        }
+    }
+}
+
+/// Returns the scheme of a URI reference, `None` if it is relative.
+fn parse_scheme(uri: &str) -> Option<&str> {
+    // scheme = ALPHA *( ALPHA / DIGIT / "+" / "-" / "." ) followed by ':'
+    let scheme = &uri[..uri.find(':')?];
+
+    let is_valid = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.');
+
+    if is_valid {
+        Some(scheme)
+    } else {
+        None
+    }
+}
+
+/// Removes the `.` and `..` segments of a path, see RFC 3986, section 5.2.4.
+fn remove_dot_segments(path: &str) -> String {
+    let mut input = path;
+    let mut output = String::new();
+
+    while !input.is_empty() {
+        if let Some(rest) = input.strip_prefix("../") {
+            input = rest;
+        } else if let Some(rest) = input.strip_prefix("./") {
+            input = rest;
+        } else if input.starts_with("/./") {
+            input = &input[2..];
+        } else if input == "/." {
+            input = "/";
+        } else if input.starts_with("/../") || input == "/.." {
+            input = if input == "/.." { "/" } else { &input[3..] };
+            output.truncate(output.rfind('/').unwrap_or(0));
+        } else if input == "." || input == ".." {
+            input = "";
+        } else {
+            // move the first segment, including its leading '/', to the output
+            let end = input
+                .char_indices()
+                .skip(1)
+                .find(|&(_, c)| c == '/')
+                .map_or(input.len(), |(index, _)| index);
+            output.push_str(&input[..end]);
+            input = &input[end..];
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::uri::Uri;
+
+    #[test]
+    fn test_resolve_rfc_3986_examples() {
+        let base = Uri::new("http://a/b/c/d;p?q".to_string());
+
+        for (reference, expected) in [
+            ("g:h", "g:h"),
+            ("g", "http://a/b/c/g"),
+            ("./g", "http://a/b/c/g"),
+            ("g/", "http://a/b/c/g/"),
+            ("/g", "http://a/g"),
+            ("//g", "http://g"),
+            ("?y", "http://a/b/c/d;p?y"),
+            ("g?y", "http://a/b/c/g?y"),
+            ("#s", "http://a/b/c/d;p?q#s"),
+            ("g#s", "http://a/b/c/g#s"),
+            ("", "http://a/b/c/d;p?q"),
+            (".", "http://a/b/c/"),
+            ("./", "http://a/b/c/"),
+            ("..", "http://a/b/"),
+            ("../g", "http://a/b/g"),
+            ("../..", "http://a/"),
+            ("../../g", "http://a/g"),
+            ("../../../g", "http://a/g"),
+            ("/./g", "http://a/g"),
+            ("/../g", "http://a/g"),
+            ("g.", "http://a/b/c/g."),
+            ("..g", "http://a/b/c/..g"),
+            ("./../g", "http://a/b/g"),
+            ("g/./h", "http://a/b/c/g/h"),
+            ("g/../h", "http://a/b/c/h"),
+            ("g;x=1/../y", "http://a/b/c/y"),
+            ("g?y/./x", "http://a/b/c/g?y/./x"),
+            ("g#s/../x", "http://a/b/c/g#s/../x"),
+            ("é/../ü", "http://a/b/c/ü"),
+        ] {
+            assert_eq!(base.resolve(reference).to_string(), expected, "{}", reference);
+        }
     }
 }
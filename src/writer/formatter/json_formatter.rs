@@ -0,0 +1,161 @@
+use crate::node::Node;
+use crate::uri::Uri;
+use crate::writer::formatter::rdf_formatter::RdfFormatter;
+
+/// Formatter for formatting nodes to JSON term objects.
+/// This formatter is used by `JsonTriplesWriter`.
+#[derive(Default)]
+pub struct JsonFormatter {}
+
+impl JsonFormatter {
+    /// Constructor of `JsonFormatter`.
+    pub fn new() -> JsonFormatter {
+        JsonFormatter {}
+    }
+
+    /// Returns the provided string as quoted and escaped JSON string.
+    pub fn format_string(&self, s: &str) -> String {
+        let mut output_string = "\"".to_string();
+
+        for c in s.chars() {
+            match c {
+                '"' => output_string.push_str("\\\""),
+                '\\' => output_string.push_str("\\\\"),
+                '\n' => output_string.push_str("\\n"),
+                '\r' => output_string.push_str("\\r"),
+                '\t' => output_string.push_str("\\t"),
+                c if (c as u32) < 0x20 => output_string.push_str(&format!("\\u{:04x}", c as u32)),
+                c => output_string.push(c),
+            }
+        }
+
+        output_string.push('"');
+
+        output_string
+    }
+}
+
+impl RdfFormatter for JsonFormatter {
+    /// Returns the corresponding JSON term object for a node.
+    ///
+    /// Determines the node type, extracts its content and calls the
+    /// right function for formatting this content.
+    fn format_node(&self, node: &Node) -> String {
+        match *node {
+            Node::BlankNode { ref id } => self.format_blank(id),
+            Node::LiteralNode {
+                ref literal,
+                ref data_type,
+                ref language,
+            } => self.format_literal(literal, data_type, language),
+            Node::UriNode { ref uri } => self.format_uri(uri),
+        }
+    }
+
+    /// Formats a literal to a JSON term object.
+    ///
+    /// Data type and language are only added if present.
+    fn format_literal(
+        &self,
+        literal: &str,
+        data_type: &Option<Uri>,
+        language: &Option<String>,
+    ) -> String {
+        let mut output_string = "{\"type\":\"literal\",\"value\":".to_string();
+        output_string.push_str(&self.format_string(literal));
+
+        if let Some(ref dt) = *data_type {
+            output_string.push_str(",\"datatype\":");
+            output_string.push_str(&self.format_string(dt.to_string()));
+        }
+
+        if let Some(ref lang) = *language {
+            output_string.push_str(",\"lang\":");
+            output_string.push_str(&self.format_string(lang));
+        }
+
+        output_string.push('}');
+
+        output_string
+    }
+
+    /// Formats the content of a blank node to a JSON term object.
+    fn format_blank(&self, id: &str) -> String {
+        let mut output_string = "{\"type\":\"bnode\",\"value\":".to_string();
+        output_string.push_str(&self.format_string(&("_:".to_string() + id)));
+        output_string.push('}');
+
+        output_string
+    }
+
+    /// Formats a URI to a JSON term object.
+    fn format_uri(&self, uri: &Uri) -> String {
+        let mut output_string = "{\"type\":\"uri\",\"value\":".to_string();
+        output_string.push_str(&self.format_string(uri.to_string()));
+        output_string.push('}');
+
+        output_string
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::*;
+    use crate::specs::xml_specs::XmlDataTypes;
+    use crate::uri::Uri;
+    use crate::writer::formatter::json_formatter::JsonFormatter;
+    use crate::writer::formatter::rdf_formatter::RdfFormatter;
+
+    #[test]
+    fn test_json_literal_formatting() {
+        let formatter = JsonFormatter::new();
+
+        let node = Node::LiteralNode {
+            literal: "a \"quoted\"\nliteral".to_string(),
+            data_type: None,
+            language: Some("en".to_string()),
+        };
+
+        assert_eq!(
+            formatter.format_node(&node),
+            "{\"type\":\"literal\",\"value\":\"a \\\"quoted\\\"\\nliteral\",\"lang\":\"en\"}"
+        );
+
+        let node = Node::LiteralNode {
+            literal: "1".to_string(),
+            data_type: Some(XmlDataTypes::Integer.to_uri()),
+            language: None,
+        };
+
+        assert_eq!(
+            formatter.format_node(&node),
+            "{\"type\":\"literal\",\"value\":\"1\",\"datatype\":\"http://www.w3.org/2001/XMLSchema#integer\"}"
+        );
+    }
+
+    #[test]
+    fn test_json_blank_node_formatting() {
+        let formatter = JsonFormatter::new();
+        let node = Node::BlankNode {
+            id: "auto0".to_string(),
+        };
+
+        assert_eq!(
+            formatter.format_node(&node),
+            "{\"type\":\"bnode\",\"value\":\"_:auto0\"}"
+        );
+    }
+
+    #[test]
+    fn test_json_uri_formatting() {
+        let formatter = JsonFormatter::new();
+        let node = Node::UriNode {
+            uri: Uri::new("http://example.org/a".to_string()),
+        };
+
+        assert_eq!(
+            formatter.format_node(&node),
+            "{\"type\":\"uri\",\"value\":\"http://example.org/a\"}"
+        );
+    }
+}
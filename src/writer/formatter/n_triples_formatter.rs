@@ -1,16 +1,29 @@
 use crate::node::Node;
 use crate::uri::Uri;
 use crate::writer::formatter::rdf_formatter::RdfFormatter;
+use crate::writer::writer_options::WriterOptions;
 
 /// Formatter for formatting nodes to N-Triple syntax.
 /// This formatter is used by `NTriplesWriter`.
 #[derive(Default)]
-pub struct NTriplesFormatter {}
+pub struct NTriplesFormatter {
+    options: WriterOptions,
+}
 
 impl NTriplesFormatter {
     /// Constructor of `NTriplesFormatter`.
     pub fn new() -> NTriplesFormatter {
-        NTriplesFormatter {}
+        NTriplesFormatter::with_options(WriterOptions::default())
+    }
+
+    /// Constructor of `NTriplesFormatter` using the provided options.
+    pub fn with_options(options: WriterOptions) -> NTriplesFormatter {
+        NTriplesFormatter { options }
+    }
+
+    /// Returns the options used for formatting.
+    pub(crate) fn options(&self) -> &WriterOptions {
+        &self.options
     }
 }
 
@@ -42,15 +55,15 @@ impl RdfFormatter for NTriplesFormatter {
         language: &Option<String>,
     ) -> String {
         let mut output_string = "\"".to_string();
-        output_string.push_str(format!("{}", literal.escape_debug()).as_ref());
-        output_string.push_str("\"");
+        output_string.push_str(&self.escape_literal(literal));
+        output_string.push('"');
 
         if let Some(ref lang) = *language {
-            output_string.push_str("@");
+            output_string.push('@');
             output_string.push_str(lang);
         }
 
-        if let Some(ref dt) = *data_type {
+        if let Some(ref dt) = self.options.literal_data_type(data_type, language) {
             output_string.push_str("^^");
             output_string.push_str(&self.format_uri(dt));
         }
@@ -60,14 +73,14 @@ impl RdfFormatter for NTriplesFormatter {
 
     /// Formats the content of a blank node to the corresponding N-Triples syntax.
     fn format_blank(&self, id: &str) -> String {
-        "_:".to_string() + id
+        "_:".to_string() + &self.options.blank_node_label(id)
     }
 
     /// Formats a URI to N-Triples syntax.
     fn format_uri(&self, uri: &Uri) -> String {
         let mut output_string = "<".to_string();
         output_string.push_str(uri.to_string());
-        output_string.push_str(">");
+        output_string.push('>');
 
         output_string
     }
@@ -79,6 +92,7 @@ mod tests {
     use crate::uri::Uri;
     use crate::writer::formatter::n_triples_formatter::NTriplesFormatter;
     use crate::writer::formatter::rdf_formatter::RdfFormatter;
+    use crate::writer::writer_options::WriterOptions;
 
     #[test]
     fn test_n_triples_blank_node_formatting() {
@@ -156,4 +170,59 @@ mod tests {
 
         assert_eq!(formatter.format_node(&node), "\"literal\"@en".to_string());
     }
+
+    #[test]
+    fn test_n_triples_explicit_string_datatype_formatting() {
+        let options = WriterOptions {
+            explicit_string_datatype: true,
+            ..Default::default()
+        };
+        let formatter = NTriplesFormatter::with_options(options);
+
+        let plain = Node::LiteralNode {
+            literal: "literal".to_string(),
+            data_type: None,
+            language: None,
+        };
+        let with_language = Node::LiteralNode {
+            literal: "literal".to_string(),
+            data_type: None,
+            language: Some("en".to_string()),
+        };
+
+        assert_eq!(
+            formatter.format_node(&plain),
+            "\"literal\"^^<http://www.w3.org/2001/XMLSchema#string>".to_string()
+        );
+        assert_eq!(formatter.format_node(&with_language), "\"literal\"@en".to_string());
+    }
+
+    #[test]
+    fn test_n_triples_blank_node_suffix_formatting() {
+        let options = WriterOptions {
+            blank_node_suffix: "-1".to_string(),
+            ..Default::default()
+        };
+        let formatter = NTriplesFormatter::with_options(options);
+        let node = Node::BlankNode {
+            id: "blank".to_string(),
+        };
+
+        assert_eq!(formatter.format_node(&node), "_:blank-1".to_string());
+    }
+
+    #[test]
+    fn test_n_triples_control_character_literal_node_formatting() {
+        let formatter = NTriplesFormatter::new();
+        let node = Node::LiteralNode {
+            literal: "a\u{8}b\u{c}c\td\ne\rf\u{1}".to_string(),
+            data_type: None,
+            language: None,
+        };
+
+        assert_eq!(
+            formatter.format_node(&node),
+            "\"a\\bb\\fc\\td\\ne\\rf\\u0001\"".to_string()
+        );
+    }
 }
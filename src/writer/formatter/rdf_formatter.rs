@@ -19,4 +19,40 @@ pub trait RdfFormatter {
 
     /// Formats a URI.
     fn format_uri(&self, uri: &Uri) -> String;
+
+    /// Escapes the characters of a literal that cannot be written within quotes.
+    ///
+    /// Uses the escape sequences `\t`, `\b`, `\n`, `\r`, `\f`, `\"`, `\'` and `\\`,
+    /// all other control characters are written as `\uXXXX`.
+    fn escape_literal(&self, literal: &str) -> String {
+        escape_characters(literal, &['"', '\''])
+    }
+
+    /// Escapes the characters of a literal that cannot be written within the provided quote
+    /// character, the other quote character is written unescaped.
+    fn escape_literal_for_quote(&self, literal: &str, quote: char) -> String {
+        escape_characters(literal, &[quote])
+    }
+}
+
+/// Escapes backslashes, control characters and the provided quote characters of a literal.
+fn escape_characters(literal: &str, quotes: &[char]) -> String {
+    let mut escaped = String::with_capacity(literal.len());
+
+    for c in literal.chars() {
+        match c {
+            '\t' => escaped.push_str("\\t"),
+            '\u{8}' => escaped.push_str("\\b"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\u{c}' => escaped.push_str("\\f"),
+            '"' if quotes.contains(&c) => escaped.push_str("\\\""),
+            '\'' if quotes.contains(&c) => escaped.push_str("\\'"),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04X}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
 }
@@ -3,17 +3,82 @@ use crate::specs::turtle_specs::TurtleSpecs;
 use std::collections::HashMap;
 use crate::uri::Uri;
 use crate::writer::formatter::rdf_formatter::RdfFormatter;
+use crate::writer::writer_options::WriterOptions;
 
 /// Formatter for formatting nodes to Turtle syntax.
 /// This formatter is used by `TurtleWriter`.
 pub struct TurtleFormatter<'a> {
     namespaces: &'a HashMap<String, Uri>,
+
+    /// Prefixes that may be used for abbreviating URIs, all if `None`.
+    prefix_whitelist: Option<Vec<String>>,
+
+    options: WriterOptions,
 }
 
 impl<'a> TurtleFormatter<'a> {
     /// Constructor of `TurtleFormatter`.
     pub fn new(namespaces: &'a HashMap<String, Uri>) -> TurtleFormatter<'a> {
-        TurtleFormatter { namespaces }
+        TurtleFormatter::with_options(namespaces, WriterOptions::default())
+    }
+
+    /// Constructor of `TurtleFormatter` using the provided options.
+    pub fn with_options(
+        namespaces: &'a HashMap<String, Uri>,
+        options: WriterOptions,
+    ) -> TurtleFormatter<'a> {
+        TurtleFormatter {
+            namespaces,
+            prefix_whitelist: None,
+            options,
+        }
+    }
+
+    /// Constructor of `TurtleFormatter` that only abbreviates URIs
+    /// of namespaces whose prefix is contained in the whitelist.
+    pub fn with_prefix_whitelist(
+        namespaces: &'a HashMap<String, Uri>,
+        prefix_whitelist: Vec<String>,
+    ) -> TurtleFormatter<'a> {
+        TurtleFormatter {
+            namespaces,
+            prefix_whitelist: Some(prefix_whitelist),
+            options: WriterOptions::default(),
+        }
+    }
+
+    /// Checks if URIs of the namespace with the provided prefix may be abbreviated.
+    fn is_prefix_allowed(&self, prefix: &str) -> bool {
+        match self.prefix_whitelist {
+            Some(ref whitelist) => whitelist.iter().any(|p| p == prefix),
+            None => true,
+        }
+    }
+
+    /// Returns the namespaces that may be used for abbreviating URIs, sorted by prefix.
+    pub fn namespaces(&self) -> Vec<(&String, &Uri)> {
+        let mut namespaces: Vec<(&String, &Uri)> = self
+            .namespaces
+            .iter()
+            .filter(|(prefix, _)| self.is_prefix_allowed(prefix))
+            .collect();
+
+        namespaces.sort();
+        namespaces
+    }
+
+    /// Returns the quote character for a literal that requires less escaping.
+    ///
+    /// Single quotes are only used if the literal contains more double quotes than single quotes.
+    fn literal_quote(literal: &str) -> char {
+        let double_quotes = literal.matches('"').count();
+        let single_quotes = literal.matches('\'').count();
+
+        if double_quotes > single_quotes {
+            '\''
+        } else {
+            '"'
+        }
     }
 }
 
@@ -36,7 +101,8 @@ impl<'a> RdfFormatter for TurtleFormatter<'a> {
 
     /// Formats a literal to the corresponding Turtle syntax.
     ///
-    /// Also considers the data type and language of the literal.
+    /// Also considers the data type and language of the literal. The literal is quoted with
+    /// the quote character that requires less escaping, see `literal_quote`.
     fn format_literal(
         &self,
         literal: &str,
@@ -44,23 +110,26 @@ impl<'a> RdfFormatter for TurtleFormatter<'a> {
         language: &Option<String>,
     ) -> String {
         let mut output_string = "".to_string();
+        let data_type = self.options.literal_data_type(data_type, language);
 
         if (TurtleSpecs::is_boolean_literal(literal) || TurtleSpecs::is_integer_literal(literal))
-            && *language == None && *data_type == None {
+            && language.is_none() && data_type.is_none() {
             // some number or boolean
             output_string.push_str(literal);
         } else {
-            output_string.push_str("\"");
-            output_string.push_str(format!("{}", literal.escape_debug()).as_ref());
-            output_string.push_str("\"");
+            let quote = Self::literal_quote(literal);
+
+            output_string.push(quote);
+            output_string.push_str(&self.escape_literal_for_quote(literal, quote));
+            output_string.push(quote);
         }
 
         if let Some(ref lang) = *language {
-            output_string.push_str("@");
+            output_string.push('@');
             output_string.push_str(lang);
         }
 
-        if let Some(ref dt) = *data_type {
+        if let Some(ref dt) = data_type {
             output_string.push_str("^^");
             output_string.push_str(&self.format_uri(dt));
         }
@@ -70,18 +139,23 @@ impl<'a> RdfFormatter for TurtleFormatter<'a> {
 
     /// Formats the content of a blank node to the corresponding Turtle syntax.
     fn format_blank(&self, id: &str) -> String {
-        "_:".to_string() + id
+        "_:".to_string() + &self.options.blank_node_label(id)
     }
 
     /// Formats a URI to Turtle syntax.
     fn format_uri(&self, uri: &Uri) -> String {
         let mut output_string = "".to_string();
 
-        // write QName if namespace for URI exists
-        for (prefix, namespace_uri) in self.namespaces.iter() {
+        // write QName if namespace for URI exists, the longest namespace is used if several match
+        let mut namespaces = self.namespaces();
+        namespaces.sort_by_key(|(_, namespace_uri)| {
+            std::cmp::Reverse(namespace_uri.to_string().len())
+        });
+
+        for (prefix, namespace_uri) in namespaces {
             if uri.to_string().starts_with(namespace_uri.to_string()) {
                 output_string.push_str(prefix);
-                output_string.push_str(":");
+                output_string.push(':');
 
                 let path = uri
                     .to_string()
@@ -94,9 +168,9 @@ impl<'a> RdfFormatter for TurtleFormatter<'a> {
             }
         }
 
-        output_string.push_str("<");
+        output_string.push('<');
         output_string.push_str(uri.to_string());
-        output_string.push_str(">");
+        output_string.push('>');
 
         output_string
     }
@@ -180,10 +254,28 @@ mod tests {
 
         assert_eq!(
             formatter.format_node(&node),
-            "\"literal \\' \\\\ \\\" \"".to_string()
+            "\"literal ' \\\\ \\\" \"".to_string()
         );
     }
 
+    #[test]
+    fn test_turtle_literal_quote_formatting() {
+        let hashmap = HashMap::new();
+        let formatter = TurtleFormatter::new(&hashmap);
+
+        let quoted = |literal: &str| {
+            formatter.format_node(&Node::LiteralNode {
+                literal: literal.to_string(),
+                data_type: None,
+                language: None,
+            })
+        };
+
+        assert_eq!(quoted("say \"hi\""), "'say \"hi\"'".to_string());
+        assert_eq!(quoted("it's \"hi\""), "'it\\'s \"hi\"'".to_string());
+        assert_eq!(quoted("it's"), "\"it's\"".to_string());
+    }
+
     #[test]
     fn test_turtle_literal_node_with_datatype_formatting() {
         let hashmap = HashMap::new();
@@ -0,0 +1,144 @@
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::triple::Triple;
+use crate::writer::formatter::json_formatter::JsonFormatter;
+use crate::writer::formatter::rdf_formatter::*;
+use crate::writer::rdf_writer::RdfWriter;
+use crate::Result;
+use std::io::Write;
+
+/// RDF writer to generate a JSON array of triples.
+///
+/// Each triple is written as object with `subject`, `predicate` and `object` keys
+/// mapping to term objects of the shape `{"type": ..., "value": ...}`, where the type
+/// is `uri`, `bnode` or `literal`. Literals may also contain `datatype` and `lang`.
+///
+/// This is a minimal serialization for interoperability and not JSON-LD.
+#[derive(Default)]
+pub struct JsonTriplesWriter {
+    formatter: JsonFormatter,
+}
+
+impl RdfWriter for JsonTriplesWriter {
+    /// Generates a JSON array containing each triple stored in the provided graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::writer::json_triples_writer::JsonTriplesWriter;
+    /// use rdf::writer::rdf_writer::RdfWriter;
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let writer = JsonTriplesWriter::new();
+    ///
+    /// let mut graph = Graph::new(None);
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_literal_node("a".to_string());
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+    ///
+    /// assert_eq!(writer.write_to_string(&graph).unwrap(),
+    ///            "[{\"subject\":{\"type\":\"bnode\",\"value\":\"_:auto0\"},\
+    ///              \"predicate\":{\"type\":\"uri\",\"value\":\"http://example.org/show/localName\"},\
+    ///              \"object\":{\"type\":\"literal\",\"value\":\"a\"}}]".to_string());
+    /// ```
+    fn write_to_string(&self, graph: &Graph) -> Result<String> {
+        let triples: Vec<String> = graph
+            .triples_iter()
+            .map(|triple| self.triple_to_json(triple))
+            .collect();
+
+        Ok("[".to_string() + &triples.join(",") + "]")
+    }
+}
+
+impl JsonTriplesWriter {
+    /// Constructor of `JsonTriplesWriter`.
+    pub fn new() -> JsonTriplesWriter {
+        JsonTriplesWriter {
+            formatter: JsonFormatter::new(),
+        }
+    }
+
+    /// Writes the JSON array of triples stored in the provided graph to the output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::writer::json_triples_writer::JsonTriplesWriter;
+    /// use rdf::graph::Graph;
+    ///
+    /// let writer = JsonTriplesWriter::new();
+    /// let graph = Graph::new(None);
+    /// let mut output: Vec<u8> = Vec::new();
+    ///
+    /// writer.write_json_triples(&graph, &mut output).unwrap();
+    ///
+    /// assert_eq!(output, b"[]");
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Writing to the output fails.
+    ///
+    pub fn write_json_triples<W: Write>(&self, graph: &Graph, output: &mut W) -> Result<()> {
+        let json = self.write_to_string(graph)?;
+
+        output
+            .write_all(json.as_bytes())
+            .map_err(|err| Error::new(ErrorType::InvalidWriterOutput, err.to_string()))
+    }
+
+    /// Generates the JSON object of the provided triple.
+    pub fn triple_to_json(&self, triple: &Triple) -> String {
+        let mut output_string = "{\"subject\":".to_string();
+        output_string.push_str(&self.formatter.format_node(triple.subject()));
+        output_string.push_str(",\"predicate\":");
+        output_string.push_str(&self.formatter.format_node(triple.predicate()));
+        output_string.push_str(",\"object\":");
+        output_string.push_str(&self.formatter.format_node(triple.object()));
+        output_string.push('}');
+
+        output_string
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::Graph;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+    use crate::writer::json_triples_writer::JsonTriplesWriter;
+    use crate::writer::rdf_writer::RdfWriter;
+
+    #[test]
+    fn test_json_triples_writer() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_uri_node(&Uri::new("http://example.org/s".to_string()));
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let object_1 = graph.create_integer_node(1);
+        let object_2 =
+            graph.create_literal_node_with_language("chat".to_string(), "fr".to_string());
+        graph.add_triple(&Triple::new(&subject, &predicate, &object_1));
+        graph.add_triple(&Triple::new(&subject, &predicate, &object_2));
+
+        let expected = "[\
+            {\"subject\":{\"type\":\"uri\",\"value\":\"http://example.org/s\"},\
+            \"predicate\":{\"type\":\"uri\",\"value\":\"http://example.org/p\"},\
+            \"object\":{\"type\":\"literal\",\"value\":\"1\",\"datatype\":\"http://www.w3.org/2001/XMLSchema#integer\"}},\
+            {\"subject\":{\"type\":\"uri\",\"value\":\"http://example.org/s\"},\
+            \"predicate\":{\"type\":\"uri\",\"value\":\"http://example.org/p\"},\
+            \"object\":{\"type\":\"literal\",\"value\":\"chat\",\"lang\":\"fr\"}}\
+            ]";
+
+        let writer = JsonTriplesWriter::new();
+        assert_eq!(writer.write_to_string(&graph).unwrap(), expected);
+
+        let mut output: Vec<u8> = Vec::new();
+        writer.write_json_triples(&graph, &mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), expected);
+    }
+}
@@ -5,7 +5,9 @@ use crate::triple::*;
 use crate::writer::formatter::n_triples_formatter::NTriplesFormatter;
 use crate::writer::formatter::rdf_formatter::*;
 use crate::writer::rdf_writer::RdfWriter;
+use crate::writer::writer_options::WriterOptions;
 use crate::Result;
+use std::io::Write;
 
 /// RDF writer to generate N-Triples syntax.
 #[derive(Default)]
@@ -43,7 +45,7 @@ impl RdfWriter for NTriplesWriter {
             match self.triple_to_n_triples(triple) {
                 Ok(str) => {
                     output_string.push_str(&str);
-                    output_string.push_str("\n");
+                    output_string.push('\n');
                 }
                 Err(error) => return Err(error),
             }
@@ -65,9 +67,112 @@ impl NTriplesWriter {
     /// let writer = NTriplesWriter::new();
     /// ```
     pub fn new() -> NTriplesWriter {
+        NTriplesWriter::with_options(WriterOptions::default())
+    }
+
+    /// Constructor of `NTriplesWriter` using the provided options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::writer::n_triples_writer::NTriplesWriter;
+    /// use rdf::writer::rdf_writer::RdfWriter;
+    /// use rdf::writer::writer_options::WriterOptions;
+    /// use rdf::graph::Graph;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_literal_node("literal".to_string());
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+    ///
+    /// let options = WriterOptions {
+    ///     explicit_string_datatype: true,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let writer = NTriplesWriter::with_options(options);
+    ///
+    /// assert_eq!(writer.write_to_string(&graph).unwrap(),
+    ///            "_:auto0 <http://example.org/show/localName> \"literal\"^^<http://www.w3.org/2001/XMLSchema#string> .\n");
+    /// ```
+    pub fn with_options(options: WriterOptions) -> NTriplesWriter {
         NTriplesWriter {
-            formatter: NTriplesFormatter::new(),
+            formatter: NTriplesFormatter::with_options(options),
+        }
+    }
+
+    /// Writes the N-Triples syntax of the provided triple as one line to the output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::writer::n_triples_writer::NTriplesWriter;
+    /// use rdf::node::Node;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let writer = NTriplesWriter::new();
+    ///
+    /// let subject = Node::BlankNode { id: "blank".to_string() };
+    /// let object = Node::LiteralNode { literal: "a \"b\"".to_string(), data_type: None, language: None };
+    /// let predicate = Node::UriNode { uri: Uri::new("http://example.org/show/localName".to_string()) };
+    ///
+    /// let mut output: Vec<u8> = Vec::new();
+    /// writer.write_triple(&Triple::new(&subject, &predicate, &object), &mut output).unwrap();
+    ///
+    /// assert_eq!(String::from_utf8(output).unwrap(),
+    ///            "_:blank <http://example.org/show/localName> \"a \\\"b\\\"\" .\n".to_string());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid node type for a certain position.
+    /// - Writing to the output fails.
+    ///
+    pub fn write_triple<W: Write>(&self, triple: &Triple, output: &mut W) -> Result<()> {
+        let line = self.triple_to_n_triples(triple)? + "\n";
+
+        output
+            .write_all(line.as_bytes())
+            .map_err(|err| Error::new(ErrorType::InvalidWriterOutput, err.to_string()))
+    }
+
+    /// Writes the N-Triples syntax of each triple of the provided graph to the output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::writer::n_triples_writer::NTriplesWriter;
+    /// use rdf::graph::Graph;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node_with_id("s".to_string());
+    /// let predicate = graph.create_uri_node_str("http://example.org/p");
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &subject));
+    ///
+    /// let mut output: Vec<u8> = Vec::new();
+    /// NTriplesWriter::new().write_graph(&graph, &mut output).unwrap();
+    ///
+    /// assert_eq!(String::from_utf8(output).unwrap(), "_:s <http://example.org/p> _:s .\n");
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid triples are to be written to the output that do not conform the NTriples syntax standard.
+    /// - Writing to the output fails.
+    ///
+    pub fn write_graph<W: Write>(&self, graph: &Graph, output: &mut W) -> Result<()> {
+        for triple in graph.triples_iter() {
+            self.write_triple(triple, output)?;
         }
+
+        Ok(())
     }
 
     /// Generates the corresponding N-Triples syntax of the provided triple.
@@ -105,7 +210,7 @@ impl NTriplesWriter {
             Err(error) => return Err(error),
         }
 
-        output_string.push_str(" ");
+        output_string.push(' ');
 
         // convert predicate
         match self.node_to_n_triples(triple.predicate(), &TripleSegment::Predicate) {
@@ -113,7 +218,7 @@ impl NTriplesWriter {
             Err(error) => return Err(error),
         }
 
-        output_string.push_str(" ");
+        output_string.push(' ');
 
         // convert object
         match self.node_to_n_triples(triple.object(), &TripleSegment::Object) {
@@ -121,7 +226,7 @@ impl NTriplesWriter {
             Err(error) => return Err(error),
         }
 
-        output_string.push_str(" .");
+        output_string.push_str(self.formatter.options().statement_end());
 
         Ok(output_string)
     }
@@ -152,16 +257,13 @@ impl NTriplesWriter {
     ///
     pub fn node_to_n_triples(&self, node: &Node, segment: &TripleSegment) -> Result<String> {
         match *node {
-            Node::BlankNode { .. } =>
-            // blank nodes are not allowed as predicates
-            {
-                if *segment == TripleSegment::Predicate {
+            Node::BlankNode { .. }
+                if *segment == TripleSegment::Predicate => {
                     return Err(Error::new(
                         ErrorType::InvalidWriterOutput,
                         "Blank nodes are not allowed as predicates.",
                     ));
                 }
-            }
             Node::LiteralNode {
                 data_type: ref dt,
                 language: ref lang,
@@ -176,7 +278,7 @@ impl NTriplesWriter {
                 }
 
                 // either language or data type could be defined, but not both
-                if *lang != None && *dt != None {
+                if lang.is_some() && dt.is_some() {
                     return Err(Error::new(
                         ErrorType::InvalidWriterOutput,
                         "Language and data type defined for a literal.",
@@ -190,3 +292,61 @@ impl NTriplesWriter {
         Ok(self.formatter.format_node(node))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::reader::n_triples_parser::NTriplesParser;
+    use crate::reader::rdf_parser::RdfParser;
+    use crate::writer::n_triples_writer::NTriplesWriter;
+    use crate::writer::rdf_writer::RdfWriter;
+    use crate::writer::writer_options::WriterOptions;
+
+    #[test]
+    fn test_write_graph_round_trip() {
+        let input = "<http://example.org/s> <http://example.org/p> \"quote \\\" backslash \\\\ line\\nbreak\" .
+                 _:b <http://example.org/p> \"tab\\t\"@en-US .
+                 _:b <http://example.org/q> \"1\"^^<http://www.w3.org/2001/XMLSchema#integer> .
+                 <http://example.org/s> <http://example.org/q> _:b .";
+
+        let graph = NTriplesParser::from_string(input).decode().unwrap();
+
+        let mut output: Vec<u8> = Vec::new();
+        NTriplesWriter::new().write_graph(&graph, &mut output).unwrap();
+
+        let reparsed = NTriplesParser::from_reader(output.as_slice()).decode().unwrap();
+
+        assert_eq!(reparsed.count(), 4);
+        assert!(graph.diff(&reparsed).is_empty());
+    }
+
+    #[test]
+    fn test_write_compact_statement_end() {
+        let input = "<http://example.org/s> <http://example.org/p> <http://example.org/o> .
+                 _:b <http://example.org/p> \"o\"@en .";
+
+        let graph = NTriplesParser::from_string(input).decode().unwrap();
+
+        let options = WriterOptions {
+            compact_statement_end: true,
+            ..Default::default()
+        };
+
+        let canonical = NTriplesWriter::new().write_to_string(&graph).unwrap();
+        let compact = NTriplesWriter::with_options(options).write_to_string(&graph).unwrap();
+
+        assert_eq!(
+            canonical,
+            "<http://example.org/s> <http://example.org/p> <http://example.org/o> .\n\
+             _:b <http://example.org/p> \"o\"@en .\n"
+        );
+        assert_eq!(
+            compact,
+            "<http://example.org/s> <http://example.org/p> <http://example.org/o>.\n\
+             _:b <http://example.org/p> \"o\"@en.\n"
+        );
+
+        let reparsed = NTriplesParser::from_string(compact).decode().unwrap();
+
+        assert!(graph.diff(&reparsed).is_empty());
+    }
+}
@@ -0,0 +1,149 @@
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::triple::Triple;
+use crate::writer::formatter::json_formatter::JsonFormatter;
+use crate::writer::formatter::rdf_formatter::*;
+use crate::writer::rdf_writer::RdfWriter;
+use crate::Result;
+
+/// RDF writer to generate RDF/JSON syntax.
+///
+/// Triples are grouped by subject and predicate:
+/// `{"subject": {"predicate": [{"type": ..., "value": ...}, ...]}}`.
+#[derive(Default)]
+pub struct RdfJsonWriter {
+    formatter: JsonFormatter,
+}
+
+impl RdfWriter for RdfJsonWriter {
+    /// Generates the RDF/JSON syntax for the triples stored in the provided graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::writer::rdf_json_writer::RdfJsonWriter;
+    /// use rdf::writer::rdf_writer::RdfWriter;
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let writer = RdfJsonWriter::new();
+    ///
+    /// let mut graph = Graph::new(None);
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_literal_node("a".to_string());
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+    ///
+    /// assert_eq!(writer.write_to_string(&graph).unwrap(),
+    ///            "{\"_:auto0\":{\"http://example.org/show/localName\":[{\"type\":\"literal\",\"value\":\"a\"}]}}".to_string());
+    /// ```
+    fn write_to_string(&self, graph: &Graph) -> Result<String> {
+        let mut triples: Vec<&Triple> = graph.triples_iter().collect();
+        triples.sort();
+
+        let mut output_string = "{".to_string();
+
+        let mut previous_subject: Option<&Node> = None;
+        let mut previous_predicate: Option<&Node> = None;
+
+        for triple in triples {
+            if previous_subject == Some(triple.subject()) {
+                if previous_predicate == Some(triple.predicate()) {
+                    output_string.push(',');
+                } else {
+                    // close object list of previous predicate
+                    output_string.push_str("],");
+                    output_string.push_str(&self.node_to_key(triple.predicate()));
+                    output_string.push_str(":[");
+                }
+            } else {
+                if previous_subject.is_some() {
+                    // close object list and predicates of previous subject
+                    output_string.push_str("]},");
+                }
+
+                output_string.push_str(&self.node_to_key(triple.subject()));
+                output_string.push_str(":{");
+                output_string.push_str(&self.node_to_key(triple.predicate()));
+                output_string.push_str(":[");
+            }
+
+            output_string.push_str(&self.formatter.format_node(triple.object()));
+
+            previous_subject = Some(triple.subject());
+            previous_predicate = Some(triple.predicate());
+        }
+
+        if previous_subject.is_some() {
+            output_string.push_str("]}");
+        }
+
+        output_string.push('}');
+
+        Ok(output_string)
+    }
+}
+
+impl RdfJsonWriter {
+    /// Constructor of `RdfJsonWriter`.
+    pub fn new() -> RdfJsonWriter {
+        RdfJsonWriter {
+            formatter: JsonFormatter::new(),
+        }
+    }
+
+    /// Returns the JSON key for a subject or predicate node.
+    fn node_to_key(&self, node: &Node) -> String {
+        match *node {
+            Node::UriNode { ref uri } => self.formatter.format_string(uri.to_string()),
+            Node::BlankNode { ref id } => self.formatter.format_string(&("_:".to_string() + id)),
+            Node::LiteralNode { ref literal, .. } => self.formatter.format_string(literal),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::Graph;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+    use crate::writer::rdf_json_writer::RdfJsonWriter;
+    use crate::writer::rdf_writer::RdfWriter;
+
+    #[test]
+    fn test_rdf_json_writer_groups_triples() {
+        let mut graph = Graph::new(None);
+
+        let subject_1 = graph.create_uri_node(&Uri::new("http://example.org/a".to_string()));
+        let subject_2 = graph.create_uri_node(&Uri::new("http://example.org/b".to_string()));
+        let predicate_1 = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let predicate_2 = graph.create_uri_node(&Uri::new("http://example.org/q".to_string()));
+        let object_1 = graph.create_literal_node("1".to_string());
+        let object_2 = graph.create_literal_node("2".to_string());
+
+        graph.add_triple(&Triple::new(&subject_1, &predicate_1, &object_1));
+        graph.add_triple(&Triple::new(&subject_1, &predicate_1, &object_2));
+        graph.add_triple(&Triple::new(&subject_1, &predicate_2, &object_1));
+        graph.add_triple(&Triple::new(&subject_2, &predicate_1, &subject_1));
+
+        let expected = "{\
+            \"http://example.org/a\":{\
+            \"http://example.org/p\":[{\"type\":\"literal\",\"value\":\"1\"},{\"type\":\"literal\",\"value\":\"2\"}],\
+            \"http://example.org/q\":[{\"type\":\"literal\",\"value\":\"1\"}]},\
+            \"http://example.org/b\":{\
+            \"http://example.org/p\":[{\"type\":\"uri\",\"value\":\"http://example.org/a\"}]}\
+            }";
+
+        let writer = RdfJsonWriter::new();
+        assert_eq!(writer.write_to_string(&graph).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_rdf_json_writer_empty_graph() {
+        let graph = Graph::new(None);
+        let writer = RdfJsonWriter::new();
+
+        assert_eq!(writer.write_to_string(&graph).unwrap(), "{}");
+    }
+}
@@ -2,13 +2,13 @@ use crate::error::{Error, ErrorType};
 use crate::graph::Graph;
 use crate::node::Node;
 use std::collections::HashMap;
-use std::iter::repeat;
 use crate::triple::Triple;
 use crate::triple::TripleSegment;
 use crate::uri::Uri;
 use crate::writer::formatter::rdf_formatter::*;
 use crate::writer::formatter::turtle_formatter::TurtleFormatter;
 use crate::writer::rdf_writer::RdfWriter;
+use crate::writer::writer_options::WriterOptions;
 use crate::Result;
 
 /// RDF writer to generate Turtle syntax.
@@ -55,7 +55,7 @@ impl<'a> RdfWriter for TurtleWriter<'a> {
         let mut output_string = "".to_string();
 
         output_string.push_str(&self.write_base_uri(graph));
-        output_string.push_str(&self.write_prefixes(graph));
+        output_string.push_str(&self.write_prefixes());
 
         output_string.push_str(&self.write_triples_on_the_fly(graph.triples_iter().cloned().collect(), true).unwrap());
 
@@ -71,6 +71,55 @@ impl<'a> TurtleWriter<'a> {
         }
     }
 
+    /// Constructor of `TurtleWriter` using the provided options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::writer::turtle_writer::TurtleWriter;
+    /// use rdf::writer::writer_options::WriterOptions;
+    /// use rdf::graph::Graph;
+    ///
+    /// let graph = Graph::new(None);
+    /// let options = WriterOptions {
+    ///     explicit_string_datatype: true,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let writer = TurtleWriter::with_options(graph.namespaces(), options);
+    /// ```
+    pub fn with_options(
+        namespaces: &'a HashMap<String, Uri>,
+        options: WriterOptions,
+    ) -> TurtleWriter<'a> {
+        TurtleWriter {
+            formatter: TurtleFormatter::with_options(namespaces, options),
+        }
+    }
+
+    /// Constructor of `TurtleWriter` that only abbreviates URIs of
+    /// namespaces whose prefix is contained in the whitelist.
+    ///
+    /// URIs of all other namespaces are written in full, even if declared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::writer::turtle_writer::TurtleWriter;
+    /// use rdf::graph::Graph;
+    ///
+    /// let graph = Graph::new(None);
+    /// let writer = TurtleWriter::with_prefix_whitelist(graph.namespaces(), vec!["rdf".to_string()]);
+    /// ```
+    pub fn with_prefix_whitelist(
+        namespaces: &'a HashMap<String, Uri>,
+        prefix_whitelist: Vec<String>,
+    ) -> TurtleWriter<'a> {
+        TurtleWriter {
+            formatter: TurtleFormatter::with_prefix_whitelist(namespaces, prefix_whitelist),
+        }
+    }
+
     /// Returns the formatted base URI as string.
     pub fn write_base_uri(&self, graph: &Graph) -> String {
         let mut output_string = "".to_string();
@@ -84,12 +133,16 @@ impl<'a> TurtleWriter<'a> {
         output_string
     }
 
-    /// Returns all prefixes as formatted string.
-    pub fn write_prefixes(&self, graph: &Graph) -> String {
+    /// Returns the prefixes of all namespaces that may be used for abbreviating URIs as
+    /// formatted string, sorted by prefix.
+    ///
+    /// These are the namespaces the writer was created with, restricted to the prefix
+    /// whitelist if any, so that every abbreviated URI has a declared prefix.
+    pub fn write_prefixes(&self) -> String {
         let mut output_string = "".to_string();
 
         // write prefixes
-        for (prefix, namespace_uri) in graph.namespaces() {
+        for (prefix, namespace_uri) in self.formatter.namespaces() {
             output_string.push_str("@prefix ");
             output_string.push_str(prefix);
             output_string.push_str(": <");
@@ -122,7 +175,7 @@ impl<'a> TurtleWriter<'a> {
                     // indent object
                     output_string.push_str(" ,\n");
                     output_string
-                        .push_str(&repeat(" ").take(object_indentation).collect::<String>());
+                        .push_str(&std::iter::repeat_n(" ", object_indentation).collect::<String>());
                 } else {
                     output_string.push_str(" ;\n");
 
@@ -131,18 +184,18 @@ impl<'a> TurtleWriter<'a> {
                         self.node_to_turtle(triple.predicate(), &TripleSegment::Predicate)?;
                     // indent predicate
                     output_string
-                        .push_str(&repeat(" ").take(predicate_indentation).collect::<String>());
+                        .push_str(&std::iter::repeat_n(" ", predicate_indentation).collect::<String>());
                     output_string.push_str(&turtle_predicate);
 
                     previous_predicate = Some(triple.predicate());
 
-                    output_string.push_str(" ");
+                    output_string.push(' ');
 
                     // recalculate object indentation
                     object_indentation = predicate_indentation + turtle_predicate.len() + 1;
                 }
             } else {
-                if previous_subject != None {
+                if previous_subject.is_some() {
                     output_string.push_str(" .\n");
                 }
 
@@ -152,12 +205,12 @@ impl<'a> TurtleWriter<'a> {
                 output_string.push_str(&turtle_subject);
                 previous_subject = Some(triple.subject());
 
-                output_string.push_str(" ");
+                output_string.push(' ');
                 let turtle_predicate =
                     self.node_to_turtle(triple.predicate(), &TripleSegment::Predicate)?;
                 output_string.push_str(&turtle_predicate);
                 previous_predicate = Some(triple.predicate());
-                output_string.push_str(" ");
+                output_string.push(' ');
 
                 predicate_indentation = turtle_subject.len() + 1;
                 object_indentation = predicate_indentation + turtle_predicate.len() + 1;
@@ -185,16 +238,13 @@ impl<'a> TurtleWriter<'a> {
     ///
     fn node_to_turtle(&self, node: &Node, segment: &TripleSegment) -> Result<String> {
         match *node {
-            Node::BlankNode { .. } =>
-            // blank nodes are not allowed as predicates
-                {
-                    if *segment == TripleSegment::Predicate {
+            Node::BlankNode { .. }
+                    if *segment == TripleSegment::Predicate => {
                         return Err(Error::new(
                             ErrorType::InvalidWriterOutput,
                             "Blank nodes are not allowed as predicates in Turtle.",
                         ));
                     }
-                }
             Node::LiteralNode {
                 data_type: ref dt,
                 language: ref lang,
@@ -209,7 +259,7 @@ impl<'a> TurtleWriter<'a> {
                 }
 
                 // either language or data type could be defined, but not both
-                if *lang != None && *dt != None {
+                if lang.is_some() && dt.is_some() {
                     return Err(Error::new(
                         ErrorType::InvalidWriterOutput,
                         "Literal has data type and language.",
@@ -232,6 +282,8 @@ mod tests {
     use crate::uri::Uri;
     use crate::writer::rdf_writer::RdfWriter;
     use crate::writer::turtle_writer::TurtleWriter;
+    use crate::writer::writer_options::WriterOptions;
+    use std::collections::HashMap;
 
     #[test]
     fn test_turtle_writer() {
@@ -250,7 +302,7 @@ mod tests {
         let writer = TurtleWriter::new(graph.namespaces());
         match writer.write_to_string(&graph) {
             Ok(str) => assert_eq!(result, str),
-            Err(_) => assert!(false),
+            Err(e) => panic!("Err {}", e),
         }
     }
 
@@ -281,7 +333,7 @@ _:auto2 <http://example.org/show/localName> _:auto1 ;
         let writer = TurtleWriter::new(graph.namespaces());
         match writer.write_to_string(&graph) {
             Ok(str) => assert_eq!(result, str),
-            Err(_) => assert!(false),
+            Err(e) => panic!("Err {}", e),
         }
     }
 
@@ -311,7 +363,40 @@ _:auto2 <http://example.org/show/localName> _:auto1 ,
         let writer = TurtleWriter::new(graph.namespaces());
         match writer.write_to_string(&graph) {
             Ok(str) => assert_eq!(result, str),
-            Err(_) => assert!(false),
+            Err(e) => panic!("Err {}", e),
+        }
+    }
+
+    #[test]
+    fn test_turtle_writer_with_namespace_map() {
+        let mut graph = Graph::new(None);
+        let mut namespaces = HashMap::new();
+
+        namespaces.insert("ex".to_string(), Uri::new("http://example.org/".to_string()));
+        namespaces.insert("other".to_string(), Uri::new("http://other.org/ns#".to_string()));
+
+        let subject = graph.create_uri_node_str("http://example.org/s");
+        let p = graph.create_uri_node_str("http://example.org/p");
+        let q = graph.create_uri_node_str("http://example.org/q");
+        let o1 = graph.create_uri_node_str("http://example.org/o1");
+        let o2 = graph.create_uri_node_str("http://example.org/o2");
+        let unprefixed = graph.create_uri_node_str("http://other.org/x");
+
+        graph.add_triple(&Triple::new(&subject, &p, &o1));
+        graph.add_triple(&Triple::new(&subject, &p, &o2));
+        graph.add_triple(&Triple::new(&subject, &q, &unprefixed));
+
+        let result = "@prefix ex: <http://example.org/> .
+@prefix other: <http://other.org/ns#> .
+ex:s ex:p ex:o1 ,
+          ex:o2 ;
+     ex:q <http://other.org/x> ."
+            .to_string();
+
+        let writer = TurtleWriter::new(&namespaces);
+        match writer.write_to_string(&graph) {
+            Ok(str) => assert_eq!(result, str),
+            Err(e) => panic!("Err {}", e),
         }
     }
 
@@ -324,7 +409,7 @@ _:auto2 <http://example.org/show/localName> _:auto1 ,
         let writer = TurtleWriter::new(graph.namespaces());
         match writer.write_to_string(&graph) {
             Ok(str) => assert_eq!(result, str),
-            Err(_) => assert!(false),
+            Err(e) => panic!("Err {}", e),
         }
     }
 
@@ -342,7 +427,7 @@ _:auto2 <http://example.org/show/localName> _:auto1 ,
         let writer = TurtleWriter::new(graph.namespaces());
         match writer.write_to_string(&graph) {
             Ok(str) => assert_eq!(result, str),
-            Err(_) => assert!(false),
+            Err(e) => panic!("Err {}", e),
         }
     }
 
@@ -355,7 +440,7 @@ _:auto2 <http://example.org/show/localName> _:auto1 ,
             Uri::new("http://example.org/".to_string()),
         ));
 
-        let result = "@prefix example: <http://example.org/> .\n _:auto0 example.localName 1".to_string();
+        let result = "@prefix example: <http://example.org/> .\n_:auto0 example:show:localName \"1\"^^<http://www.w3.org/2001/XMLSchema#integer> .".to_string();
         let subject = graph.create_blank_node();
         let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
         let object = graph.create_integer_node(1);
@@ -364,7 +449,86 @@ _:auto2 <http://example.org/show/localName> _:auto1 ,
         let writer = TurtleWriter::new(graph.namespaces());
         match writer.write_to_string(&graph) {
             Ok(str) => assert_eq!(result, str),
-            Err(_) => assert!(false),
+            Err(e) => panic!("Err {}", e),
         }
     }
+
+    #[test]
+    fn test_turtle_writer_prefix_whitelist() {
+        let mut graph = Graph::new(None);
+
+        graph.add_namespace(&Namespace::new(
+            "example".to_string(),
+            Uri::new("http://example.org/".to_string()),
+        ));
+        graph.add_namespace(&Namespace::new(
+            "xsd".to_string(),
+            Uri::new("http://www.w3.org/2001/XMLSchema#".to_string()),
+        ));
+
+        let subject = graph.create_uri_node(&Uri::new("http://example.org/s".to_string()));
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let object = graph.create_integer_node(1);
+        graph.add_triple(&Triple::new(&subject, &predicate, &object));
+
+        let writer = TurtleWriter::with_prefix_whitelist(graph.namespaces(), vec!["xsd".to_string()]);
+        let triples = writer.write_triples_on_the_fly(graph.triples_iter().cloned().collect(), true).unwrap();
+
+        assert_eq!(triples, "<http://example.org/s> <http://example.org/p> \"1\"^^xsd:integer .");
+    }
+
+    #[test]
+    fn test_turtle_writer_explicit_string_datatype() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_blank_node();
+        let predicate =
+            graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+        let object = graph.create_literal_node("literal".to_string());
+        graph.add_triple(&Triple::new(&subject, &predicate, &object));
+
+        let options = WriterOptions {
+            explicit_string_datatype: true,
+            ..Default::default()
+        };
+
+        let implicit = TurtleWriter::new(graph.namespaces());
+        let explicit = TurtleWriter::with_options(graph.namespaces(), options);
+
+        assert_eq!(
+            implicit.write_to_string(&graph).unwrap(),
+            "_:auto0 <http://example.org/show/localName> \"literal\" ."
+        );
+        assert_eq!(
+            explicit.write_to_string(&graph).unwrap(),
+            "_:auto0 <http://example.org/show/localName> \"literal\"^^<http://www.w3.org/2001/XMLSchema#string> ."
+        );
+    }
+
+    #[test]
+    fn test_turtle_writer_blank_node_suffix() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_blank_node();
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let object = graph.create_blank_node_with_id("b".to_string());
+        graph.add_triple(&Triple::new(&subject, &predicate, &object));
+        graph.add_triple(&Triple::new(&object, &predicate, &subject));
+
+        let options = WriterOptions {
+            blank_node_suffix: "_g1".to_string(),
+            ..Default::default()
+        };
+
+        let output = TurtleWriter::with_options(graph.namespaces(), options)
+            .write_to_string(&graph)
+            .unwrap();
+
+        let labels: Vec<&str> = output
+            .split_whitespace()
+            .filter(|term| term.starts_with("_:"))
+            .collect();
+
+        assert_eq!(labels, vec!["_:auto0_g1", "_:b_g1", "_:b_g1", "_:auto0_g1"]);
+    }
 }
@@ -0,0 +1,52 @@
+use crate::specs::xml_specs::XmlDataTypes;
+use crate::uri::Uri;
+
+/// Options to configure the output of RDF writers.
+#[derive(Debug, Clone, Default)]
+pub struct WriterOptions {
+    /// Write the `xsd:string` data type for literals without data type and language,
+    /// e.g. `"literal"` becomes `"literal"^^<http://www.w3.org/2001/XMLSchema#string>`.
+    pub explicit_string_datatype: bool,
+
+    /// Suffix appended to the label of every written blank node, e.g. `_:b` becomes `_:b_1`
+    /// for the suffix `_1`.
+    ///
+    /// Choosing distinct suffixes keeps blank nodes of separately written graphs distinct
+    /// when their outputs are concatenated.
+    pub blank_node_suffix: String,
+
+    /// Omit the space before the `.` that ends each statement written by the N-Triples
+    /// writer, e.g. `<s> <p> <o> .` becomes `<s> <p> <o>.`.
+    ///
+    /// The space is written by default as required by canonical N-Triples.
+    pub compact_statement_end: bool,
+}
+
+impl WriterOptions {
+    /// Returns the data type that is written for a literal according to the options.
+    pub(crate) fn literal_data_type(
+        &self,
+        data_type: &Option<Uri>,
+        language: &Option<String>,
+    ) -> Option<Uri> {
+        if self.explicit_string_datatype && data_type.is_none() && language.is_none() {
+            return Some(XmlDataTypes::String.to_uri());
+        }
+
+        data_type.clone()
+    }
+
+    /// Returns the label that is written for a blank node according to the options.
+    pub(crate) fn blank_node_label(&self, id: &str) -> String {
+        id.to_string() + &self.blank_node_suffix
+    }
+
+    /// Returns the characters that end a written statement according to the options.
+    pub(crate) fn statement_end(&self) -> &'static str {
+        if self.compact_statement_end {
+            "."
+        } else {
+            " ."
+        }
+    }
+}